@@ -0,0 +1,33 @@
+use ori_native::prelude::*;
+
+fn main() {
+    let mut data = Data {
+        rows: (0..100_000).map(|i| format!("Row {i}")).collect(),
+    };
+
+    App::new().run(&mut data, ui);
+}
+
+struct Data {
+    rows: Vec<String>,
+}
+
+fn ui(data: &Data) -> impl Effect<Data> + use<> {
+    window(
+        lazy_list(
+            data.rows.len(),
+            32.0,
+            |index, data: &Data| {
+                row(text(data.rows[index].clone()))
+                    .padding_left(12.0)
+                    .align_items(Align::Center)
+                    .background_color(if index % 2 == 0 {
+                        Color::WHITE
+                    } else {
+                        Color::WHITE.darken(0.05)
+                    })
+            },
+        )
+        .flex(1.0),
+    )
+}
@@ -12,6 +12,9 @@ struct Data {
 
 struct Remove(usize);
 
+#[derive(Clone)]
+struct Dragged(usize);
+
 struct Todo {
     name: String,
     done: bool,
@@ -31,26 +34,69 @@ fn ui(data: &Data) -> impl Effect<Data> + use<> {
     effects((
         window(
             column(
-                column((
-                    input(),
-                    (!data.todos.is_empty()).then(|| todos(data)),
-                ))
-                .width(300.0)
-                .align_items(Align::Stretch)
-                .border(1.0)
-                .border_color(theme::BORDER),
+                file_drop(|data: &Data, hovered| {
+                    column((
+                        input(),
+                        (!data.todos.is_empty()).then(|| todos(data)),
+                    ))
+                    .width(300.0)
+                    .align_items(Align::Stretch)
+                    .border(1.0)
+                    .border_color(if hovered {
+                        theme::SUCCESS
+                    } else {
+                        theme::BORDER
+                    })
+                })
+                .on_text(|data: &mut Data, text| add_todos(data, &text)),
             )
             .flex(1.0)
             .justify_contents(Justify::Center)
             .align_items(Align::Center)
             .background_color(theme::BACKGROUND),
-        ),
+        )
+        .menubar(menubar(data)),
+        clipboard().on_text(|data: &mut Data, text| add_todos(data, &text)),
+        app_action("remove-last", |data: &mut Data| {
+            data.todos.pop();
+        })
+        .accelerator("Ctrl+Z")
+        .enabled(!data.todos.is_empty()),
         receive(|data: &mut Data, Remove(index)| {
             data.todos.remove(index);
         }),
     ))
 }
 
+fn menubar(data: &Data) -> Menu<Data> {
+    let all_done = data.todos.iter().all(|todo| todo.done);
+    let any_done = data.todos.iter().any(|todo| todo.done);
+
+    Menu::new([MenuItem::submenu(
+        "Todos",
+        Menu::new([
+            MenuItem::new("All done", move |data: &mut Data| {
+                for todo in &mut data.todos {
+                    todo.done = !all_done;
+                }
+            })
+            .checked(!data.todos.is_empty() && all_done)
+            .enabled(!data.todos.is_empty()),
+            MenuItem::separator(),
+            MenuItem::new("Clear done", |data: &mut Data| {
+                data.todos.retain(|todo| !todo.done);
+            })
+            .accelerator("Ctrl+Shift+D")
+            .enabled(any_done),
+            MenuItem::separator(),
+            MenuItem::new("Paste", |_: &mut Data| {
+                paste(ClipboardKind::Text)
+            })
+            .accelerator("Ctrl+Shift+V"),
+        ]),
+    )])
+}
+
 fn input() -> impl View<Data> + use<> {
     with(
         |_| String::new(),
@@ -78,12 +124,19 @@ fn add_todo(data: &mut Data, name: String) {
     data.todos.insert(0, Todo { name, done: false })
 }
 
+fn add_todos(data: &mut Data, text: &str) {
+    // every line of the text becomes a todo
+    for line in text.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        add_todo(data, line.to_owned());
+    }
+}
+
 fn todos(data: &Data) -> impl View<Data> + use<> {
     let todos = data
         .todos
         .iter()
         .enumerate()
-        .map(|(i, x)| todo(i, x))
+        .map(|(i, _)| reorderable(i))
         .collect::<Vec<_>>();
 
     column(vscroll(column(todos)))
@@ -92,7 +145,27 @@ fn todos(data: &Data) -> impl View<Data> + use<> {
         .border_color(theme::BORDER)
 }
 
-fn todo(index: usize, _todo: &Todo) -> impl View<Data> + use<> {
+fn reorderable(index: usize) -> impl View<Data> + use<> {
+    drop_target(move |data: &Data, hovered| {
+        let view = draggable(
+            todo(index, &data.todos[index]),
+            Dragged(index),
+        );
+
+        column(view).background_color(if hovered {
+            theme::BACKGROUND.lighten(0.05)
+        } else {
+            Color::TRANSPARENT
+        })
+    })
+    .on_drop(move |data: &mut Data, Dragged(from)| {
+        // move the dragged todo to the position of the one it was dropped on
+        let todo = data.todos.remove(from);
+        data.todos.insert(index, todo);
+    })
+}
+
+fn todo(index: usize, todo: &Todo) -> impl View<Data> + use<> {
     let view = pressable(move |todo: &Todo, state| {
         let name = if todo.done {
             text(&todo.name)
@@ -121,6 +194,22 @@ fn todo(index: usize, _todo: &Todo) -> impl View<Data> + use<> {
     })
     .on_press(|todo: &mut Todo| todo.done = !todo.done);
 
+    let menu = Menu::new([
+        MenuItem::new("Done", |todo: &mut Todo| {
+            todo.done = !todo.done
+        })
+        .checked(todo.done),
+        MenuItem::new("Copy", |todo: &mut Todo| {
+            copy(todo.name.clone())
+        }),
+        MenuItem::separator(),
+        MenuItem::new("Remove", move |_: &mut Todo| {
+            Message::new(Remove(index), None)
+        }),
+    ]);
+
+    let view = context_menu(view, menu);
+
     map(view, move |data: &mut Data, f| {
         f(&mut data.todos[index])
     })
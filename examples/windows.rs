@@ -0,0 +1,107 @@
+use ori_native::prelude::*;
+
+fn main() {
+    let mut data = Data {
+        settings_open: false,
+        confirm_reset: false,
+        presses:       0,
+    };
+
+    App::new()
+        .quit_policy(QuitPolicy::LastWindowClosed)
+        .run(&mut data, ui);
+}
+
+struct Data {
+    settings_open: bool,
+    confirm_reset: bool,
+    presses:       u32,
+}
+
+fn ui(data: &Data) -> impl Effect<Data> + use<> {
+    effects((
+        window(
+            column((
+                button("Open settings", |data: &mut Data| {
+                    data.settings_open = true;
+                }),
+                text(format!(
+                    "Pressed {} times.",
+                    data.presses
+                )),
+                button("Reset", |data: &mut Data| {
+                    data.confirm_reset = true;
+                }),
+            ))
+            .flex(1.0)
+            .gap(20.0)
+            .justify_contents(Justify::Center)
+            .align_items(Align::Center)
+            .background_color(Color::WHITE),
+        )
+        .name("main"),
+        data.settings_open.then(settings),
+        data.confirm_reset.then(confirm_reset),
+    ))
+}
+
+fn settings() -> impl Effect<Data> + use<> {
+    window(
+        column(button("Press me", |data: &mut Data| {
+            data.presses += 1;
+        }))
+        .flex(1.0)
+        .justify_contents(Justify::Center)
+        .align_items(Align::Center)
+        .background_color(Color::WHITE),
+    )
+    .sizing(Sizing::Content)
+    .on_close_requested(|data: &mut Data| {
+        data.settings_open = false;
+    })
+}
+
+fn confirm_reset() -> impl Effect<Data> + use<> {
+    dialog(|id| {
+        column((
+            text("Reset the counter?"),
+            row((
+                button("Cancel", move |_| respond(id, false)),
+                button("Reset", move |_| respond(id, true)),
+            ))
+            .gap(8.0),
+        ))
+        .gap(20.0)
+        .padding(20.0)
+        .background_color(Color::WHITE)
+    })
+    .title("Reset")
+    .owner("main")
+    .on_response(|data: &mut Data, reset: bool| {
+        if reset {
+            data.presses = 0;
+        }
+
+        data.confirm_reset = false;
+    })
+    .on_close(|data: &mut Data| {
+        data.confirm_reset = false;
+    })
+}
+
+fn button<A>(label: &'static str, on_press: impl FnMut(&mut Data) -> A + 'static) -> impl View<Data>
+where
+    A: Into<Action>,
+{
+    pressable(move |_, state| {
+        row(text(label))
+            .padding(8.0)
+            .corner(4.0)
+            .background_color(if state.hovered {
+                Color::hex("#d0d0d0")
+            } else {
+                Color::hex("#e0e0e0")
+            })
+    })
+    .on_press(on_press)
+}
@@ -0,0 +1,37 @@
+/// The contents of the clipboard.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ClipboardContent {
+    Text(String),
+
+    /// An encoded image, in any format supported by [`image`](crate::views::image). Images
+    /// read from the clipboard are always PNG.
+    Image(Vec<u8>),
+}
+
+impl ClipboardContent {
+    pub fn kind(&self) -> ClipboardKind {
+        match self {
+            ClipboardContent::Text(_) => ClipboardKind::Text,
+            ClipboardContent::Image(_) => ClipboardKind::Image,
+        }
+    }
+}
+
+impl From<String> for ClipboardContent {
+    fn from(text: String) -> Self {
+        ClipboardContent::Text(text)
+    }
+}
+
+impl From<&str> for ClipboardContent {
+    fn from(text: &str) -> Self {
+        ClipboardContent::Text(text.to_owned())
+    }
+}
+
+/// The kind of [`ClipboardContent`] to read from the clipboard.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ClipboardKind {
+    Text,
+    Image,
+}
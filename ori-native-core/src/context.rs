@@ -117,10 +117,22 @@ where
             .set_node_context(node, Some(Box::new(leaf)))
     }
 
+    pub fn get_layout_style(&self, node: taffy::NodeId) -> taffy::TaffyResult<&taffy::Style> {
+        self.layout_tree.style(node)
+    }
+
     pub fn get_computed_layout(&self, node: taffy::NodeId) -> taffy::TaffyResult<&taffy::Layout> {
         self.layout_tree.layout(node)
     }
 
+    pub fn get_layout_child(
+        &self,
+        parent: taffy::NodeId,
+        index: usize,
+    ) -> taffy::TaffyResult<taffy::NodeId> {
+        self.layout_tree.child_at_index(parent, index)
+    }
+
     pub fn compute_layout(
         &mut self,
         node: taffy::NodeId,
@@ -0,0 +1,32 @@
+use std::path::PathBuf;
+
+/// The phase of a drag gesture.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DragPhase {
+    /// The pointer moved past the threshold, with a button held.
+    Start,
+    Update,
+    /// The button was released, or the drag was cancelled.
+    End,
+}
+
+/// A drag gesture, see [`on_drag`](crate::views::on_drag).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DragEvent {
+    pub phase:    DragPhase,
+    /// The horizontal position the drag started at, relative to the layout box of the view.
+    pub start_x:  f32,
+    /// The vertical position the drag started at, relative to the layout box of the view.
+    pub start_y:  f32,
+    /// The horizontal distance the pointer moved since the drag started.
+    pub offset_x: f32,
+    /// The vertical distance the pointer moved since the drag started.
+    pub offset_y: f32,
+}
+
+/// Data dropped from another application, see [`file_drop`](crate::views::file_drop).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExternalDrop {
+    Files(Vec<PathBuf>),
+    Text(String),
+}
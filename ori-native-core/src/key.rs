@@ -0,0 +1,397 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// A key on the keyboard, independent of the modifiers held.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Key {
+    /// A key producing a character, always lowercase for letters.
+    Character(char),
+    Enter,
+    Escape,
+    Tab,
+    Backspace,
+    Delete,
+    Insert,
+    Space,
+    Up,
+    Down,
+    Left,
+    Right,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    /// A function key, `F(1)` is `F1`, up to [`Key::MAX_F`].
+    F(u8),
+    Shift,
+    Control,
+    Alt,
+    Super,
+    /// A key that has no representation, see [`KeyEvent::code`] for the physical key.
+    Unidentified,
+}
+
+impl Key {
+    /// The highest function key, `F35`.
+    pub const MAX_F: u8 = 35;
+
+    fn parse(name: &str) -> Option<Self> {
+        let mut chars = name.chars();
+
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            return match c {
+                ' ' => Some(Key::Space),
+                c => Some(Key::Character(c.to_lowercase().next()?)),
+            };
+        }
+
+        let key = match name.to_lowercase().as_str() {
+            "enter" | "return" => Key::Enter,
+            "escape" | "esc" => Key::Escape,
+            "tab" => Key::Tab,
+            "backspace" => Key::Backspace,
+            "delete" | "del" => Key::Delete,
+            "insert" | "ins" => Key::Insert,
+            "space" => Key::Space,
+            "up" => Key::Up,
+            "down" => Key::Down,
+            "left" => Key::Left,
+            "right" => Key::Right,
+            "home" => Key::Home,
+            "end" => Key::End,
+            "pageup" | "page_up" => Key::PageUp,
+            "pagedown" | "page_down" => Key::PageDown,
+            name => {
+                let number = name.strip_prefix('f')?.parse().ok()?;
+
+                if !(1..=Self::MAX_F).contains(&number) {
+                    return None;
+                }
+
+                Key::F(number)
+            }
+        };
+
+        Some(key)
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Key::Character(c) => write!(f, "{}", c.to_uppercase()),
+            Key::F(number) => write!(f, "F{number}"),
+            key => write!(f, "{key:?}"),
+        }
+    }
+}
+
+/// The modifier keys held while a key is pressed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Modifiers {
+    pub shift: bool,
+    pub ctrl:  bool,
+    pub alt:   bool,
+    /// The super, or command key.
+    pub meta:  bool,
+}
+
+impl Modifiers {
+    pub const NONE: Self = Self {
+        shift: false,
+        ctrl:  false,
+        alt:   false,
+        meta:  false,
+    };
+
+    pub fn is_empty(&self) -> bool {
+        *self == Self::NONE
+    }
+}
+
+/// Whether a key was pressed or released.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum KeyState {
+    Pressed,
+    Released,
+}
+
+/// Whether a key event propagates to the parents of the widget handling it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Propagation {
+    /// Let the parents handle the event too.
+    #[default]
+    Proceed,
+
+    /// Stop the event here, e.g. to keep the arrow keys from moving the focus.
+    Stop,
+}
+
+/// A key being pressed or released.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct KeyEvent {
+    /// The logical key, after applying the keyboard layout.
+    pub key:       Key,
+    /// The platform specific code of the physical key, independent of the keyboard layout.
+    pub code:      u32,
+    pub modifiers: Modifiers,
+    /// Whether the event is generated by holding the key down.
+    pub repeat:    bool,
+    pub state:     KeyState,
+}
+
+impl KeyEvent {
+    /// Create an event of `key` being pressed, without modifiers.
+    pub fn pressed(key: Key) -> Self {
+        Self {
+            key,
+            code: 0,
+            modifiers: Modifiers::NONE,
+            repeat: false,
+            state: KeyState::Pressed,
+        }
+    }
+
+    /// Create an event of `key` being released, without modifiers.
+    pub fn released(key: Key) -> Self {
+        Self {
+            state: KeyState::Released,
+            ..Self::pressed(key)
+        }
+    }
+
+    pub fn is_pressed(&self) -> bool {
+        self.state == KeyState::Pressed
+    }
+
+    /// Check whether the event is `accelerator` being pressed.
+    pub fn matches(&self, accelerator: Accelerator) -> bool {
+        self.is_pressed() && self.key == accelerator.key && self.modifiers == accelerator.modifiers
+    }
+}
+
+/// A key combined with modifiers, that triggers a shortcut.
+///
+/// Accelerators are parsed from either the gtk format, `<Ctrl><Shift>s`, or the format shown
+/// in menus, `Ctrl+Shift+S`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Accelerator {
+    pub modifiers: Modifiers,
+    pub key:       Key,
+}
+
+impl Accelerator {
+    pub fn new(modifiers: Modifiers, key: Key) -> Self {
+        Self { modifiers, key }
+    }
+
+    pub fn parse(accelerator: &str) -> Result<Self, ParseAcceleratorError> {
+        let error = || ParseAcceleratorError {
+            accelerator: accelerator.to_owned(),
+        };
+
+        let mut modifiers = Modifiers::NONE;
+        let mut rest = accelerator.trim();
+
+        let mut add_modifier = |name: &str| {
+            match name.to_lowercase().as_str() {
+                "shift" => modifiers.shift = true,
+                "ctrl" | "control" | "primary" => modifiers.ctrl = true,
+                "alt" | "option" => modifiers.alt = true,
+                "super" | "meta" | "cmd" | "logo" => modifiers.meta = true,
+                _ => return false,
+            }
+
+            true
+        };
+
+        let key = if rest.starts_with('<') {
+            while let Some(inner) = rest.strip_prefix('<') {
+                let (name, after) = inner.split_once('>').ok_or_else(error)?;
+
+                if !add_modifier(name) {
+                    return Err(error());
+                }
+
+                rest = after;
+            }
+
+            rest
+        } else {
+            // the key itself can be a `+`, e.g. `+` or `Ctrl++`
+            let (names, key) = match rest.strip_suffix("++") {
+                Some(names) => (names, "+"),
+                None if rest == "+" => ("", "+"),
+                None => match rest.rsplit_once('+') {
+                    Some((names, key)) => (names, key),
+                    None => ("", rest),
+                },
+            };
+
+            for name in names.split('+').filter(|name| !name.is_empty()) {
+                if !add_modifier(name.trim()) {
+                    return Err(error());
+                }
+            }
+
+            key.trim()
+        };
+
+        let key = Key::parse(key).ok_or_else(error)?;
+        Ok(Self { modifiers, key })
+    }
+}
+
+impl FromStr for Accelerator {
+    type Err = ParseAcceleratorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for Accelerator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Modifiers {
+            shift,
+            ctrl,
+            alt,
+            meta,
+        } = self.modifiers;
+
+        if ctrl {
+            write!(f, "Ctrl+")?;
+        }
+
+        if alt {
+            write!(f, "Alt+")?;
+        }
+
+        if shift {
+            write!(f, "Shift+")?;
+        }
+
+        if meta {
+            write!(f, "Super+")?;
+        }
+
+        write!(f, "{}", self.key)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseAcceleratorError {
+    accelerator: String,
+}
+
+impl fmt::Display for ParseAcceleratorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid accelerator `{}`",
+            self.accelerator
+        )
+    }
+}
+
+impl Error for ParseAcceleratorError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn accelerator(modifiers: Modifiers, key: Key) -> Result<Accelerator, ParseAcceleratorError> {
+        Ok(Accelerator::new(modifiers, key))
+    }
+
+    const CTRL: Modifiers = Modifiers {
+        ctrl: true,
+        ..Modifiers::NONE
+    };
+
+    const CTRL_SHIFT: Modifiers = Modifiers {
+        ctrl: true,
+        shift: true,
+        ..Modifiers::NONE
+    };
+
+    #[test]
+    fn parse_gtk_format() {
+        assert_eq!(
+            Accelerator::parse("<Ctrl><Shift>s"),
+            accelerator(CTRL_SHIFT, Key::Character('s'))
+        );
+        assert_eq!(
+            Accelerator::parse("<Primary>Return"),
+            accelerator(CTRL, Key::Enter)
+        );
+        assert_eq!(
+            Accelerator::parse("<Ctrl>+"),
+            accelerator(CTRL, Key::Character('+'))
+        );
+    }
+
+    #[test]
+    fn parse_menu_format() {
+        assert_eq!(
+            Accelerator::parse("Ctrl+Shift+S"),
+            accelerator(CTRL_SHIFT, Key::Character('s'))
+        );
+        assert_eq!(
+            Accelerator::parse("ctrl + page_down"),
+            accelerator(CTRL, Key::PageDown)
+        );
+        assert_eq!(
+            Accelerator::parse("Escape"),
+            accelerator(Modifiers::NONE, Key::Escape)
+        );
+    }
+
+    #[test]
+    fn parse_plus_key() {
+        assert_eq!(
+            Accelerator::parse("+"),
+            accelerator(Modifiers::NONE, Key::Character('+'))
+        );
+        assert_eq!(
+            Accelerator::parse("Ctrl++"),
+            accelerator(CTRL, Key::Character('+'))
+        );
+    }
+
+    #[test]
+    fn parse_function_keys() {
+        assert_eq!(
+            Accelerator::parse("F1"),
+            accelerator(Modifiers::NONE, Key::F(1))
+        );
+        assert_eq!(
+            Accelerator::parse("<Ctrl>F35"),
+            accelerator(CTRL, Key::F(35))
+        );
+
+        assert!(Accelerator::parse("F0").is_err());
+        assert!(Accelerator::parse("F36").is_err());
+        assert!(Accelerator::parse("F255").is_err());
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!(Accelerator::parse("").is_err());
+        assert!(Accelerator::parse("Ctrl+").is_err());
+        assert!(Accelerator::parse("<Ctrl>").is_err());
+        assert!(Accelerator::parse("<Ctrl s").is_err());
+        assert!(Accelerator::parse("Hyper+s").is_err());
+        assert!(Accelerator::parse("Ctrl+NotAKey").is_err());
+    }
+
+    #[test]
+    fn display_roundtrips() {
+        for text in ["Ctrl+Shift+S", "Alt+F4", "Ctrl++", "Super+Space"] {
+            let accelerator = Accelerator::parse(text).unwrap();
+            assert_eq!(
+                Accelerator::parse(&accelerator.to_string()),
+                Ok(accelerator)
+            );
+        }
+    }
+}
@@ -1,20 +1,35 @@
+mod clipboard;
 mod context;
+mod drag;
 mod element;
+mod key;
 mod lifecycle;
+mod menu;
 mod platform;
+mod pointer;
+mod scroll;
 mod style;
 mod text;
 
 pub mod native;
 pub mod views;
 
+pub use clipboard::{ClipboardContent, ClipboardKind};
 pub use context::{BoxedEffect, Context, LayoutLeaf};
+pub use drag::{DragEvent, DragPhase, ExternalDrop};
 pub use element::{BoxedWidget, NativeParent, NativeWidget, Pod, PodMut, WidgetView};
+pub use key::{
+    Accelerator, Key, KeyEvent, KeyState, Modifiers, ParseAcceleratorError, Propagation,
+};
 pub use lifecycle::Lifecycle;
-pub use platform::Platform;
+pub use menu::{Menu, MenuEntry, MenuItem, MenuModel};
+pub use platform::{Platform, QuitPolicy};
+pub use pointer::{PointerButton, PointerEvent, PointerEventKind};
+pub use scroll::ScrollEvent;
 pub use style::{
-    Align, AutoLength, BorderLayout, Color, ContainerLayout, Direction, FlexLayout, Fraction,
-    Justify, Layout, Length, Overflow, Position, Sizing,
+    Align, AutoLength, BorderLayout, BoxSizing, Color, ContainerLayout, Direction, FlexLayout,
+    FlexWrap, Fraction, GridFlow, GridLayout, GridPlacement, Justify, Layout, Length, Overflow,
+    Position, Repeat, Sizing, TemplateTrack, Track, TrackSize, fr, minmax, repeat, span,
 };
 pub use text::{Font, Stretch, TextSpan, Weight, Wrap};
 
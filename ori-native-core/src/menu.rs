@@ -0,0 +1,166 @@
+use ori::Action;
+
+/// A declarative menu, whose items dispatch actions on `T` when selected.
+pub struct Menu<T> {
+    items: Vec<MenuItem<T>>,
+}
+
+impl<T> Default for Menu<T> {
+    fn default() -> Self {
+        Self::new([])
+    }
+}
+
+impl<T> Menu<T> {
+    pub fn new(items: impl IntoIterator<Item = MenuItem<T>>) -> Self {
+        Self {
+            items: items.into_iter().collect(),
+        }
+    }
+
+    pub fn item(mut self, item: MenuItem<T>) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Split the menu into a model for the platform, and the handlers of the items, indexed
+    /// by the `id` of [`MenuEntry::Item`].
+    pub(crate) fn into_model(self) -> (MenuModel, Vec<MenuHandler<T>>) {
+        let mut handlers = Vec::new();
+        let model = self.into_model_with(&mut handlers);
+        (model, handlers)
+    }
+
+    fn into_model_with(self, handlers: &mut Vec<MenuHandler<T>>) -> MenuModel {
+        let entries = self.items.into_iter().map(|item| match item.kind {
+            MenuItemKind::Item(on_select) => {
+                let id = handlers.len();
+                handlers.push(on_select);
+
+                MenuEntry::Item {
+                    id,
+                    label: item.label,
+                    accelerator: item.accelerator,
+                    enabled: item.enabled,
+                    checked: item.checked,
+                }
+            }
+
+            MenuItemKind::Submenu(menu) => MenuEntry::Submenu {
+                label:   item.label,
+                enabled: item.enabled,
+                menu:    menu.into_model_with(handlers),
+            },
+
+            MenuItemKind::Separator => MenuEntry::Separator,
+        });
+
+        MenuModel {
+            entries: entries.collect(),
+        }
+    }
+}
+
+pub(crate) type MenuHandler<T> = Box<dyn FnMut(&mut T) -> Action>;
+
+pub struct MenuItem<T> {
+    label:       String,
+    accelerator: Option<String>,
+    enabled:     bool,
+    checked:     Option<bool>,
+    kind:        MenuItemKind<T>,
+}
+
+enum MenuItemKind<T> {
+    Item(MenuHandler<T>),
+    Submenu(Menu<T>),
+    Separator,
+}
+
+impl<T> MenuItem<T> {
+    /// Create an item that calls `on_select` when selected.
+    pub fn new<A>(
+        label: impl Into<String>,
+        mut on_select: impl FnMut(&mut T) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        Self::with_kind(
+            label.into(),
+            MenuItemKind::Item(Box::new(move |data| {
+                on_select(data).into()
+            })),
+        )
+    }
+
+    pub fn submenu(label: impl Into<String>, menu: Menu<T>) -> Self {
+        Self::with_kind(
+            label.into(),
+            MenuItemKind::Submenu(menu),
+        )
+    }
+
+    pub fn separator() -> Self {
+        Self::with_kind(String::new(), MenuItemKind::Separator)
+    }
+
+    fn with_kind(label: String, kind: MenuItemKind<T>) -> Self {
+        Self {
+            label,
+            accelerator: None,
+            enabled: true,
+            checked: None,
+            kind,
+        }
+    }
+
+    /// Set the accelerator shown next to the item, e.g. `Ctrl+C`.
+    ///
+    /// In context menus this is only a hint, in a menubar it also triggers the item.
+    pub fn accelerator(mut self, accelerator: impl Into<String>) -> Self {
+        self.accelerator = Some(accelerator.into());
+        self
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Show a check mark next to the item, reflecting `checked`.
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+}
+
+/// The structure of a [`Menu`], as presented by the platform.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MenuModel {
+    pub entries: Vec<MenuEntry>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MenuEntry {
+    Item {
+        /// Identifies the item when it's selected, unique within the whole menu.
+        id:          usize,
+        label:       String,
+        accelerator: Option<String>,
+        enabled:     bool,
+        checked:     Option<bool>,
+    },
+
+    Submenu {
+        label:   String,
+        enabled: bool,
+        menu:    MenuModel,
+    },
+
+    Separator,
+}
@@ -0,0 +1,19 @@
+use crate::Platform;
+
+pub trait HasAppActions: Platform {
+    /// Add an action named `name` to the application, calling `on_activate` when it's
+    /// activated, e.g. by pressing `accelerator` in any window of the application.
+    ///
+    /// An existing action with the same name is replaced.
+    fn add_app_action(
+        &mut self,
+        name: &str,
+        accelerator: Option<&str>,
+        on_activate: impl Fn() + 'static,
+    );
+
+    fn remove_app_action(&mut self, name: &str);
+
+    /// Set whether the action named `name` can be activated.
+    fn set_app_action_enabled(&mut self, name: &str, enabled: bool);
+}
@@ -0,0 +1,13 @@
+use crate::{ClipboardContent, ClipboardKind, Platform};
+
+pub trait HasClipboard: Platform {
+    fn write_clipboard(&mut self, content: ClipboardContent);
+
+    /// Read content of `kind` from the clipboard, calling `on_read` when the read completes,
+    /// with `None` if the clipboard holds no such content.
+    fn read_clipboard(
+        &mut self,
+        kind: ClipboardKind,
+        on_read: impl FnOnce(Option<ClipboardContent>) + 'static,
+    );
+}
@@ -0,0 +1,20 @@
+use crate::{MenuModel, NativeWidget, Platform};
+
+pub trait HasContextMenu: Platform {
+    type ContextMenu: NativeContextMenu<Self>;
+}
+
+/// A widget wrapping contents, that shows a menu when the contents are right-clicked.
+pub trait NativeContextMenu<P>: NativeWidget<P>
+where
+    P: Platform,
+{
+    fn build(platform: &mut P, contents: &P::Widget) -> Self;
+    fn teardown(self, platform: &mut P);
+
+    fn set_size(&mut self, width: f32, height: f32);
+    fn set_menu(&mut self, platform: &mut P, menu: &MenuModel);
+
+    /// Set a callback for when the item with `id` is selected.
+    fn set_on_select(&mut self, on_select: impl Fn(usize) + 'static);
+}
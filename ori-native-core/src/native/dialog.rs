@@ -0,0 +1,22 @@
+use crate::native::{HasWindow, NativeWindow};
+
+pub trait HasDialog: HasWindow {
+    type Dialog: NativeDialog<Self>;
+}
+
+pub trait NativeDialog<P>: NativeWindow<P>
+where
+    P: HasWindow,
+{
+    /// Build a dialog, transient for the window named `owner`, see
+    /// [`NativeWindow::set_name`].
+    ///
+    /// Without an owner, or if no window has that name, the dialog is transient for the
+    /// active window of the application.
+    fn build(platform: &mut P, contents: &P::Widget, owner: Option<&str>, modal: bool) -> Self;
+
+    /// Make the dialog transient for the window named `owner`, see [`NativeDialog::build`].
+    fn set_owner(&mut self, platform: &mut P, owner: Option<&str>);
+
+    fn set_modal(&mut self, modal: bool);
+}
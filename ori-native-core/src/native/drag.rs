@@ -0,0 +1,67 @@
+use std::{any::Any, rc::Rc};
+
+use crate::{DragEvent, NativeWidget, Platform};
+
+pub trait HasDragGesture: Platform {
+    type DragGesture: NativeDragGesture<Self>;
+}
+
+/// A widget wrapping contents, that recognizes drags within its bounds.
+pub trait NativeDragGesture<P>: NativeWidget<P>
+where
+    P: Platform,
+{
+    fn build(platform: &mut P, contents: &P::Widget) -> Self;
+    fn teardown(self, platform: &mut P);
+
+    fn set_size(&mut self, width: f32, height: f32);
+
+    /// Set the distance the pointer must move before a drag starts.
+    fn set_threshold(&mut self, threshold: f32);
+    fn set_on_drag(&mut self, on_drag: impl Fn(DragEvent) + 'static);
+}
+
+pub trait HasDragSource: Platform {
+    type DragSource: NativeDragSource<Self>;
+}
+
+/// A widget wrapping contents, that can be dragged to a [`NativeDropTarget`] carrying a
+/// payload.
+pub trait NativeDragSource<P>: NativeWidget<P>
+where
+    P: Platform,
+{
+    fn build(platform: &mut P, contents: &P::Widget) -> Self;
+    fn teardown(self, platform: &mut P);
+
+    fn set_size(&mut self, width: f32, height: f32);
+
+    /// Set the payload delivered to the drop target, when dragged.
+    fn set_payload(&mut self, payload: Rc<dyn Any>);
+    fn set_on_drag_start(&mut self, on_drag_start: impl Fn() + 'static);
+
+    /// Set a callback for when the drag ends, with whether the payload was dropped.
+    fn set_on_drag_end(&mut self, on_drag_end: impl Fn(bool) + 'static);
+}
+
+pub trait HasDropTarget: Platform {
+    type DropTarget: NativeDropTarget<Self>;
+}
+
+/// A widget wrapping contents, that payloads of a [`NativeDragSource`] can be dropped on.
+pub trait NativeDropTarget<P>: NativeWidget<P>
+where
+    P: Platform,
+{
+    fn build(platform: &mut P, contents: &P::Widget) -> Self;
+    fn teardown(self, platform: &mut P);
+
+    fn set_size(&mut self, width: f32, height: f32);
+
+    /// Set a callback deciding whether a payload can be dropped on the target.
+    fn set_accept(&mut self, accept: impl Fn(&dyn Any) -> bool + 'static);
+
+    /// Set a callback for when an accepted payload is dragged in or out of the target.
+    fn set_on_hover(&mut self, on_hover: impl Fn(bool) + 'static);
+    fn set_on_drop(&mut self, on_drop: impl Fn(&dyn Any) + 'static);
+}
@@ -0,0 +1,27 @@
+use crate::{LayoutLeaf, NativeWidget, Platform, views::DropdownOption};
+
+pub trait HasDropdown: Platform {
+    type Dropdown: NativeDropdown<Self>;
+}
+
+pub trait NativeDropdown<P>: NativeWidget<P>
+where
+    P: Platform,
+{
+    fn build(platform: &mut P) -> Self;
+    fn teardown(self, platform: &mut P);
+
+    /// Set the options, this doesn't change the selected index.
+    fn set_options(&mut self, options: &[DropdownOption]);
+
+    /// Set the selected option, without calling the `on_select` callback.
+    fn set_selected(&mut self, selected: Option<usize>);
+
+    /// Set whether the options can be filtered by searching.
+    fn set_searchable(&mut self, searchable: bool);
+
+    /// Set the callback called when the user selects an option, which is never disabled.
+    fn set_on_select(&mut self, on_select: impl Fn(usize) + 'static);
+
+    fn get_layout(&mut self, platform: &mut P) -> impl LayoutLeaf<P>;
+}
@@ -0,0 +1,24 @@
+use crate::{ExternalDrop, NativeWidget, Platform};
+
+pub trait HasFileDrop: Platform {
+    type FileDrop: NativeFileDrop<Self>;
+}
+
+/// A widget wrapping contents, that files and text from other applications can be dropped
+/// on.
+pub trait NativeFileDrop<P>: NativeWidget<P>
+where
+    P: Platform,
+{
+    fn build(platform: &mut P, contents: &P::Widget) -> Self;
+    fn teardown(self, platform: &mut P);
+
+    fn set_size(&mut self, width: f32, height: f32);
+
+    /// Set whether files and text are accepted, a drop of neither is rejected.
+    fn set_accepts(&mut self, files: bool, text: bool);
+
+    /// Set a callback for when accepted data is dragged in or out of the widget.
+    fn set_on_hover(&mut self, on_hover: impl Fn(bool) + 'static);
+    fn set_on_drop(&mut self, on_drop: impl Fn(ExternalDrop) + 'static);
+}
@@ -25,6 +25,8 @@ where
 
     fn set_child_layout(&mut self, index: usize, x: f32, y: f32, width: f32, height: f32);
 
+    fn set_child_visible(&mut self, index: usize, visible: bool);
+
     fn set_background_color(&mut self, platform: &mut P, color: Color);
     fn set_border_color(&mut self, platform: &mut P, color: Color);
     fn set_border_width(&mut self, platform: &mut P, width: [f32; 4]);
@@ -96,6 +98,13 @@ where
         }
 
         for (index, child) in self.children.iter_mut().enumerate() {
+            // hidden children are laid out at zero size, which widgets might not shrink to
+            let visible = cx
+                .get_layout_style(child.node)
+                .is_ok_and(|style| style.display != taffy::Display::None);
+
+            self.group.set_child_visible(index, visible);
+
             if let Ok(layout) = cx.get_computed_layout(child.node) {
                 self.group.set_child_layout(
                     index,
@@ -0,0 +1,28 @@
+use crate::{KeyEvent, NativeWidget, Platform, Propagation};
+
+pub trait HasKeyHandler: Platform {
+    type KeyHandler: NativeKeyHandler<Self>;
+}
+
+/// A focusable widget wrapping contents, that receives the key events while the focus is
+/// within it.
+pub trait NativeKeyHandler<P>: NativeWidget<P>
+where
+    P: Platform,
+{
+    fn build(platform: &mut P, contents: &P::Widget) -> Self;
+    fn teardown(self, platform: &mut P);
+
+    fn set_size(&mut self, width: f32, height: f32);
+
+    /// Set the callbacks for key events.
+    ///
+    /// `on_key` sends the event to the view, the platform then handles the pending messages
+    /// before asking `propagation` whether the event propagates to the parents of the widget.
+    /// `propagation` returns `None` while the event hasn't been handled, it then propagates.
+    fn set_on_key(
+        &mut self,
+        on_key: impl Fn(KeyEvent) + 'static,
+        propagation: impl Fn() -> Option<Propagation> + 'static,
+    );
+}
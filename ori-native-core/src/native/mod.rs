@@ -1,15 +1,46 @@
+mod app_action;
+mod clipboard;
+mod context_menu;
+mod dialog;
+mod drag;
+mod dropdown;
+mod file_drop;
 mod group;
 mod image;
+mod key_handler;
+mod pointer_handler;
+mod popover;
 mod pressable;
+mod progress;
 mod scroll;
+mod shortcuts;
+mod slider;
 mod text;
 mod textinput;
+mod toggle;
 mod window;
 
+pub use app_action::HasAppActions;
+pub use clipboard::HasClipboard;
+pub use context_menu::{HasContextMenu, NativeContextMenu};
+pub use dialog::{HasDialog, NativeDialog};
+pub use drag::{
+    HasDragGesture, HasDragSource, HasDropTarget, NativeDragGesture, NativeDragSource,
+    NativeDropTarget,
+};
+pub use dropdown::{HasDropdown, NativeDropdown};
+pub use file_drop::{HasFileDrop, NativeFileDrop};
 pub use group::{Group, HasGroup, NativeGroup};
 pub use image::{HasImage, NativeImage};
+pub use key_handler::{HasKeyHandler, NativeKeyHandler};
+pub use pointer_handler::{HasPointerHandler, NativePointerHandler};
+pub use popover::{HasPopover, NativePopover, Placement};
 pub use pressable::{HasPressable, NativePressable, Press};
-pub use scroll::{HasScroll, NativeScroll};
+pub use progress::{HasProgress, HasSpinner, NativeProgress, NativeSpinner};
+pub use scroll::{HasScroll, NativeScroll, ScrollbarPolicy};
+pub use shortcuts::{HasShortcuts, NativeShortcuts, ShortcutScope};
+pub use slider::{HasSlider, NativeSlider};
 pub use text::{HasText, NativeText};
 pub use textinput::{HasTextInput, NativeTextInput};
-pub use window::{HasWindow, NativeWindow};
+pub use toggle::{HasToggle, NativeToggle, ToggleKind};
+pub use window::{HasWindow, NativeWindow, Screenshot};
@@ -0,0 +1,17 @@
+use crate::{NativeWidget, Platform, PointerEvent};
+
+pub trait HasPointerHandler: Platform {
+    type PointerHandler: NativePointerHandler<Self>;
+}
+
+/// A widget wrapping contents, that receives the pointer events within its bounds.
+pub trait NativePointerHandler<P>: NativeWidget<P>
+where
+    P: Platform,
+{
+    fn build(platform: &mut P, contents: &P::Widget) -> Self;
+    fn teardown(self, platform: &mut P);
+
+    fn set_size(&mut self, width: f32, height: f32);
+    fn set_on_pointer(&mut self, on_pointer: impl Fn(PointerEvent) + 'static);
+}
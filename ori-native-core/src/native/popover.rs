@@ -0,0 +1,43 @@
+use crate::{NativeWidget, Platform, element::NativeParent};
+
+pub trait HasPopover: Platform {
+    type Popover: NativePopover<Self>;
+}
+
+/// A widget wrapping an anchor, with contents shown in a popup next to it.
+///
+/// The anchor is child `0` and the contents are child `1`.
+pub trait NativePopover<P>: NativeWidget<P> + NativeParent<P>
+where
+    P: Platform,
+{
+    fn build(platform: &mut P, anchor: &P::Widget, contents: &P::Widget) -> Self;
+    fn teardown(self, platform: &mut P);
+
+    fn set_size(&mut self, width: f32, height: f32);
+    fn set_anchor_layout(&mut self, x: f32, y: f32, width: f32, height: f32);
+    fn set_contents_size(&mut self, width: f32, height: f32);
+
+    /// Set where the contents are placed relative to the anchor, and whether they may be
+    /// flipped to the opposite side when they don't fit.
+    fn set_placement(&mut self, placement: Placement, flip: bool);
+    fn set_open(&mut self, open: bool);
+
+    /// Set a callback for when the user dismisses the popover, e.g. by clicking outside of
+    /// it or pressing escape.
+    fn set_on_dismiss(&mut self, on_dismiss: impl Fn() + 'static);
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Placement {
+    Above,
+
+    #[default]
+    Below,
+
+    /// Before the anchor in the text direction, to the left in left-to-right text.
+    Start,
+
+    /// After the anchor in the text direction, to the right in left-to-right text.
+    End,
+}
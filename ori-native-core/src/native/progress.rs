@@ -0,0 +1,34 @@
+use crate::{LayoutLeaf, NativeWidget, Platform};
+
+pub trait HasProgress: Platform {
+    type Progress: NativeProgress<Self>;
+}
+
+pub trait NativeProgress<P>: NativeWidget<P>
+where
+    P: Platform,
+{
+    fn build(platform: &mut P) -> Self;
+    fn teardown(self, platform: &mut P);
+
+    /// Set the fraction that is done, from `0.0` to `1.0`.
+    fn set_fraction(&mut self, fraction: f32);
+
+    fn get_layout(&mut self, platform: &mut P) -> impl LayoutLeaf<P>;
+}
+
+pub trait HasSpinner: Platform {
+    type Spinner: NativeSpinner<Self>;
+}
+
+pub trait NativeSpinner<P>: NativeWidget<P>
+where
+    P: Platform,
+{
+    fn build(platform: &mut P) -> Self;
+    fn teardown(self, platform: &mut P);
+
+    fn set_spinning(&mut self, spinning: bool);
+
+    fn get_layout(&mut self, platform: &mut P) -> impl LayoutLeaf<P>;
+}
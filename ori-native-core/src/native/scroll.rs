@@ -1,4 +1,4 @@
-use crate::{Direction, NativeWidget, Platform, element::NativeParent};
+use crate::{NativeWidget, Platform, ScrollEvent, element::NativeParent};
 
 pub trait HasScroll: Platform {
     type Scroll: NativeScroll<Self>;
@@ -13,5 +13,45 @@ where
 
     fn set_size(&mut self, width: f32, height: f32);
 
-    fn set_direction(&mut self, direction: Direction);
+    fn set_scrollbars(&mut self, horizontal: ScrollbarPolicy, vertical: ScrollbarPolicy);
+
+    /// Set whether scrolling continues with momentum after a swipe on a touch device.
+    fn set_kinetic(&mut self, kinetic: bool);
+
+    /// Set the scroll offset.
+    ///
+    /// If the contents aren't large enough to reach the offset yet, e.g. because they
+    /// haven't been laid out, the offset is applied once they are, unless the user scrolls
+    /// in the meantime.
+    fn set_offset(&mut self, x: f32, y: f32);
+
+    /// Scroll to the end of the contents, the bottom right, and stay there as the contents
+    /// grow, until the user scrolls or the offset is set.
+    fn scroll_to_end(&mut self);
+
+    /// Scroll the least distance that makes a rectangle of the contents visible.
+    fn scroll_into_view(&mut self, x: f32, y: f32, width: f32, height: f32);
+
+    /// Set a callback for when the scroll offset changes.
+    fn set_on_scroll(&mut self, on_scroll: impl Fn(ScrollEvent) + 'static);
+}
+
+/// When the scrollbar of an axis is shown.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ScrollbarPolicy {
+    /// Shown when the contents overflow, in the style of the platform.
+    #[default]
+    Auto,
+
+    /// Always shown, even when the contents fit.
+    Always,
+
+    /// Drawn over the contents while scrolling, when the contents overflow.
+    ///
+    /// On platforms where this is a setting of the whole scroll, [`ScrollbarPolicy::Always`]
+    /// on the other axis takes precedence.
+    Overlay,
+
+    /// Never shown, and the axis doesn't scroll.
+    Never,
 }
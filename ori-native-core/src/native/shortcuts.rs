@@ -0,0 +1,31 @@
+use crate::{Accelerator, NativeWidget, Platform};
+
+pub trait HasShortcuts: Platform {
+    type Shortcuts: NativeShortcuts<Self>;
+}
+
+/// A widget wrapping contents, that handles keyboard shortcuts.
+pub trait NativeShortcuts<P>: NativeWidget<P>
+where
+    P: Platform,
+{
+    fn build(platform: &mut P, contents: &P::Widget) -> Self;
+    fn teardown(self, platform: &mut P);
+
+    fn set_size(&mut self, width: f32, height: f32);
+    fn set_shortcuts(&mut self, accelerators: &[Accelerator], scope: ShortcutScope);
+
+    /// Set a callback for when the shortcut at `index` of the accelerators is triggered.
+    fn set_on_shortcut(&mut self, on_shortcut: impl Fn(usize) + 'static);
+}
+
+/// Where the keyboard focus must be for a shortcut to trigger.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ShortcutScope {
+    /// The focus is within the contents.
+    #[default]
+    Focus,
+
+    /// The focus is anywhere in the window of the contents.
+    Window,
+}
@@ -0,0 +1,31 @@
+use crate::{Direction, LayoutLeaf, NativeWidget, Platform, views::SliderMark};
+
+pub trait HasSlider: Platform {
+    type Slider: NativeSlider<Self>;
+}
+
+pub trait NativeSlider<P>: NativeWidget<P>
+where
+    P: Platform,
+{
+    fn build(platform: &mut P, direction: Direction) -> Self;
+    fn teardown(self, platform: &mut P);
+
+    fn set_range(&mut self, min: f32, max: f32);
+
+    /// Set the step values snap to, `None` allows any value in the range.
+    fn set_step(&mut self, step: Option<f32>);
+
+    /// Set the value, without calling the `on_change` or `on_commit` callbacks.
+    fn set_value(&mut self, value: f32);
+    fn set_marks(&mut self, marks: &[SliderMark]);
+
+    /// Set the callback called whenever the user changes the value, e.g. while dragging.
+    fn set_on_change(&mut self, on_change: impl Fn(f32) + 'static);
+
+    /// Set the callback called when the user is done changing the value, e.g. when a drag
+    /// ends.
+    fn set_on_commit(&mut self, on_commit: impl Fn(f32) + 'static);
+
+    fn get_layout(&mut self, platform: &mut P) -> impl LayoutLeaf<P>;
+}
@@ -1,4 +1,7 @@
-use crate::{Font, LayoutLeaf, NativeWidget, Platform, views::Newline};
+use crate::{
+    Font, LayoutLeaf, NativeWidget, Platform,
+    views::{InputMode, InputPurpose, Newline},
+};
 
 pub trait HasTextInput: Platform {
     type TextInput: NativeTextInput<Self>;
@@ -8,7 +11,7 @@ pub trait NativeTextInput<P>: NativeWidget<P>
 where
     P: Platform,
 {
-    fn build(platform: &mut P) -> Self;
+    fn build(platform: &mut P, mode: InputMode) -> Self;
     fn teardown(self, platform: &mut P);
 
     fn set_on_change(&mut self, platform: &mut P, on_change: impl Fn(String) + 'static);
@@ -17,6 +20,11 @@ where
     fn set_newline(&mut self, platform: &mut P, newline: Newline);
     fn set_accept_tab(&mut self, platform: &mut P, accept_tab: bool);
 
+    /// Set the maximum number of characters, `None` allows any number.
+    fn set_max_length(&mut self, platform: &mut P, max_length: Option<usize>);
+    fn set_purpose(&mut self, platform: &mut P, purpose: InputPurpose);
+    fn set_editable(&mut self, platform: &mut P, editable: bool);
+
     fn set_font(&mut self, platform: &mut P, font: Font);
     fn set_text(&mut self, platform: &mut P, text: String);
     fn set_placeholder_font(&mut self, platform: &mut P, font: Font);
@@ -0,0 +1,32 @@
+use crate::{LayoutLeaf, NativeWidget, Platform, views::CheckState};
+
+pub trait HasToggle: Platform {
+    type Toggle: NativeToggle<Self>;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ToggleKind {
+    Checkbox,
+    Switch,
+    Radio,
+}
+
+pub trait NativeToggle<P>: NativeWidget<P>
+where
+    P: Platform,
+{
+    fn build(platform: &mut P, kind: ToggleKind) -> Self;
+    fn teardown(self, platform: &mut P);
+
+    /// Join the group of the radio `leader`, of which only one radio is checked at a time.
+    fn set_group(&mut self, leader: &Self);
+
+    /// Set the checked state, without calling the `on_toggle` callback.
+    fn set_checked(&mut self, checked: CheckState);
+    fn set_label(&mut self, label: String);
+
+    /// Set the callback called when the user toggles the widget, with whether it's checked.
+    fn set_on_toggle(&mut self, on_toggle: impl Fn(bool) + 'static);
+
+    fn get_layout(&mut self, platform: &mut P) -> impl LayoutLeaf<P>;
+}
@@ -1,6 +1,6 @@
 use std::time::Duration;
 
-use crate::{Platform, element::NativeParent};
+use crate::{MenuModel, Platform, element::NativeParent};
 
 pub trait HasWindow: Platform {
     type Window: NativeWindow<Self>;
@@ -14,6 +14,10 @@ where
 
     fn teardown(self, platform: &mut P);
 
+    /// Close the window, after which it's no longer shown and doesn't count as open, it's
+    /// still torn down when removed.
+    fn close(&mut self, platform: &mut P);
+
     fn get_size(&self) -> (u32, u32);
     fn get_min_size(&self) -> (Option<u32>, Option<u32>);
 
@@ -21,10 +25,50 @@ where
     fn set_on_resize(&mut self, on_resize: impl Fn() + 'static);
     fn set_on_close_requested(&mut self, on_close_requested: impl Fn() + 'static);
 
+    /// Set a callback for when the window is maximized, fullscreened or minimized, or stops
+    /// being so.
+    fn set_on_state_changed(&mut self, on_state_changed: impl Fn() + 'static);
+
+    /// Set a callback for when the item with `id` of the menubar is selected.
+    fn set_on_menu_select(&mut self, on_select: impl Fn(usize) + 'static);
+
     fn start_animating(&mut self);
     fn stop_animating(&mut self);
 
     fn set_min_size(&mut self, width: u32, height: u32);
     fn set_size(&mut self, width: u32, height: u32);
     fn set_resizable(&mut self, resizable: bool);
+    fn set_visible(&mut self, visible: bool);
+
+    /// Set the name of the window, which dialogs refer to it by, see
+    /// [`NativeDialog::build`](crate::native::NativeDialog::build).
+    fn set_name(&mut self, name: Option<&str>);
+
+    fn set_title(&mut self, title: &str);
+    fn set_icon_name(&mut self, icon_name: Option<&str>);
+    fn set_decorated(&mut self, decorated: bool);
+    fn set_menubar(&mut self, menu: Option<&MenuModel>);
+
+    fn is_maximized(&self) -> bool;
+    fn is_fullscreen(&self) -> bool;
+    fn is_minimized(&self) -> bool;
+
+    fn set_maximized(&mut self, maximized: bool);
+    fn set_fullscreen(&mut self, fullscreen: bool);
+    fn set_minimized(&mut self, minimized: bool);
+
+    /// Render the current contents of the window, if the platform supports it.
+    fn screenshot(&self) -> Option<Screenshot> {
+        None
+    }
+}
+
+/// The rendered contents of a window.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Screenshot {
+    pub width:  u32,
+    pub height: u32,
+
+    /// Non-premultiplied RGBA pixels, row by row from the top left.
+    pub pixels: Vec<u8>,
 }
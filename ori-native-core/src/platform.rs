@@ -5,3 +5,14 @@ pub trait Platform: Proxied + Sized + 'static {
 
     fn quit(&mut self);
 }
+
+/// When an application quits on its own.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum QuitPolicy {
+    /// Quit when no windows are left open.
+    #[default]
+    LastWindowClosed,
+
+    /// Never quit on its own, only when [`Platform::quit`] is called.
+    Never,
+}
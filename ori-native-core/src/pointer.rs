@@ -0,0 +1,56 @@
+use crate::Modifiers;
+
+/// A button of a pointer device.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PointerButton {
+    /// The main button, usually the left mouse button.
+    Primary,
+    /// The secondary button, usually the right mouse button.
+    Secondary,
+    Middle,
+    Back,
+    Forward,
+    /// Any other button, identified by a platform specific number.
+    Other(u32),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PointerEventKind {
+    Move,
+    Enter,
+    Leave,
+    Down(PointerButton),
+    Up(PointerButton),
+
+    /// The press of a button stopped being reported before it was released, e.g. because the
+    /// contents claimed it. No [`PointerEventKind::Up`] follows.
+    Cancel(PointerButton),
+
+    /// A scroll, in steps of the scroll wheel, positive down and to the right.
+    Scroll {
+        delta_x: f32,
+        delta_y: f32,
+    },
+}
+
+/// An event of a pointer device, like a mouse.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PointerEvent {
+    pub kind:      PointerEventKind,
+    /// The horizontal position, relative to the layout box of the view.
+    pub x:         f32,
+    /// The vertical position, relative to the layout box of the view.
+    pub y:         f32,
+    pub modifiers: Modifiers,
+}
+
+impl PointerEvent {
+    pub fn new(kind: PointerEventKind, x: f32, y: f32) -> Self {
+        Self {
+            kind,
+            x,
+            y,
+            modifiers: Modifiers::NONE,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+/// The scroll position of a [`Scroll`](crate::views::Scroll), reported when it changes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScrollEvent {
+    /// The horizontal distance scrolled from the start of the contents.
+    pub offset_x:        f32,
+    /// The vertical distance scrolled from the top of the contents.
+    pub offset_y:        f32,
+    /// The width of the visible part of the contents.
+    pub viewport_width:  f32,
+    /// The height of the visible part of the contents.
+    pub viewport_height: f32,
+    pub content_width:   f32,
+    pub content_height:  f32,
+}
+
+impl ScrollEvent {
+    /// Get the largest horizontal offset, where the right end of the contents is visible.
+    pub fn max_offset_x(&self) -> f32 {
+        (self.content_width - self.viewport_width).max(0.0)
+    }
+
+    /// Get the largest vertical offset, where the bottom of the contents is visible.
+    pub fn max_offset_y(&self) -> f32 {
+        (self.content_height - self.viewport_height).max(0.0)
+    }
+
+    /// Check whether the contents are scrolled to the end, the bottom right.
+    ///
+    /// Offsets within half a pixel of the end count as the end.
+    pub fn is_at_end(&self) -> bool {
+        self.offset_x >= self.max_offset_x() - 0.5 && self.offset_y >= self.max_offset_y() - 0.5
+    }
+}
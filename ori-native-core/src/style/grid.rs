@@ -0,0 +1,390 @@
+use crate::{Align, ContainerLayout, Fraction, Justify};
+
+/// Create a track taking `amount` shares of the space left by the other tracks.
+pub fn fr(amount: f32) -> Track {
+    Track::Size(TrackSize::Flex(amount))
+}
+
+/// Create a track at least `min` and at most `max` in size.
+pub fn minmax(min: impl Into<TrackSize>, max: impl Into<TrackSize>) -> Track {
+    Track::MinMax(min.into(), max.into())
+}
+
+/// Create `tracks` repeated `count` times, see [`Repeat`].
+pub fn repeat(count: impl Into<Repeat>, tracks: impl IntoIterator<Item = Track>) -> TemplateTrack {
+    TemplateTrack::Repeat(
+        count.into(),
+        tracks.into_iter().collect(),
+    )
+}
+
+/// Create a [`GridPlacement`] spanning `tracks` tracks.
+pub fn span(tracks: u16) -> GridPlacement {
+    GridPlacement::Span(tracks)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TrackSize {
+    Length(f32),
+    Fraction(f32),
+
+    /// A share of the space left by the other tracks, only used as the maximum of a track.
+    Flex(f32),
+
+    Auto,
+    MinContent,
+    MaxContent,
+}
+
+impl From<f32> for TrackSize {
+    fn from(x: f32) -> Self {
+        TrackSize::Length(x)
+    }
+}
+
+impl From<Fraction> for TrackSize {
+    fn from(Fraction(x): Fraction) -> Self {
+        TrackSize::Fraction(x)
+    }
+}
+
+/// A row or column track of a grid.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Track {
+    Size(TrackSize),
+    MinMax(TrackSize, TrackSize),
+}
+
+impl Track {
+    pub const AUTO: Self = Track::Size(TrackSize::Auto);
+    pub const MIN_CONTENT: Self = Track::Size(TrackSize::MinContent);
+    pub const MAX_CONTENT: Self = Track::Size(TrackSize::MaxContent);
+}
+
+impl From<f32> for Track {
+    fn from(x: f32) -> Self {
+        Track::Size(TrackSize::Length(x))
+    }
+}
+
+impl From<Fraction> for Track {
+    fn from(Fraction(x): Fraction) -> Self {
+        Track::Size(TrackSize::Fraction(x))
+    }
+}
+
+impl From<TrackSize> for Track {
+    fn from(size: TrackSize) -> Self {
+        Track::Size(size)
+    }
+}
+
+/// A track of the rows or columns of a grid, or tracks repeated with [`repeat`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum TemplateTrack {
+    Single(Track),
+    Repeat(Repeat, Vec<Track>),
+}
+
+impl From<Track> for TemplateTrack {
+    fn from(track: Track) -> Self {
+        TemplateTrack::Single(track)
+    }
+}
+
+impl From<f32> for TemplateTrack {
+    fn from(x: f32) -> Self {
+        TemplateTrack::Single(Track::from(x))
+    }
+}
+
+impl From<Fraction> for TemplateTrack {
+    fn from(x: Fraction) -> Self {
+        TemplateTrack::Single(Track::from(x))
+    }
+}
+
+impl From<TrackSize> for TemplateTrack {
+    fn from(size: TrackSize) -> Self {
+        TemplateTrack::Single(Track::Size(size))
+    }
+}
+
+/// How many times the tracks of a [`repeat`] are repeated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Repeat {
+    Count(u16),
+
+    /// As many times as fit in the grid.
+    AutoFill,
+
+    /// As many times as fit in the grid, collapsing the tracks left empty.
+    AutoFit,
+}
+
+impl From<u16> for Repeat {
+    fn from(count: u16) -> Self {
+        Repeat::Count(count)
+    }
+}
+
+/// The line a grid item starts or ends at.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum GridPlacement {
+    #[default]
+    Auto,
+
+    /// A line, starting at `1`, negative lines count from the end of the grid.
+    Line(i16),
+
+    /// Span a number of tracks from the other end of the item.
+    Span(u16),
+
+    /// The edge of a named area, see [`GridLayout::areas`].
+    Area(String),
+}
+
+impl From<i16> for GridPlacement {
+    fn from(line: i16) -> Self {
+        GridPlacement::Line(line)
+    }
+}
+
+impl From<&str> for GridPlacement {
+    fn from(area: &str) -> Self {
+        GridPlacement::Area(area.to_owned())
+    }
+}
+
+impl From<String> for GridPlacement {
+    fn from(area: String) -> Self {
+        GridPlacement::Area(area)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GridFlow {
+    Row,
+    Column,
+    RowDense,
+    ColumnDense,
+}
+
+pub trait GridLayout: ContainerLayout {
+    fn rows(mut self, tracks: impl IntoIterator<Item = impl Into<TemplateTrack>>) -> Self {
+        self.style_mut().grid_template_rows = tracks
+            .into_iter()
+            .map(|track| track.into().into_taffy())
+            .collect();
+        self
+    }
+
+    fn columns(mut self, tracks: impl IntoIterator<Item = impl Into<TemplateTrack>>) -> Self {
+        self.style_mut().grid_template_columns = tracks
+            .into_iter()
+            .map(|track| track.into().into_taffy())
+            .collect();
+        self
+    }
+
+    /// Set the size of the rows created for items placed outside the rows of the grid.
+    fn auto_rows(mut self, tracks: impl IntoIterator<Item = Track>) -> Self {
+        self.style_mut().grid_auto_rows = tracks.into_iter().map(Track::into_taffy).collect();
+        self
+    }
+
+    /// Set the size of the columns created for items placed outside the columns of the grid.
+    fn auto_columns(mut self, tracks: impl IntoIterator<Item = Track>) -> Self {
+        self.style_mut().grid_auto_columns = tracks.into_iter().map(Track::into_taffy).collect();
+        self
+    }
+
+    fn auto_flow(mut self, flow: GridFlow) -> Self {
+        self.style_mut().grid_auto_flow = flow.into_taffy();
+        self
+    }
+
+    /// Name areas of the grid, with a string of space separated names for every row, e.g.
+    /// `["header header", "sidebar main"]`. A `.` leaves a cell unnamed.
+    ///
+    /// Items are placed in an area with [`Layout::grid_area`](crate::Layout::grid_area).
+    ///
+    /// # Panics
+    ///
+    /// If the cells of an area don't form a rectangle.
+    #[track_caller]
+    fn areas<'a>(mut self, rows: impl IntoIterator<Item = &'a str>) -> Self {
+        self.style_mut().grid_template_areas = parse_areas(rows);
+        self
+    }
+
+    fn align_items(mut self, align: Align) -> Self {
+        self.style_mut().align_items = Some(align.into_taffy());
+        self
+    }
+
+    fn justify_items(mut self, align: Align) -> Self {
+        self.style_mut().justify_items = Some(align.into_taffy());
+        self
+    }
+
+    fn align_contents(mut self, justify: Justify) -> Self {
+        self.style_mut().align_content = Some(justify.into_taffy());
+        self
+    }
+
+    fn justify_contents(mut self, justify: Justify) -> Self {
+        self.style_mut().justify_content = Some(justify.into_taffy());
+        self
+    }
+}
+
+#[track_caller]
+fn parse_areas<'a>(
+    rows: impl IntoIterator<Item = &'a str>,
+) -> Vec<taffy::GridTemplateArea<String>> {
+    let rows: Vec<Vec<&str>> = rows
+        .into_iter()
+        .map(|names| names.split_whitespace().collect())
+        .collect();
+
+    let mut areas: Vec<taffy::GridTemplateArea<String>> = Vec::new();
+
+    for (row, names) in rows.iter().enumerate() {
+        for (column, &name) in names.iter().enumerate() {
+            if name.chars().all(|c| c == '.') {
+                continue;
+            }
+
+            // lines start at 1, the area ends at the line after the cell
+            let (row, column) = (row as u16 + 1, column as u16 + 1);
+
+            match areas.iter_mut().find(|area| area.name == name) {
+                Some(area) => {
+                    area.row_start = area.row_start.min(row);
+                    area.row_end = area.row_end.max(row + 1);
+                    area.column_start = area.column_start.min(column);
+                    area.column_end = area.column_end.max(column + 1);
+                }
+
+                None => areas.push(taffy::GridTemplateArea {
+                    name:         name.to_owned(),
+                    row_start:    row,
+                    row_end:      row + 1,
+                    column_start: column,
+                    column_end:   column + 1,
+                }),
+            }
+        }
+    }
+
+    // every cell named by an area is in its bounds, so it's a rectangle if it fills them
+    for area in &areas {
+        let rectangle = (area.row_start..area.row_end).all(|row| {
+            (area.column_start..area.column_end).all(|column| {
+                let names = &rows[row as usize - 1];
+                names.get(column as usize - 1) == Some(&area.name.as_str())
+            })
+        });
+
+        assert!(
+            rectangle,
+            "grid area `{}` should be a rectangle",
+            area.name,
+        );
+    }
+
+    areas
+}
+
+impl TrackSize {
+    fn into_taffy_min(self) -> taffy::MinTrackSizingFunction {
+        match self {
+            TrackSize::Length(x) => taffy::MinTrackSizingFunction::length(x),
+            TrackSize::Fraction(x) => taffy::MinTrackSizingFunction::percent(x),
+            TrackSize::Flex(_) | TrackSize::Auto => taffy::MinTrackSizingFunction::auto(),
+            TrackSize::MinContent => taffy::MinTrackSizingFunction::min_content(),
+            TrackSize::MaxContent => taffy::MinTrackSizingFunction::max_content(),
+        }
+    }
+
+    fn into_taffy_max(self) -> taffy::MaxTrackSizingFunction {
+        match self {
+            TrackSize::Length(x) => taffy::MaxTrackSizingFunction::length(x),
+            TrackSize::Fraction(x) => taffy::MaxTrackSizingFunction::percent(x),
+            TrackSize::Flex(x) => taffy::MaxTrackSizingFunction::fr(x),
+            TrackSize::Auto => taffy::MaxTrackSizingFunction::auto(),
+            TrackSize::MinContent => taffy::MaxTrackSizingFunction::min_content(),
+            TrackSize::MaxContent => taffy::MaxTrackSizingFunction::max_content(),
+        }
+    }
+}
+
+impl Track {
+    fn into_taffy(self) -> taffy::TrackSizingFunction {
+        let (min, max) = match self {
+            Track::Size(size) => (size, size),
+            Track::MinMax(min, max) => (min, max),
+        };
+
+        taffy::MinMax {
+            min: min.into_taffy_min(),
+            max: max.into_taffy_max(),
+        }
+    }
+}
+
+impl TemplateTrack {
+    fn into_taffy(self) -> taffy::GridTemplateComponent<String> {
+        match self {
+            TemplateTrack::Single(track) => {
+                taffy::GridTemplateComponent::Single(track.into_taffy())
+            }
+
+            TemplateTrack::Repeat(count, tracks) => {
+                taffy::GridTemplateComponent::Repeat(taffy::GridTemplateRepetition {
+                    count:      count.into_taffy(),
+                    tracks:     tracks.into_iter().map(Track::into_taffy).collect(),
+                    line_names: Vec::new(),
+                })
+            }
+        }
+    }
+}
+
+impl Repeat {
+    fn into_taffy(self) -> taffy::RepetitionCount {
+        match self {
+            Repeat::Count(count) => taffy::RepetitionCount::Count(count),
+            Repeat::AutoFill => taffy::RepetitionCount::AutoFill,
+            Repeat::AutoFit => taffy::RepetitionCount::AutoFit,
+        }
+    }
+}
+
+impl GridPlacement {
+    pub(crate) fn into_taffy(self, end: bool) -> taffy::GridPlacement<String> {
+        match self {
+            GridPlacement::Auto => taffy::GridPlacement::Auto,
+            GridPlacement::Line(line) => taffy::style_helpers::line(line),
+            GridPlacement::Span(tracks) => taffy::GridPlacement::Span(tracks),
+
+            // areas define the lines `<name>-start` and `<name>-end`
+            GridPlacement::Area(name) => match end {
+                false => taffy::GridPlacement::NamedLine(format!("{name}-start"), 1),
+                true => taffy::GridPlacement::NamedLine(format!("{name}-end"), 1),
+            },
+        }
+    }
+}
+
+impl GridFlow {
+    fn into_taffy(self) -> taffy::GridAutoFlow {
+        match self {
+            GridFlow::Row => taffy::GridAutoFlow::Row,
+            GridFlow::Column => taffy::GridAutoFlow::Column,
+            GridFlow::RowDense => taffy::GridAutoFlow::RowDense,
+            GridFlow::ColumnDense => taffy::GridAutoFlow::ColumnDense,
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::GridPlacement;
+
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
 pub struct Fraction(pub f32);
 
@@ -74,6 +76,22 @@ pub enum Position {
     Absolute,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FlexWrap {
+    NoWrap,
+    Wrap,
+    WrapReverse,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BoxSizing {
+    /// The size includes the padding and border.
+    BorderBox,
+
+    /// The size excludes the padding and border.
+    ContentBox,
+}
+
 pub trait Layout: Sized {
     fn style_mut(&mut self) -> &mut taffy::Style;
 
@@ -82,12 +100,65 @@ pub trait Layout: Sized {
         self
     }
 
-    fn offset(mut self, x: impl Into<AutoLength>, y: impl Into<AutoLength>) -> Self {
-        self.style_mut().inset.left = x.into().into_taffy_length_auto();
+    fn offset(self, x: impl Into<AutoLength>, y: impl Into<AutoLength>) -> Self {
+        self.offset_left(x).offset_top(y)
+    }
+
+    fn offset_top(mut self, y: impl Into<AutoLength>) -> Self {
         self.style_mut().inset.top = y.into().into_taffy_length_auto();
         self
     }
 
+    fn offset_right(mut self, x: impl Into<AutoLength>) -> Self {
+        self.style_mut().inset.right = x.into().into_taffy_length_auto();
+        self
+    }
+
+    fn offset_bottom(mut self, y: impl Into<AutoLength>) -> Self {
+        self.style_mut().inset.bottom = y.into().into_taffy_length_auto();
+        self
+    }
+
+    fn offset_left(mut self, x: impl Into<AutoLength>) -> Self {
+        self.style_mut().inset.left = x.into().into_taffy_length_auto();
+        self
+    }
+
+    fn offset_all(
+        self,
+        top: impl Into<AutoLength>,
+        right: impl Into<AutoLength>,
+        bottom: impl Into<AutoLength>,
+        left: impl Into<AutoLength>,
+    ) -> Self {
+        self.offset_top(top)
+            .offset_right(right)
+            .offset_bottom(bottom)
+            .offset_left(left)
+    }
+
+    /// Remove the item from the layout when `hidden` is `true`, it takes up no space and isn't
+    /// shown.
+    fn hidden(mut self, hidden: bool) -> Self {
+        self.style_mut().display = match hidden {
+            true => taffy::Display::None,
+            false => taffy::Display::Flex,
+        };
+
+        self
+    }
+
+    fn box_sizing(mut self, box_sizing: BoxSizing) -> Self {
+        self.style_mut().box_sizing = box_sizing.into_taffy();
+        self
+    }
+
+    /// Set the ratio of width to height, used when only one of them is known.
+    fn aspect_ratio(mut self, ratio: f32) -> Self {
+        self.style_mut().aspect_ratio = Some(ratio);
+        self
+    }
+
     fn size(self, width: impl Into<AutoLength>, height: impl Into<AutoLength>) -> Self {
         self.width(width).height(height)
     }
@@ -219,6 +290,52 @@ pub trait Layout: Sized {
         self.style_mut().flex_shrink = amount;
         self
     }
+
+    /// Set the size of the item along the main axis, before growing and shrinking.
+    fn flex_basis(mut self, basis: impl Into<AutoLength>) -> Self {
+        self.style_mut().flex_basis = basis.into().into_taffy_dimension();
+        self
+    }
+
+    /// Place the item in the rows of a grid from `start` to `end`, e.g. `grid_row(2, span(2))`.
+    fn grid_row(mut self, start: impl Into<GridPlacement>, end: impl Into<GridPlacement>) -> Self {
+        self.style_mut().grid_row = taffy::Line {
+            start: start.into().into_taffy(false),
+            end:   end.into().into_taffy(true),
+        };
+        self
+    }
+
+    /// Place the item in the columns of a grid from `start` to `end`, see [`Layout::grid_row`].
+    fn grid_column(
+        mut self,
+        start: impl Into<GridPlacement>,
+        end: impl Into<GridPlacement>,
+    ) -> Self {
+        self.style_mut().grid_column = taffy::Line {
+            start: start.into().into_taffy(false),
+            end:   end.into().into_taffy(true),
+        };
+        self
+    }
+
+    /// Place the item in a named area of a grid, see [`GridLayout::areas`](crate::GridLayout::areas).
+    fn grid_area(self, area: impl Into<String>) -> Self {
+        let area = area.into();
+
+        self.grid_row(area.as_str(), area.as_str())
+            .grid_column(area.as_str(), area.as_str())
+    }
+
+    fn align_self(mut self, align: Align) -> Self {
+        self.style_mut().align_self = Some(align.into_taffy());
+        self
+    }
+
+    fn justify_self(mut self, align: Align) -> Self {
+        self.style_mut().justify_self = Some(align.into_taffy());
+        self
+    }
 }
 
 pub trait BorderLayout: Layout {
@@ -267,6 +384,18 @@ pub trait ContainerLayout: Layout {
         self.style_mut().gap.height = self.style_mut().gap.width;
         self
     }
+
+    /// Set the gap between rows, of a grid or of wrapped flex lines.
+    fn row_gap(mut self, gap: impl Into<Length>) -> Self {
+        self.style_mut().gap.height = gap.into().into_taffy();
+        self
+    }
+
+    /// Set the gap between columns, of a grid or of wrapped flex lines.
+    fn column_gap(mut self, gap: impl Into<Length>) -> Self {
+        self.style_mut().gap.width = gap.into().into_taffy();
+        self
+    }
 }
 
 pub trait FlexLayout: ContainerLayout {
@@ -284,6 +413,11 @@ pub trait FlexLayout: ContainerLayout {
         self.style_mut().justify_content = Some(justify.into_taffy());
         self
     }
+
+    fn flex_wrap(mut self, wrap: FlexWrap) -> Self {
+        self.style_mut().flex_wrap = wrap.into_taffy();
+        self
+    }
 }
 
 impl AutoLength {
@@ -322,8 +456,27 @@ impl Position {
     }
 }
 
+impl FlexWrap {
+    fn into_taffy(self) -> taffy::FlexWrap {
+        match self {
+            FlexWrap::NoWrap => taffy::FlexWrap::NoWrap,
+            FlexWrap::Wrap => taffy::FlexWrap::Wrap,
+            FlexWrap::WrapReverse => taffy::FlexWrap::WrapReverse,
+        }
+    }
+}
+
+impl BoxSizing {
+    fn into_taffy(self) -> taffy::BoxSizing {
+        match self {
+            BoxSizing::BorderBox => taffy::BoxSizing::BorderBox,
+            BoxSizing::ContentBox => taffy::BoxSizing::ContentBox,
+        }
+    }
+}
+
 impl Align {
-    fn into_taffy(self) -> taffy::AlignItems {
+    pub(crate) fn into_taffy(self) -> taffy::AlignItems {
         match self {
             Align::Start => taffy::AlignItems::Start,
             Align::Center => taffy::AlignItems::Center,
@@ -337,7 +490,7 @@ impl Align {
 }
 
 impl Justify {
-    fn into_taffy(self) -> taffy::AlignContent {
+    pub(crate) fn into_taffy(self) -> taffy::AlignContent {
         match self {
             Justify::Start => taffy::AlignContent::Start,
             Justify::Center => taffy::AlignContent::Center,
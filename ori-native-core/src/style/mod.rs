@@ -1,12 +1,17 @@
 mod color;
+mod grid;
 mod layout;
 mod overflow;
 mod window;
 
 pub use color::Color;
+pub use grid::{
+    GridFlow, GridLayout, GridPlacement, Repeat, TemplateTrack, Track, TrackSize, fr, minmax,
+    repeat, span,
+};
 pub use layout::{
-    Align, AutoLength, BorderLayout, ContainerLayout, Direction, FlexLayout, Fraction, Justify,
-    Layout, Length, Position,
+    Align, AutoLength, BorderLayout, BoxSizing, ContainerLayout, Direction, FlexLayout, FlexWrap,
+    Fraction, Justify, Layout, Length, Position,
 };
 pub use overflow::Overflow;
 pub use window::Sizing;
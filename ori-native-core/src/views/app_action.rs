@@ -0,0 +1,155 @@
+use ori::{Action, Message, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{Context, native::HasAppActions};
+
+/// Create an action of the application named `name`, that calls `on_activate` when
+/// activated.
+///
+/// Unlike the items of a menubar, app actions aren't tied to a window, their accelerators
+/// work in every window of the application.
+pub fn app_action<T, A>(
+    name: impl Into<String>,
+    mut on_activate: impl FnMut(&mut T) -> A + 'static,
+) -> AppAction<T>
+where
+    A: Into<Action>,
+{
+    AppAction::new(name, move |data| {
+        on_activate(data).into()
+    })
+}
+
+/// An effect that adds an action to the application, for as long as it's in the view tree.
+#[allow(clippy::type_complexity)]
+pub struct AppAction<T> {
+    name:        String,
+    accelerator: Option<String>,
+    enabled:     bool,
+    on_activate: Box<dyn FnMut(&mut T) -> Action>,
+}
+
+impl<T> AppAction<T> {
+    pub fn new(
+        name: impl Into<String>,
+        on_activate: impl FnMut(&mut T) -> Action + 'static,
+    ) -> Self {
+        Self {
+            name:        name.into(),
+            accelerator: None,
+            enabled:     true,
+            on_activate: Box::new(on_activate),
+        }
+    }
+
+    /// Set the accelerator that activates the action, e.g. `Ctrl+Q`, see
+    /// [`Accelerator::parse`](crate::Accelerator::parse).
+    pub fn accelerator(mut self, accelerator: impl Into<String>) -> Self {
+        self.accelerator = Some(accelerator.into());
+        self
+    }
+
+    /// Set whether the action can be activated, defaults to `true`.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+enum AppActionMessage {
+    Activated,
+}
+
+impl<T> AppAction<T> {
+    fn add<P>(&self, cx: &mut Context<P>, view_id: ViewId)
+    where
+        P: HasAppActions + Proxied,
+    {
+        let proxy = cx.proxy();
+
+        cx.platform.add_app_action(
+            &self.name,
+            self.accelerator.as_deref(),
+            move || {
+                proxy.message(Message::new(
+                    AppActionMessage::Activated,
+                    view_id,
+                ));
+            },
+        );
+
+        if !self.enabled {
+            cx.platform.set_app_action_enabled(&self.name, false);
+        }
+    }
+}
+
+impl<T> ViewMarker for AppAction<T> {}
+impl<P, T> View<Context<P>, T> for AppAction<T>
+where
+    P: HasAppActions + Proxied,
+{
+    type Element = ();
+    type State = AppActionState<T>;
+
+    fn build(self, cx: &mut Context<P>, _data: &mut T) -> (Self::Element, Self::State) {
+        let view_id = ViewId::next();
+        self.add(cx, view_id);
+
+        let state = AppActionState {
+            view_id,
+            name: self.name,
+            accelerator: self.accelerator,
+            enabled: self.enabled,
+            on_activate: self.on_activate,
+        };
+
+        ((), state)
+    }
+
+    fn rebuild(
+        self,
+        _element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context<P>,
+        _data: &mut T,
+    ) {
+        if self.name != state.name || self.accelerator != state.accelerator {
+            cx.platform.remove_app_action(&state.name);
+            self.add(cx, state.view_id);
+        } else if self.enabled != state.enabled {
+            cx.platform.set_app_action_enabled(&self.name, self.enabled);
+        }
+
+        state.name = self.name;
+        state.accelerator = self.accelerator;
+        state.enabled = self.enabled;
+        state.on_activate = self.on_activate;
+    }
+
+    fn message(
+        _element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        _cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        match message.take_targeted(state.view_id) {
+            Some(AppActionMessage::Activated) => (state.on_activate)(data),
+            None => Action::new(),
+        }
+    }
+
+    fn teardown(_element: Self::Element, state: Self::State, cx: &mut Context<P>) {
+        cx.platform.remove_app_action(&state.name);
+    }
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct AppActionState<T> {
+    view_id:     ViewId,
+    name:        String,
+    accelerator: Option<String>,
+    enabled:     bool,
+    on_activate: Box<dyn FnMut(&mut T) -> Action>,
+}
@@ -0,0 +1,174 @@
+use std::sync::Mutex;
+
+use ori::{Action, Message, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{ClipboardContent, ClipboardKind, Context, native::HasClipboard};
+
+pub fn clipboard<T>() -> Clipboard<T> {
+    Clipboard::new()
+}
+
+/// Create a [`Message`] that writes `content` to the clipboard.
+///
+/// The message is handled by the first [`clipboard`] effect in the view tree.
+pub fn copy(content: impl Into<ClipboardContent>) -> Message {
+    ClipboardCommand::Write(content.into()).into_message()
+}
+
+/// Create a [`Message`] that reads content of `kind` from the clipboard.
+///
+/// The message is handled by the first [`clipboard`] effect in the view tree, the content is
+/// delivered to its handlers once the read completes.
+pub fn paste(kind: ClipboardKind) -> Message {
+    ClipboardCommand::Read(kind).into_message()
+}
+
+#[derive(Clone, Debug)]
+enum ClipboardCommand {
+    Write(ClipboardContent),
+    Read(ClipboardKind),
+}
+
+impl ClipboardCommand {
+    fn into_message(self) -> Message {
+        Message::new(
+            ClipboardRequest(Mutex::new(Some(self))),
+            None,
+        )
+    }
+}
+
+/// A [`ClipboardCommand`] that is taken by the first effect handling it, the message is
+/// delivered to every effect.
+struct ClipboardRequest(Mutex<Option<ClipboardCommand>>);
+
+/// An effect that gives the application access to the clipboard, through the [`copy`] and
+/// [`paste`] messages.
+#[allow(clippy::type_complexity)]
+pub struct Clipboard<T> {
+    on_text:  Box<dyn FnMut(&mut T, String) -> Action>,
+    on_image: Box<dyn FnMut(&mut T, Vec<u8>) -> Action>,
+}
+
+impl<T> Default for Clipboard<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clipboard<T> {
+    pub fn new() -> Self {
+        Self {
+            on_text:  Box::new(|_, _| Action::new()),
+            on_image: Box::new(|_, _| Action::new()),
+        }
+    }
+
+    /// Handle text read from the clipboard, with [`paste`] of [`ClipboardKind::Text`].
+    pub fn on_text<A>(mut self, mut on_text: impl FnMut(&mut T, String) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_text = Box::new(move |data, text| on_text(data, text).into());
+        self
+    }
+
+    /// Handle an image read from the clipboard, with [`paste`] of [`ClipboardKind::Image`].
+    ///
+    /// The image is encoded as PNG.
+    pub fn on_image<A>(mut self, mut on_image: impl FnMut(&mut T, Vec<u8>) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_image = Box::new(move |data, image| on_image(data, image).into());
+        self
+    }
+}
+
+enum ClipboardMessage {
+    Read(ClipboardContent),
+}
+
+impl<T> ViewMarker for Clipboard<T> {}
+impl<P, T> View<Context<P>, T> for Clipboard<T>
+where
+    P: HasClipboard + Proxied,
+{
+    type Element = ();
+    type State = ClipboardState<T>;
+
+    fn build(self, _cx: &mut Context<P>, _data: &mut T) -> (Self::Element, Self::State) {
+        let state = ClipboardState {
+            view_id:  ViewId::next(),
+            on_text:  self.on_text,
+            on_image: self.on_image,
+        };
+
+        ((), state)
+    }
+
+    fn rebuild(
+        self,
+        _element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        _cx: &mut Context<P>,
+        _data: &mut T,
+    ) {
+        state.on_text = self.on_text;
+        state.on_image = self.on_image;
+    }
+
+    fn message(
+        _element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        if let Some(ClipboardMessage::Read(content)) = message.take_targeted(state.view_id) {
+            return match content {
+                ClipboardContent::Text(text) => (state.on_text)(data, text),
+                ClipboardContent::Image(image) => (state.on_image)(data, image),
+            };
+        }
+
+        let command = match message.get::<ClipboardRequest>() {
+            Some(ClipboardRequest(command)) => command.lock().unwrap().take(),
+            None => None,
+        };
+
+        match command {
+            Some(ClipboardCommand::Write(content)) => {
+                cx.platform.write_clipboard(content);
+            }
+
+            Some(ClipboardCommand::Read(kind)) => {
+                let proxy = cx.proxy();
+                let view_id = state.view_id;
+
+                cx.platform.read_clipboard(kind, move |content| {
+                    if let Some(content) = content {
+                        proxy.message(Message::new(
+                            ClipboardMessage::Read(content),
+                            view_id,
+                        ));
+                    }
+                });
+            }
+
+            None => {}
+        }
+
+        Action::new()
+    }
+
+    fn teardown(_element: Self::Element, _state: Self::State, _cx: &mut Context<P>) {}
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct ClipboardState<T> {
+    view_id:  ViewId,
+    on_text:  Box<dyn FnMut(&mut T, String) -> Action>,
+    on_image: Box<dyn FnMut(&mut T, Vec<u8>) -> Action>,
+}
@@ -0,0 +1,148 @@
+use ori::{Action, Message, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{
+    Context, Lifecycle, Menu, MenuModel, NativeWidget, Pod, PodMut, WidgetView,
+    menu::MenuHandler,
+    native::{HasContextMenu, NativeContextMenu},
+};
+
+pub fn context_menu<V, T>(contents: V, menu: Menu<T>) -> ContextMenu<V, T> {
+    ContextMenu::new(contents, menu)
+}
+
+/// Shows a [`Menu`] when the contents are right-clicked.
+pub struct ContextMenu<V, T> {
+    contents: V,
+    menu:     Menu<T>,
+}
+
+impl<V, T> ContextMenu<V, T> {
+    pub fn new(contents: V, menu: Menu<T>) -> Self {
+        Self { contents, menu }
+    }
+}
+
+enum ContextMenuMessage {
+    Selected(usize),
+}
+
+impl<V, T> ViewMarker for ContextMenu<V, T> {}
+impl<P, T, V> View<Context<P>, T> for ContextMenu<V, T>
+where
+    P: HasContextMenu + Proxied,
+    V: WidgetView<P, T>,
+{
+    type Element = Pod<P, P::ContextMenu>;
+    type State = (V::Widget, ContextMenuState<T, V::State>);
+
+    fn build(self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
+        let (contents, state) = self.contents.build(cx, data);
+
+        let mut widget = P::ContextMenu::build(
+            &mut cx.platform,
+            contents.widget.widget(),
+        );
+
+        let view_id = ViewId::next();
+
+        widget.set_on_select({
+            let proxy = cx.proxy();
+
+            move |id| {
+                proxy.message(Message::new(
+                    ContextMenuMessage::Selected(id),
+                    view_id,
+                ));
+            }
+        });
+
+        let (model, handlers) = self.menu.into_model();
+        widget.set_menu(&mut cx.platform, &model);
+
+        let pod = Pod::new(contents.node, widget);
+
+        let state = ContextMenuState {
+            view_id,
+            model,
+            handlers,
+            state,
+        };
+
+        (pod, (contents.widget, state))
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) {
+        let (model, handlers) = self.menu.into_model();
+
+        if model != state.model {
+            element.widget.set_menu(&mut cx.platform, &model);
+            state.model = model;
+        }
+
+        state.handlers = handlers;
+
+        let pod = PodMut {
+            parent_node:   element.parent_node,
+            parent_widget: element.parent_widget,
+
+            index:  element.index,
+            node:   element.node,
+            widget: contents,
+        };
+
+        self.contents.rebuild(pod, &mut state.state, cx, data);
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        if let Some(ContextMenuMessage::Selected(id)) = message.take_targeted(state.view_id) {
+            return match state.handlers.get_mut(id) {
+                Some(on_select) => on_select(data),
+                None => Action::new(),
+            };
+        }
+
+        if let Some(Lifecycle::Layout) = message.get()
+            && let Ok(layout) = cx.get_computed_layout(*element.node)
+        {
+            (element.widget).set_size(layout.size.width, layout.size.height);
+        }
+
+        let pod = PodMut {
+            parent_node:   element.parent_node,
+            parent_widget: element.parent_widget,
+
+            index:  element.index,
+            node:   element.node,
+            widget: contents,
+        };
+
+        V::message(pod, &mut state.state, cx, data, message)
+    }
+
+    fn teardown(element: Self::Element, (contents, state): Self::State, cx: &mut Context<P>) {
+        let pod = Pod::new(element.node, contents);
+
+        V::teardown(pod, state.state, cx);
+        element.widget.teardown(&mut cx.platform);
+    }
+}
+
+#[doc(hidden)]
+pub struct ContextMenuState<T, S> {
+    view_id:  ViewId,
+    model:    MenuModel,
+    handlers: Vec<MenuHandler<T>>,
+    state:    S,
+}
@@ -0,0 +1,209 @@
+use std::any::Any;
+
+use ori::{Action, Message, Mut, Proxied, View, ViewId, ViewMarker};
+
+use crate::{
+    Context, Sizing, WidgetView,
+    native::{HasDialog, NativeDialog},
+    views::{Window, WindowState},
+};
+
+/// Create a dialog, `contents` is called with the id of the dialog, to [`respond`] to it.
+pub fn dialog<F, V, T>(contents: F) -> Dialog<F, T>
+where
+    F: FnOnce(ViewId) -> V,
+{
+    Dialog::new(contents)
+}
+
+/// Create a [`Message`] that delivers `response` to the [`Dialog::on_response`] handlers of
+/// the dialog with the id `dialog`.
+pub fn respond<R>(dialog: ViewId, response: R) -> Message
+where
+    R: Send + 'static,
+{
+    Message::new(DialogResponse(response), dialog)
+}
+
+/// The response of a dialog, see [`respond`].
+#[derive(Clone, Debug)]
+pub struct DialogResponse<R>(pub R);
+
+/// A window that is transient for another window, typically opened and closed by adding and
+/// removing it from the view tree.
+#[allow(clippy::type_complexity)]
+pub struct Dialog<F, T> {
+    window:      Window<F>,
+    owner:       Option<String>,
+    modal:       bool,
+    on_response: Vec<Box<dyn FnMut(&mut T, &mut Message, ViewId) -> Option<Action>>>,
+}
+
+impl<F, T> Dialog<F, T> {
+    pub fn new(contents: F) -> Self {
+        Self {
+            window:      Window::new(contents).sizing(Sizing::Content),
+            owner:       None,
+            modal:       true,
+            on_response: Vec::new(),
+        }
+    }
+
+    /// Set the sizing of the dialog, defaults to [`Sizing::Content`].
+    pub fn sizing(mut self, sizing: Sizing) -> Self {
+        self.window = self.window.sizing(sizing);
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.window = self.window.title(title);
+        self
+    }
+
+    /// Make the dialog transient for the window with `name`, see [`Window::name`].
+    ///
+    /// Without an owner the dialog is transient for the active window.
+    pub fn owner(mut self, name: impl Into<String>) -> Self {
+        self.owner = Some(name.into());
+        self
+    }
+
+    /// Set whether the dialog blocks input to the other windows, defaults to `true`.
+    pub fn modal(mut self, modal: bool) -> Self {
+        self.modal = modal;
+        self
+    }
+
+    /// Handle the user closing the dialog, e.g. by pressing the close button.
+    ///
+    /// See [`Window::on_close_requested`].
+    pub fn on_close<A>(mut self, on_close: impl FnMut(&mut T) -> A + 'static) -> Self
+    where
+        T: 'static,
+        A: Into<Action>,
+    {
+        self.window = self.window.on_close_requested(on_close);
+        self
+    }
+
+    /// Handle a response of type `R`, sent with [`respond`].
+    pub fn on_response<R, A>(
+        mut self,
+        mut on_response: impl FnMut(&mut T, R) -> A + 'static,
+    ) -> Self
+    where
+        R: Any + Send,
+        A: Into<Action>,
+    {
+        self.on_response.push(Box::new(
+            move |data, message, view_id| {
+                let response: Option<DialogResponse<R>> = message.take_targeted(view_id);
+                let DialogResponse(response) = response?;
+
+                Some(on_response(data, response).into())
+            },
+        ));
+        self
+    }
+}
+
+impl<F, T> ViewMarker for Dialog<F, T> {}
+impl<P, T, F, V> View<Context<P>, T> for Dialog<F, T>
+where
+    P: HasDialog + Proxied,
+    T: 'static,
+    F: FnOnce(ViewId) -> V,
+    V: WidgetView<P, T>,
+{
+    type Element = ();
+    type State = DialogState<P, T, V>;
+
+    fn build(self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
+        let view_id = ViewId::next();
+
+        let Self {
+            window,
+            owner,
+            modal,
+            on_response,
+        } = self;
+
+        let window = window.map_contents(|contents| contents(view_id));
+
+        let window = window.build_state(cx, data, |platform, contents| {
+            <P::Dialog as NativeDialog<P>>::build(
+                platform,
+                contents,
+                owner.as_deref(),
+                modal,
+            )
+        });
+
+        let state = DialogState {
+            view_id,
+            window,
+            owner,
+            modal,
+            on_response,
+        };
+
+        ((), state)
+    }
+
+    fn rebuild(
+        self,
+        _element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) {
+        let window = self.window.map_contents(|contents| contents(state.view_id));
+        window.rebuild_state(&mut state.window, cx, data);
+
+        if self.owner != state.owner {
+            (state.window.window).set_owner(&mut cx.platform, self.owner.as_deref());
+            state.owner = self.owner;
+        }
+
+        if self.modal != state.modal {
+            state.window.window.set_modal(self.modal);
+            state.modal = self.modal;
+        }
+
+        state.on_response = self.on_response;
+    }
+
+    fn message(
+        _element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        for on_response in &mut state.on_response {
+            if let Some(action) = on_response(data, message, state.view_id) {
+                return action;
+            }
+        }
+
+        state.window.message(cx, data, message)
+    }
+
+    fn teardown(_element: Self::Element, state: Self::State, cx: &mut Context<P>) {
+        state.window.teardown(cx);
+    }
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct DialogState<P, T, V>
+where
+    P: HasDialog,
+    V: WidgetView<P, T>,
+{
+    view_id:     ViewId,
+    window:      WindowState<P, T, V, P::Dialog>,
+    owner:       Option<String>,
+    modal:       bool,
+    on_response: Vec<Box<dyn FnMut(&mut T, &mut Message, ViewId) -> Option<Action>>>,
+}
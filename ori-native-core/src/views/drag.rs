@@ -0,0 +1,545 @@
+use std::{any::Any, marker::PhantomData, rc::Rc};
+
+use ori::{Action, Message, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{
+    Context, DragEvent, Lifecycle, NativeWidget, Pod, PodMut, WidgetView,
+    native::{
+        HasDragGesture, HasDragSource, HasDropTarget, NativeDragGesture, NativeDragSource,
+        NativeDropTarget,
+    },
+};
+
+pub fn on_drag<V, T, A>(
+    contents: V,
+    on_drag: impl FnMut(&mut T, DragEvent) -> A + 'static,
+) -> OnDrag<V, T>
+where
+    A: Into<Action>,
+{
+    OnDrag::new(contents, on_drag)
+}
+
+/// Recognizes drag gestures within the layout box of the contents.
+///
+/// Once a drag starts, the presses are claimed from the contents.
+#[allow(clippy::type_complexity)]
+pub struct OnDrag<V, T> {
+    contents:  V,
+    threshold: f32,
+    on_drag:   Box<dyn FnMut(&mut T, DragEvent) -> Action>,
+}
+
+impl<V, T> OnDrag<V, T> {
+    pub fn new<A>(contents: V, mut on_drag: impl FnMut(&mut T, DragEvent) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        Self {
+            contents,
+            threshold: 8.0,
+            on_drag: Box::new(move |data, event| on_drag(data, event).into()),
+        }
+    }
+
+    /// Set the distance the pointer must move before a drag starts, defaults to `8.0`.
+    pub fn threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+}
+
+enum OnDragMessage {
+    Drag(DragEvent),
+}
+
+impl<V, T> ViewMarker for OnDrag<V, T> {}
+impl<P, T, V> View<Context<P>, T> for OnDrag<V, T>
+where
+    P: HasDragGesture + Proxied,
+    V: WidgetView<P, T>,
+{
+    type Element = Pod<P, P::DragGesture>;
+    type State = (V::Widget, OnDragState<T, V::State>);
+
+    fn build(self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
+        let (contents, state) = self.contents.build(cx, data);
+
+        let mut widget = P::DragGesture::build(
+            &mut cx.platform,
+            contents.widget.widget(),
+        );
+
+        let view_id = ViewId::next();
+
+        widget.set_threshold(self.threshold);
+        widget.set_on_drag({
+            let proxy = cx.proxy();
+
+            move |event| {
+                proxy.message(Message::new(
+                    OnDragMessage::Drag(event),
+                    view_id,
+                ));
+            }
+        });
+
+        let pod = Pod::new(contents.node, widget);
+
+        let state = OnDragState {
+            view_id,
+            threshold: self.threshold,
+            on_drag: self.on_drag,
+            state,
+        };
+
+        (pod, (contents.widget, state))
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) {
+        if self.threshold != state.threshold {
+            element.widget.set_threshold(self.threshold);
+            state.threshold = self.threshold;
+        }
+
+        state.on_drag = self.on_drag;
+
+        let pod = PodMut {
+            parent_node:   element.parent_node,
+            parent_widget: element.parent_widget,
+
+            index:  element.index,
+            node:   element.node,
+            widget: contents,
+        };
+
+        self.contents.rebuild(pod, &mut state.state, cx, data);
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        if let Some(OnDragMessage::Drag(event)) = message.take_targeted(state.view_id) {
+            return (state.on_drag)(data, event);
+        }
+
+        if let Some(Lifecycle::Layout) = message.get()
+            && let Ok(layout) = cx.get_computed_layout(*element.node)
+        {
+            (element.widget).set_size(layout.size.width, layout.size.height);
+        }
+
+        let pod = PodMut {
+            parent_node:   element.parent_node,
+            parent_widget: element.parent_widget,
+
+            index:  element.index,
+            node:   element.node,
+            widget: contents,
+        };
+
+        V::message(pod, &mut state.state, cx, data, message)
+    }
+
+    fn teardown(element: Self::Element, (contents, state): Self::State, cx: &mut Context<P>) {
+        let pod = Pod::new(element.node, contents);
+
+        V::teardown(pod, state.state, cx);
+        element.widget.teardown(&mut cx.platform);
+    }
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct OnDragState<T, S> {
+    view_id:   ViewId,
+    threshold: f32,
+    on_drag:   Box<dyn FnMut(&mut T, DragEvent) -> Action>,
+    state:     S,
+}
+
+pub fn draggable<V, T, D>(contents: V, payload: D) -> Draggable<V, T, D> {
+    Draggable::new(contents, payload)
+}
+
+/// Contents that can be dragged to a [`DropTarget`] accepting payloads of type `D`.
+#[allow(clippy::type_complexity)]
+pub struct Draggable<V, T, D> {
+    contents:      V,
+    payload:       D,
+    on_drag_start: Box<dyn FnMut(&mut T) -> Action>,
+    on_drag_end:   Box<dyn FnMut(&mut T, bool) -> Action>,
+}
+
+impl<V, T, D> Draggable<V, T, D> {
+    pub fn new(contents: V, payload: D) -> Self {
+        Self {
+            contents,
+            payload,
+            on_drag_start: Box::new(|_| Action::new()),
+            on_drag_end: Box::new(|_, _| Action::new()),
+        }
+    }
+
+    pub fn on_drag_start<A>(mut self, mut on_drag_start: impl FnMut(&mut T) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_drag_start = Box::new(move |data| on_drag_start(data).into());
+        self
+    }
+
+    /// Handle the drag ending, with whether the payload was dropped on a target.
+    pub fn on_drag_end<A>(
+        mut self,
+        mut on_drag_end: impl FnMut(&mut T, bool) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_drag_end = Box::new(move |data, dropped| on_drag_end(data, dropped).into());
+        self
+    }
+}
+
+enum DraggableMessage {
+    Started,
+    Ended(bool),
+}
+
+impl<V, T, D> ViewMarker for Draggable<V, T, D> {}
+impl<P, T, V, D> View<Context<P>, T> for Draggable<V, T, D>
+where
+    P: HasDragSource + Proxied,
+    V: WidgetView<P, T>,
+    D: Any,
+{
+    type Element = Pod<P, P::DragSource>;
+    type State = (V::Widget, DraggableState<T, V::State>);
+
+    fn build(self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
+        let (contents, state) = self.contents.build(cx, data);
+
+        let mut widget = P::DragSource::build(
+            &mut cx.platform,
+            contents.widget.widget(),
+        );
+
+        let view_id = ViewId::next();
+
+        widget.set_payload(Rc::new(self.payload));
+
+        widget.set_on_drag_start({
+            let proxy = cx.proxy();
+
+            move || {
+                proxy.message(Message::new(
+                    DraggableMessage::Started,
+                    view_id,
+                ));
+            }
+        });
+
+        widget.set_on_drag_end({
+            let proxy = cx.proxy();
+
+            move |dropped| {
+                proxy.message(Message::new(
+                    DraggableMessage::Ended(dropped),
+                    view_id,
+                ));
+            }
+        });
+
+        let pod = Pod::new(contents.node, widget);
+
+        let state = DraggableState {
+            view_id,
+            on_drag_start: self.on_drag_start,
+            on_drag_end: self.on_drag_end,
+            state,
+        };
+
+        (pod, (contents.widget, state))
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) {
+        element.widget.set_payload(Rc::new(self.payload));
+
+        state.on_drag_start = self.on_drag_start;
+        state.on_drag_end = self.on_drag_end;
+
+        let pod = PodMut {
+            parent_node:   element.parent_node,
+            parent_widget: element.parent_widget,
+
+            index:  element.index,
+            node:   element.node,
+            widget: contents,
+        };
+
+        self.contents.rebuild(pod, &mut state.state, cx, data);
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        match message.take_targeted(state.view_id) {
+            Some(DraggableMessage::Started) => return (state.on_drag_start)(data),
+            Some(DraggableMessage::Ended(dropped)) => return (state.on_drag_end)(data, dropped),
+            None => {}
+        }
+
+        if let Some(Lifecycle::Layout) = message.get()
+            && let Ok(layout) = cx.get_computed_layout(*element.node)
+        {
+            (element.widget).set_size(layout.size.width, layout.size.height);
+        }
+
+        let pod = PodMut {
+            parent_node:   element.parent_node,
+            parent_widget: element.parent_widget,
+
+            index:  element.index,
+            node:   element.node,
+            widget: contents,
+        };
+
+        V::message(pod, &mut state.state, cx, data, message)
+    }
+
+    fn teardown(element: Self::Element, (contents, state): Self::State, cx: &mut Context<P>) {
+        let pod = Pod::new(element.node, contents);
+
+        V::teardown(pod, state.state, cx);
+        element.widget.teardown(&mut cx.platform);
+    }
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct DraggableState<T, S> {
+    view_id:       ViewId,
+    on_drag_start: Box<dyn FnMut(&mut T) -> Action>,
+    on_drag_end:   Box<dyn FnMut(&mut T, bool) -> Action>,
+    state:         S,
+}
+
+pub fn drop_target<D, V, T>(build: impl FnMut(&T, bool) -> V + 'static) -> DropTarget<D, V, T> {
+    DropTarget::new(build)
+}
+
+/// Contents that payloads of type `D` can be dropped on, from a [`Draggable`].
+///
+/// The contents are built with whether a payload is being dragged over them.
+#[allow(clippy::type_complexity)]
+pub struct DropTarget<D, V, T> {
+    build:    Box<dyn FnMut(&T, bool) -> V>,
+    on_drop:  Box<dyn FnMut(&mut T, D) -> Action>,
+    on_hover: Box<dyn FnMut(&mut T, bool) -> Action>,
+    marker:   PhantomData<fn(D)>,
+}
+
+impl<D, V, T> DropTarget<D, V, T> {
+    pub fn new(build: impl FnMut(&T, bool) -> V + 'static) -> Self {
+        Self {
+            build:    Box::new(build),
+            on_drop:  Box::new(|_, _| Action::new()),
+            on_hover: Box::new(|_, _| Action::new()),
+            marker:   PhantomData,
+        }
+    }
+
+    pub fn on_drop<A>(mut self, mut on_drop: impl FnMut(&mut T, D) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_drop = Box::new(move |data, payload| on_drop(data, payload).into());
+        self
+    }
+
+    /// Handle a payload being dragged in or out of the target.
+    pub fn on_hover<A>(mut self, mut on_hover: impl FnMut(&mut T, bool) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_hover = Box::new(move |data, hovered| on_hover(data, hovered).into());
+        self
+    }
+}
+
+enum DropTargetMessage<D> {
+    Hovered(bool),
+    Dropped(D),
+}
+
+impl<D, V, T> ViewMarker for DropTarget<D, V, T> {}
+impl<P, T, V, D> View<Context<P>, T> for DropTarget<D, V, T>
+where
+    P: HasDropTarget + Proxied,
+    V: WidgetView<P, T>,
+    D: Clone + Send + 'static,
+{
+    type Element = Pod<P, P::DropTarget>;
+    type State = (V::Widget, DropTargetState<P, T, V, D>);
+
+    fn build(mut self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
+        let view = (self.build)(data, false);
+        let (contents, state) = view.build(cx, data);
+
+        let mut widget = P::DropTarget::build(
+            &mut cx.platform,
+            contents.widget.widget(),
+        );
+
+        let view_id = ViewId::next();
+
+        widget.set_accept(|payload| payload.is::<D>());
+
+        widget.set_on_hover({
+            let proxy = cx.proxy();
+
+            move |hovered| {
+                proxy.message(Message::new(
+                    DropTargetMessage::<D>::Hovered(hovered),
+                    view_id,
+                ));
+            }
+        });
+
+        widget.set_on_drop({
+            let proxy = cx.proxy();
+
+            move |payload| {
+                if let Some(payload) = payload.downcast_ref::<D>() {
+                    proxy.message(Message::new(
+                        DropTargetMessage::Dropped(payload.clone()),
+                        view_id,
+                    ));
+                }
+            }
+        });
+
+        let pod = Pod::new(contents.node, widget);
+
+        let state = DropTargetState {
+            hovered: false,
+            view_id,
+            build: self.build,
+            on_drop: self.on_drop,
+            on_hover: self.on_hover,
+            state,
+        };
+
+        (pod, (contents.widget, state))
+    }
+
+    fn rebuild(
+        mut self,
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) {
+        let view = (self.build)(data, state.hovered);
+        let pod = PodMut {
+            parent_node:   element.parent_node,
+            parent_widget: element.parent_widget,
+
+            index:  element.index,
+            node:   element.node,
+            widget: contents,
+        };
+
+        view.rebuild(pod, &mut state.state, cx, data);
+        state.build = self.build;
+        state.on_drop = self.on_drop;
+        state.on_hover = self.on_hover;
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        if let Some(Lifecycle::Layout) = message.get()
+            && let Ok(layout) = cx.get_computed_layout(*element.node)
+        {
+            (element.widget).set_size(layout.size.width, layout.size.height);
+        }
+
+        let pod = PodMut {
+            parent_node:   element.parent_node,
+            parent_widget: element.parent_widget,
+
+            index:  element.index,
+            node:   element.node,
+            widget: contents,
+        };
+
+        if let Some(message) = message.take_targeted(state.view_id) {
+            let action = match message {
+                DropTargetMessage::Hovered(hovered) => {
+                    state.hovered = hovered;
+                    (state.on_hover)(data, hovered)
+                }
+
+                DropTargetMessage::Dropped(payload) => {
+                    state.hovered = false;
+                    (state.on_drop)(data, payload)
+                }
+            };
+
+            let view = (state.build)(data, state.hovered);
+            view.rebuild(pod, &mut state.state, cx, data);
+
+            action
+        } else {
+            V::message(pod, &mut state.state, cx, data, message)
+        }
+    }
+
+    fn teardown(element: Self::Element, (contents, state): Self::State, cx: &mut Context<P>) {
+        let pod = Pod::new(element.node, contents);
+
+        V::teardown(pod, state.state, cx);
+        element.widget.teardown(&mut cx.platform);
+    }
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct DropTargetState<P, T, V, D>
+where
+    P: HasDropTarget,
+    V: WidgetView<P, T>,
+{
+    hovered:  bool,
+    view_id:  ViewId,
+    build:    Box<dyn FnMut(&T, bool) -> V>,
+    on_drop:  Box<dyn FnMut(&mut T, D) -> Action>,
+    on_hover: Box<dyn FnMut(&mut T, bool) -> Action>,
+    state:    V::State,
+}
@@ -0,0 +1,219 @@
+use ori::{Action, Message, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{
+    Context, Layout, Pod,
+    native::{HasDropdown, NativeDropdown},
+};
+
+/// Create a dropdown of `options`, of which the one at `selected` is selected.
+///
+/// Options are strings, or [`DropdownOption`]s to disable some of them.
+pub fn dropdown<T>(
+    options: impl IntoIterator<Item = impl Into<DropdownOption>>,
+    selected: impl Into<Option<usize>>,
+) -> Dropdown<T> {
+    Dropdown::new(options, selected.into())
+}
+
+/// An option of a [`Dropdown`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DropdownOption {
+    pub label:    String,
+    pub disabled: bool,
+}
+
+impl DropdownOption {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label:    label.into(),
+            disabled: false,
+        }
+    }
+
+    /// Create an option that is shown, but can't be selected.
+    pub fn disabled(label: impl Into<String>) -> Self {
+        Self {
+            label:    label.into(),
+            disabled: true,
+        }
+    }
+}
+
+impl From<&str> for DropdownOption {
+    fn from(label: &str) -> Self {
+        DropdownOption::new(label)
+    }
+}
+
+impl From<String> for DropdownOption {
+    fn from(label: String) -> Self {
+        DropdownOption::new(label)
+    }
+}
+
+/// A dropdown selecting one of a list of options.
+///
+/// The selected option is bound to the data, [`Dropdown::on_select`] should update the data it
+/// was created from.
+#[allow(clippy::type_complexity)]
+pub struct Dropdown<T> {
+    layout:     taffy::Style,
+    options:    Vec<DropdownOption>,
+    selected:   Option<usize>,
+    searchable: bool,
+    on_select:  Box<dyn FnMut(&mut T, usize) -> Action>,
+}
+
+impl<T> Dropdown<T> {
+    pub fn new(
+        options: impl IntoIterator<Item = impl Into<DropdownOption>>,
+        selected: Option<usize>,
+    ) -> Self {
+        Self {
+            layout: Default::default(),
+            options: options.into_iter().map(Into::into).collect(),
+            selected,
+            searchable: false,
+            on_select: Box::new(|_, _| Action::new()),
+        }
+    }
+
+    /// Allow filtering the options by typing a search.
+    pub fn searchable(mut self, searchable: bool) -> Self {
+        self.searchable = searchable;
+        self
+    }
+
+    /// Handle the user selecting the option at an index.
+    pub fn on_select<A>(mut self, mut on_select: impl FnMut(&mut T, usize) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_select = Box::new(move |data, index| on_select(data, index).into());
+        self
+    }
+}
+
+impl<T> Layout for Dropdown<T> {
+    fn style_mut(&mut self) -> &mut taffy::Style {
+        &mut self.layout
+    }
+}
+
+enum DropdownMessage {
+    Selected(usize),
+}
+
+impl<T> ViewMarker for Dropdown<T> {}
+impl<P, T> View<Context<P>, T> for Dropdown<T>
+where
+    P: HasDropdown + Proxied,
+{
+    type Element = Pod<P, P::Dropdown>;
+    type State = DropdownState<T>;
+
+    fn build(self, cx: &mut Context<P>, _data: &mut T) -> (Self::Element, Self::State) {
+        let mut widget = P::Dropdown::build(&mut cx.platform);
+
+        widget.set_options(&self.options);
+        widget.set_selected(self.selected);
+        widget.set_searchable(self.searchable);
+
+        let view_id = ViewId::next();
+
+        widget.set_on_select({
+            let proxy = cx.proxy();
+
+            move |index| {
+                proxy.message(Message::new(
+                    DropdownMessage::Selected(index),
+                    view_id,
+                ));
+            }
+        });
+
+        let layout = widget.get_layout(&mut cx.platform);
+        let node = cx.new_layout_leaf(self.layout, layout);
+
+        let state = DropdownState {
+            view_id,
+            options: self.options,
+            selected: self.selected,
+            searchable: self.searchable,
+            on_select: self.on_select,
+        };
+
+        (Pod::new(node, widget), state)
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context<P>,
+        _data: &mut T,
+    ) {
+        let _ = cx.set_layout_style(*element.node, self.layout);
+
+        if self.searchable != state.searchable {
+            state.searchable = self.searchable;
+            element.widget.set_searchable(self.searchable);
+        }
+
+        if self.options == state.options && self.selected == state.selected {
+            state.on_select = self.on_select;
+            return;
+        }
+
+        if self.options != state.options {
+            element.widget.set_options(&self.options);
+            state.options = self.options;
+        }
+
+        // the selected option may have moved, even if the index didn't
+        state.selected = self.selected;
+        element.widget.set_selected(self.selected);
+
+        // the width depends on the selected option
+        let layout = element.widget.get_layout(&mut cx.platform);
+        let _ = cx.set_leaf_layout(*element.node, layout);
+
+        state.on_select = self.on_select;
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        match message.take_targeted(state.view_id) {
+            Some(DropdownMessage::Selected(index)) => {
+                state.selected = Some(index);
+
+                let layout = element.widget.get_layout(&mut cx.platform);
+                let _ = cx.set_leaf_layout(*element.node, layout);
+
+                (state.on_select)(data, index)
+            }
+
+            None => Action::new(),
+        }
+    }
+
+    fn teardown(element: Self::Element, _state: Self::State, cx: &mut Context<P>) {
+        element.widget.teardown(&mut cx.platform);
+        let _ = cx.remove_layout_node(element.node);
+    }
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct DropdownState<T> {
+    view_id:    ViewId,
+    options:    Vec<DropdownOption>,
+    selected:   Option<usize>,
+    searchable: bool,
+    on_select:  Box<dyn FnMut(&mut T, usize) -> Action>,
+}
@@ -0,0 +1,250 @@
+use std::path::PathBuf;
+
+use ori::{Action, Message, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{
+    Context, ExternalDrop, Lifecycle, NativeWidget, Pod, PodMut, WidgetView,
+    native::{HasFileDrop, NativeFileDrop},
+};
+
+pub fn file_drop<V, T>(build: impl FnMut(&T, bool) -> V + 'static) -> FileDrop<V, T> {
+    FileDrop::new(build)
+}
+
+/// Contents that files and text can be dropped on, from other applications.
+///
+/// The contents are built with whether accepted data is being dragged over them. Only the
+/// kinds of data with a handler are accepted.
+#[allow(clippy::type_complexity)]
+pub struct FileDrop<V, T> {
+    build:    Box<dyn FnMut(&T, bool) -> V>,
+    on_files: Option<Box<dyn FnMut(&mut T, Vec<PathBuf>) -> Action>>,
+    on_text:  Option<Box<dyn FnMut(&mut T, String) -> Action>>,
+    on_hover: Box<dyn FnMut(&mut T, bool) -> Action>,
+}
+
+impl<V, T> FileDrop<V, T> {
+    pub fn new(build: impl FnMut(&T, bool) -> V + 'static) -> Self {
+        Self {
+            build:    Box::new(build),
+            on_files: None,
+            on_text:  None,
+            on_hover: Box::new(|_, _| Action::new()),
+        }
+    }
+
+    /// Accept files, and handle the paths of the files dropped.
+    pub fn on_files<A>(
+        mut self,
+        mut on_files: impl FnMut(&mut T, Vec<PathBuf>) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_files = Some(Box::new(move |data, paths| {
+            on_files(data, paths).into()
+        }));
+        self
+    }
+
+    /// Accept text, and handle the text dropped.
+    pub fn on_text<A>(mut self, mut on_text: impl FnMut(&mut T, String) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_text = Some(Box::new(move |data, text| {
+            on_text(data, text).into()
+        }));
+        self
+    }
+
+    /// Handle accepted data being dragged in or out of the contents.
+    pub fn on_hover<A>(mut self, mut on_hover: impl FnMut(&mut T, bool) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_hover = Box::new(move |data, hovered| on_hover(data, hovered).into());
+        self
+    }
+}
+
+enum FileDropMessage {
+    Hovered(bool),
+    Dropped(ExternalDrop),
+}
+
+impl<V, T> ViewMarker for FileDrop<V, T> {}
+impl<P, T, V> View<Context<P>, T> for FileDrop<V, T>
+where
+    P: HasFileDrop + Proxied,
+    V: WidgetView<P, T>,
+{
+    type Element = Pod<P, P::FileDrop>;
+    type State = (V::Widget, FileDropState<P, T, V>);
+
+    fn build(mut self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
+        let view = (self.build)(data, false);
+        let (contents, state) = view.build(cx, data);
+
+        let mut widget = P::FileDrop::build(
+            &mut cx.platform,
+            contents.widget.widget(),
+        );
+
+        let view_id = ViewId::next();
+
+        widget.set_on_hover({
+            let proxy = cx.proxy();
+
+            move |hovered| {
+                proxy.message(Message::new(
+                    FileDropMessage::Hovered(hovered),
+                    view_id,
+                ));
+            }
+        });
+
+        widget.set_on_drop({
+            let proxy = cx.proxy();
+
+            move |drop| {
+                proxy.message(Message::new(
+                    FileDropMessage::Dropped(drop),
+                    view_id,
+                ));
+            }
+        });
+
+        let accepts = (
+            self.on_files.is_some(),
+            self.on_text.is_some(),
+        );
+        widget.set_accepts(accepts.0, accepts.1);
+
+        let pod = Pod::new(contents.node, widget);
+
+        let state = FileDropState {
+            hovered: false,
+            accepts,
+            view_id,
+            build: self.build,
+            on_files: self.on_files,
+            on_text: self.on_text,
+            on_hover: self.on_hover,
+            state,
+        };
+
+        (pod, (contents.widget, state))
+    }
+
+    fn rebuild(
+        mut self,
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) {
+        let accepts = (
+            self.on_files.is_some(),
+            self.on_text.is_some(),
+        );
+
+        if accepts != state.accepts {
+            (element.widget).set_accepts(accepts.0, accepts.1);
+            state.accepts = accepts;
+        }
+
+        let view = (self.build)(data, state.hovered);
+        let pod = PodMut {
+            parent_node:   element.parent_node,
+            parent_widget: element.parent_widget,
+
+            index:  element.index,
+            node:   element.node,
+            widget: contents,
+        };
+
+        view.rebuild(pod, &mut state.state, cx, data);
+        state.build = self.build;
+        state.on_files = self.on_files;
+        state.on_text = self.on_text;
+        state.on_hover = self.on_hover;
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        if let Some(Lifecycle::Layout) = message.get()
+            && let Ok(layout) = cx.get_computed_layout(*element.node)
+        {
+            (element.widget).set_size(layout.size.width, layout.size.height);
+        }
+
+        let pod = PodMut {
+            parent_node:   element.parent_node,
+            parent_widget: element.parent_widget,
+
+            index:  element.index,
+            node:   element.node,
+            widget: contents,
+        };
+
+        if let Some(message) = message.take_targeted(state.view_id) {
+            let action = match message {
+                FileDropMessage::Hovered(hovered) => {
+                    state.hovered = hovered;
+                    (state.on_hover)(data, hovered)
+                }
+
+                FileDropMessage::Dropped(drop) => {
+                    state.hovered = false;
+
+                    match (
+                        drop,
+                        &mut state.on_files,
+                        &mut state.on_text,
+                    ) {
+                        (ExternalDrop::Files(paths), Some(on_files), _) => on_files(data, paths),
+                        (ExternalDrop::Text(text), _, Some(on_text)) => on_text(data, text),
+                        _ => Action::new(),
+                    }
+                }
+            };
+
+            let view = (state.build)(data, state.hovered);
+            view.rebuild(pod, &mut state.state, cx, data);
+
+            action
+        } else {
+            V::message(pod, &mut state.state, cx, data, message)
+        }
+    }
+
+    fn teardown(element: Self::Element, (contents, state): Self::State, cx: &mut Context<P>) {
+        let pod = Pod::new(element.node, contents);
+
+        V::teardown(pod, state.state, cx);
+        element.widget.teardown(&mut cx.platform);
+    }
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct FileDropState<P, T, V>
+where
+    P: HasFileDrop,
+    V: WidgetView<P, T>,
+{
+    hovered:  bool,
+    accepts:  (bool, bool),
+    view_id:  ViewId,
+    build:    Box<dyn FnMut(&T, bool) -> V>,
+    on_files: Option<Box<dyn FnMut(&mut T, Vec<PathBuf>) -> Action>>,
+    on_text:  Option<Box<dyn FnMut(&mut T, String) -> Action>>,
+    on_hover: Box<dyn FnMut(&mut T, bool) -> Action>,
+    state:    V::State,
+}
@@ -0,0 +1,121 @@
+use ori::{Action, Message, Mut, View, ViewMarker, ViewSeq};
+
+use crate::{
+    BorderLayout, BoxedWidget, Color, ContainerLayout, Context, Direction, GridLayout, Layout,
+    Overflow, Pod,
+    native::{Group, HasGroup},
+    views::Flex,
+};
+
+/// Lay out `contents` in a grid, with the tracks set by [`GridLayout`].
+///
+/// Items are placed in the grid with [`Layout::grid_row`], [`Layout::grid_column`] and
+/// [`Layout::grid_area`], or placed in the next free cell.
+pub fn grid<V>(contents: V) -> Grid<V> {
+    Grid::new(contents)
+}
+
+pub struct Grid<V> {
+    flex: Flex<V>,
+}
+
+impl<V> Grid<V> {
+    pub fn new(contents: V) -> Self {
+        let mut flex = Flex::new(contents, Direction::Vertical);
+        flex.style_mut().display = taffy::Display::Grid;
+
+        Self { flex }
+    }
+
+    pub fn background_color(mut self, color: Color) -> Self {
+        self.flex = self.flex.background_color(color);
+        self
+    }
+
+    pub fn border_color(mut self, color: Color) -> Self {
+        self.flex = self.flex.border_color(color);
+        self
+    }
+
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.flex = self.flex.overflow(overflow);
+        self
+    }
+
+    pub fn corner(self, radius: f32) -> Self {
+        self.corners(radius, radius, radius, radius)
+    }
+
+    pub fn corners(
+        mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> Self {
+        self.flex = self.flex.corners(
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        );
+        self
+    }
+}
+
+impl<V> Layout for Grid<V> {
+    fn style_mut(&mut self) -> &mut taffy::Style {
+        self.flex.style_mut()
+    }
+
+    fn hidden(mut self, hidden: bool) -> Self {
+        self.style_mut().display = match hidden {
+            true => taffy::Display::None,
+            false => taffy::Display::Grid,
+        };
+
+        self
+    }
+}
+
+impl<V> ContainerLayout for Grid<V> {}
+impl<V> GridLayout for Grid<V> {}
+impl<V> BorderLayout for Grid<V> {}
+
+impl<V> ViewMarker for Grid<V> {}
+impl<P, T, V> View<Context<P>, T> for Grid<V>
+where
+    P: HasGroup,
+    V: ViewSeq<Context<P>, T, BoxedWidget<P>>,
+{
+    type Element = Pod<P, Group<P>>;
+    type State = V::State;
+
+    fn build(self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
+        self.flex.build(cx, data)
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) {
+        self.flex.rebuild(element, state, cx, data);
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        <Flex<V> as View<Context<P>, T>>::message(element, state, cx, data, message)
+    }
+
+    fn teardown(element: Self::Element, state: Self::State, cx: &mut Context<P>) {
+        <Flex<V> as View<Context<P>, T>>::teardown(element, state, cx);
+    }
+}
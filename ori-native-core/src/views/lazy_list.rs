@@ -0,0 +1,608 @@
+use std::{ops::Range, rc::Rc};
+
+use ori::{Action, Message, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{
+    Context, Layout, Lifecycle, NativeParent, NativeWidget, Platform, Pod, ScrollEvent, WidgetView,
+    native::{HasGroup, HasScroll, NativeGroup, NativeScroll, ScrollbarPolicy},
+};
+
+/// The number of items built beyond each edge of the visible part of a [`LazyList`].
+const OVERSCAN: usize = 4;
+
+pub fn lazy_list<V, T>(
+    count: usize,
+    item_height: impl Into<ItemHeight>,
+    build: impl FnMut(usize, &T) -> V + 'static,
+) -> LazyList<V, T> {
+    LazyList::new(count, item_height.into(), build)
+}
+
+/// The height of the items of a [`LazyList`].
+#[derive(Clone)]
+pub enum ItemHeight {
+    /// All items have the same height.
+    Fixed(f32),
+
+    /// The height of each item is computed from its index.
+    ///
+    /// The heights are only computed again when the count changes, or when given another
+    /// estimate, so keep the same [`ItemHeight`] around between rebuilds.
+    Estimated(Rc<dyn Fn(usize) -> f32>),
+}
+
+impl ItemHeight {
+    pub fn estimated(estimate: impl Fn(usize) -> f32 + 'static) -> Self {
+        ItemHeight::Estimated(Rc::new(estimate))
+    }
+}
+
+impl From<f32> for ItemHeight {
+    fn from(height: f32) -> Self {
+        ItemHeight::Fixed(height)
+    }
+}
+
+/// A vertically scrolling list of `count` items, of which only the visible ones are built.
+///
+/// Items that scroll out of view are rebuilt as the items scrolling into view, reusing their
+/// native widgets. Each item is placed at the offset given by the [`ItemHeight`] of the items
+/// before it, and should be as tall as its own.
+#[allow(clippy::type_complexity)]
+pub struct LazyList<V, T> {
+    style:       taffy::Style,
+    count:       usize,
+    item_height: ItemHeight,
+    build:       Box<dyn FnMut(usize, &T) -> V>,
+}
+
+impl<V, T> LazyList<V, T> {
+    pub fn new(
+        count: usize,
+        item_height: ItemHeight,
+        build: impl FnMut(usize, &T) -> V + 'static,
+    ) -> Self {
+        Self {
+            style: taffy::Style {
+                display: taffy::Display::Flex,
+                overflow: taffy::Point {
+                    x: taffy::Overflow::Hidden,
+                    y: taffy::Overflow::Scroll,
+                },
+                flex_direction: taffy::FlexDirection::Column,
+                ..Default::default()
+            },
+            count,
+            item_height,
+            build: Box::new(build),
+        }
+    }
+}
+
+impl<V, T> Layout for LazyList<V, T> {
+    fn style_mut(&mut self) -> &mut taffy::Style {
+        &mut self.style
+    }
+}
+
+/// The vertical extents of the items of a list.
+enum Extents {
+    Fixed {
+        count:  usize,
+        height: f32,
+    },
+
+    /// The offset of every item, followed by the total height.
+    Variable {
+        estimate: Rc<dyn Fn(usize) -> f32>,
+        offsets:  Vec<f32>,
+    },
+}
+
+impl Extents {
+    fn new(count: usize, item_height: &ItemHeight) -> Self {
+        match item_height {
+            ItemHeight::Fixed(height) => Extents::Fixed {
+                count,
+                height: *height,
+            },
+
+            ItemHeight::Estimated(estimate) => {
+                let mut extents = Extents::Variable {
+                    estimate: estimate.clone(),
+                    offsets:  vec![0.0],
+                };
+
+                extents.resize(count);
+                extents
+            }
+        }
+    }
+
+    /// Update the extents to `count` items of `item_height`, returning whether they changed.
+    ///
+    /// The offsets of estimated items are only computed for the added items, unless the
+    /// estimate changed.
+    fn update(&mut self, count: usize, item_height: &ItemHeight) -> bool {
+        match (&*self, item_height) {
+            (Extents::Fixed { height, .. }, ItemHeight::Fixed(new)) if height == new => {}
+            (Extents::Variable { estimate, .. }, ItemHeight::Estimated(new))
+                if Rc::ptr_eq(estimate, new) => {}
+            _ => {
+                *self = Extents::new(count, item_height);
+                return true;
+            }
+        }
+
+        if self.count() == count {
+            return false;
+        }
+
+        self.resize(count);
+        true
+    }
+
+    fn resize(&mut self, new_count: usize) {
+        match self {
+            Extents::Fixed { count, .. } => *count = new_count,
+
+            Extents::Variable { estimate, offsets } => {
+                offsets.truncate(new_count + 1);
+
+                while offsets.len() <= new_count {
+                    let index = offsets.len() - 1;
+                    offsets.push(offsets[index] + estimate(index));
+                }
+            }
+        }
+    }
+
+    fn count(&self) -> usize {
+        match self {
+            Extents::Fixed { count, .. } => *count,
+            Extents::Variable { offsets, .. } => offsets.len() - 1,
+        }
+    }
+
+    fn total(&self) -> f32 {
+        self.top(self.count())
+    }
+
+    fn top(&self, index: usize) -> f32 {
+        match self {
+            Extents::Fixed { height, .. } => index as f32 * height,
+            Extents::Variable { offsets, .. } => offsets[index],
+        }
+    }
+
+    fn height(&self, index: usize) -> f32 {
+        self.top(index + 1) - self.top(index)
+    }
+
+    /// Get the index of the item at `y`, clamped to the items.
+    fn index_at(&self, y: f32) -> usize {
+        let index = match self {
+            Extents::Fixed { height, .. } if *height > 0.0 => (y / height).max(0.0) as usize,
+            Extents::Fixed { .. } => 0,
+            Extents::Variable { offsets, .. } => offsets
+                .partition_point(|offset| *offset <= y)
+                .saturating_sub(1),
+        };
+
+        index.min(self.count().saturating_sub(1))
+    }
+
+    /// Get the range of items visible in a viewport at `offset` with `height`.
+    fn visible(&self, offset: f32, height: f32) -> Range<usize> {
+        if self.count() == 0 {
+            return 0..0;
+        }
+
+        let start = self.index_at(offset).saturating_sub(OVERSCAN);
+        let end = self.index_at(offset + height) + 1 + OVERSCAN;
+
+        start..end.min(self.count())
+    }
+}
+
+/// A built item, wrapped in a layout node placing it at its offset.
+struct Row<E, S> {
+    index:   usize,
+    wrapper: taffy::NodeId,
+    element: E,
+    state:   S,
+}
+
+/// The parent of the contents of a row, which is the group for the widget and the wrapper
+/// for the layout node.
+struct RowParent<'a, G> {
+    group: &'a mut G,
+    slot:  usize,
+}
+
+impl<P, G> NativeParent<P> for RowParent<'_, G>
+where
+    P: Platform,
+    G: NativeParent<P>,
+{
+    fn replace_child(&mut self, platform: &mut P, _index: usize, child: &P::Widget) {
+        self.group.replace_child(platform, self.slot, child);
+    }
+}
+
+fn row_style(top: f32, height: f32) -> taffy::Style {
+    taffy::Style {
+        display: taffy::Display::Flex,
+        position: taffy::Position::Absolute,
+        inset: taffy::Rect {
+            left:   taffy::LengthPercentageAuto::length(0.0),
+            right:  taffy::LengthPercentageAuto::length(0.0),
+            top:    taffy::LengthPercentageAuto::length(top),
+            bottom: taffy::LengthPercentageAuto::auto(),
+        },
+        size: taffy::Size {
+            width:  taffy::Dimension::auto(),
+            height: taffy::Dimension::length(height),
+        },
+        flex_direction: taffy::FlexDirection::Column,
+        ..Default::default()
+    }
+}
+
+fn content_style(height: f32) -> taffy::Style {
+    taffy::Style {
+        size: taffy::Size {
+            width:  taffy::Dimension::auto(),
+            height: taffy::Dimension::length(height),
+        },
+        flex_shrink: 0.0,
+        ..Default::default()
+    }
+}
+
+enum LazyListMessage {
+    Scrolled(ScrollEvent),
+}
+
+impl<V, T> ViewMarker for LazyList<V, T> {}
+impl<P, T, V> View<Context<P>, T> for LazyList<V, T>
+where
+    P: HasScroll + HasGroup + Proxied,
+    V: WidgetView<P, T>,
+{
+    type Element = Pod<P, P::Scroll>;
+    type State = LazyListState<P, T, V>;
+
+    fn build(self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
+        let extents = Extents::new(self.count, &self.item_height);
+
+        let content = cx.new_layout_node(content_style(extents.total()), &[]);
+        let node = cx.new_layout_node(self.style, &[content]);
+
+        let group = P::Group::build(&mut cx.platform);
+        let mut widget = P::Scroll::build(&mut cx.platform, group.widget());
+
+        let view_id = ViewId::next();
+
+        widget.set_scrollbars(
+            ScrollbarPolicy::Never,
+            ScrollbarPolicy::Auto,
+        );
+        widget.set_on_scroll({
+            let proxy = cx.proxy();
+
+            move |event| {
+                proxy.message(Message::new(
+                    LazyListMessage::Scrolled(event),
+                    view_id,
+                ));
+            }
+        });
+
+        let mut state = LazyListState {
+            view_id,
+            group,
+            content,
+            extents,
+            offset: 0.0,
+            viewport: 0.0,
+            build: self.build,
+            rows: Vec::new(),
+        };
+
+        state.update_rows(cx, data);
+
+        (Pod::new(node, widget), state)
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) {
+        let _ = cx.set_layout_style(*element.node, self.style);
+
+        state.build = self.build;
+
+        if state.extents.update(self.count, &self.item_height) {
+            let _ = cx.set_layout_style(
+                state.content,
+                content_style(state.extents.total()),
+            );
+        }
+
+        // rebuild the rows that stay visible, before the rows are recycled
+        state.rebuild_rows(cx, data);
+        state.update_rows(cx, data);
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        if let Some(LazyListMessage::Scrolled(event)) = message.take_targeted(state.view_id) {
+            state.offset = event.offset_y;
+            state.viewport = event.viewport_height;
+            state.update_rows(cx, data);
+
+            return Action::new();
+        }
+
+        if let Some(Lifecycle::Layout) = message.get() {
+            if let Ok(layout) = cx.get_computed_layout(*element.node) {
+                let (width, height) = (layout.size.width, layout.size.height);
+                element.widget.set_size(width, height);
+
+                if height != state.viewport {
+                    state.viewport = height;
+                    state.update_rows(cx, data);
+                }
+            }
+
+            state.layout(cx);
+        }
+
+        let mut action = Action::new();
+
+        let LazyListState { group, rows, .. } = state;
+
+        for (slot, row) in rows.iter_mut().enumerate() {
+            let mut parent = RowParent {
+                group: &mut *group,
+                slot,
+            };
+
+            let pod = row.element.as_mut(row.wrapper, &mut parent, 0);
+
+            action |= V::message(pod, &mut row.state, cx, data, message);
+        }
+
+        action
+    }
+
+    fn teardown(element: Self::Element, mut state: Self::State, cx: &mut Context<P>) {
+        for row in state.rows.drain(..) {
+            V::teardown(row.element, row.state, cx);
+            let _ = cx.remove_layout_node(row.wrapper);
+        }
+
+        state.group.teardown(&mut cx.platform);
+        element.widget.teardown(&mut cx.platform);
+
+        let _ = cx.remove_layout_node(state.content);
+        let _ = cx.remove_layout_node(element.node);
+    }
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct LazyListState<P, T, V>
+where
+    P: HasGroup,
+    V: WidgetView<P, T>,
+{
+    view_id:  ViewId,
+    group:    P::Group,
+    content:  taffy::NodeId,
+    extents:  Extents,
+    offset:   f32,
+    viewport: f32,
+    build:    Box<dyn FnMut(usize, &T) -> V>,
+    rows:     Vec<Row<Pod<P, V::Widget>, V::State>>,
+}
+
+impl<P, T, V> LazyListState<P, T, V>
+where
+    P: HasGroup,
+    V: WidgetView<P, T>,
+{
+    /// Build the rows that became visible, reusing the rows that are no longer visible.
+    fn update_rows(&mut self, cx: &mut Context<P>, data: &mut T) {
+        let visible = self.extents.visible(self.offset, self.viewport);
+
+        let mut free = Vec::new();
+
+        for (slot, row) in self.rows.iter().enumerate() {
+            if !visible.contains(&row.index) {
+                free.push(slot);
+            }
+        }
+
+        for index in visible {
+            if self.rows.iter().any(|row| row.index == index) {
+                continue;
+            }
+
+            let style = row_style(
+                self.extents.top(index),
+                self.extents.height(index),
+            );
+
+            let view = (self.build)(index, data);
+
+            if let Some(slot) = free.pop() {
+                let row = &mut self.rows[slot];
+                row.index = index;
+
+                let _ = cx.set_layout_style(row.wrapper, style);
+
+                let mut parent = RowParent {
+                    group: &mut self.group,
+                    slot,
+                };
+
+                let pod = row.element.as_mut(row.wrapper, &mut parent, 0);
+
+                view.rebuild(pod, &mut row.state, cx, data);
+            } else {
+                let (element, state) = view.build(cx, data);
+                let wrapper = cx.new_layout_node(style, &[element.node]);
+
+                let slot = self.rows.len();
+                let _ = cx.insert_layout_child(self.content, slot, wrapper);
+                self.group.insert_child(slot, element.widget.widget());
+
+                self.rows.push(Row {
+                    index,
+                    wrapper,
+                    element,
+                    state,
+                });
+            }
+        }
+
+        // remove the rows that weren't reused, from the back to keep the slots valid
+        for slot in free.into_iter().rev() {
+            let row = self.rows.remove(slot);
+
+            self.group.remove_child(slot);
+            let _ = cx.remove_layout_child(self.content, slot);
+
+            V::teardown(row.element, row.state, cx);
+            let _ = cx.remove_layout_node(row.wrapper);
+        }
+    }
+
+    /// Rebuild the rows that are still visible with the current data.
+    fn rebuild_rows(&mut self, cx: &mut Context<P>, data: &mut T) {
+        let count = self.extents.count();
+
+        for (slot, row) in self.rows.iter_mut().enumerate() {
+            // rows past the end are reused or removed by `update_rows`
+            if row.index >= count {
+                continue;
+            }
+
+            let style = row_style(
+                self.extents.top(row.index),
+                self.extents.height(row.index),
+            );
+
+            let _ = cx.set_layout_style(row.wrapper, style);
+
+            let view = (self.build)(row.index, data);
+
+            let mut parent = RowParent {
+                group: &mut self.group,
+                slot,
+            };
+
+            let pod = row.element.as_mut(row.wrapper, &mut parent, 0);
+
+            view.rebuild(pod, &mut row.state, cx, data);
+        }
+    }
+
+    fn layout(&mut self, cx: &mut Context<P>) {
+        if let Ok(layout) = cx.get_computed_layout(self.content) {
+            (self.group).set_size(layout.size.width, layout.size.height);
+        }
+
+        for (slot, row) in self.rows.iter().enumerate() {
+            let (Ok(wrapper), Ok(layout)) = (
+                cx.get_computed_layout(row.wrapper),
+                cx.get_computed_layout(row.element.node),
+            ) else {
+                continue;
+            };
+
+            self.group.set_child_layout(
+                slot,
+                wrapper.location.x + layout.location.x,
+                wrapper.location.y + layout.location.y,
+                layout.size.width,
+                layout.size.height,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn alternating() -> ItemHeight {
+        ItemHeight::estimated(|index| match index % 2 {
+            0 => 10.0,
+            _ => 30.0,
+        })
+    }
+
+    #[test]
+    fn index_at_is_clamped_to_items() {
+        let extents = Extents::new(10, &ItemHeight::Fixed(20.0));
+
+        assert_eq!(extents.index_at(-5.0), 0);
+        assert_eq!(extents.index_at(19.9), 0);
+        assert_eq!(extents.index_at(20.0), 1);
+        assert_eq!(extents.index_at(1000.0), 9);
+
+        let extents = Extents::new(4, &alternating());
+
+        assert_eq!(extents.index_at(9.9), 0);
+        assert_eq!(extents.index_at(10.0), 1);
+        assert_eq!(extents.index_at(45.0), 2);
+        assert_eq!(extents.index_at(1000.0), 3);
+        assert_eq!(extents.total(), 80.0);
+    }
+
+    #[test]
+    fn visible_includes_overscan() {
+        let extents = Extents::new(100, &ItemHeight::Fixed(20.0));
+
+        assert_eq!(extents.visible(0.0, 100.0), 0..10);
+        assert_eq!(extents.visible(400.0, 100.0), 16..30);
+        assert_eq!(extents.visible(1900.0, 100.0), 91..100);
+
+        let extents = Extents::new(0, &ItemHeight::Fixed(20.0));
+        assert_eq!(extents.visible(0.0, 100.0), 0..0);
+    }
+
+    #[test]
+    fn update_keeps_offsets_of_same_estimate() {
+        let item_height = alternating();
+        let mut extents = Extents::new(4, &item_height);
+
+        assert!(!extents.update(4, &item_height));
+
+        assert!(extents.update(6, &item_height));
+        assert_eq!(extents.count(), 6);
+        assert_eq!(extents.total(), 120.0);
+
+        assert!(extents.update(3, &item_height));
+        assert_eq!(extents.count(), 3);
+        assert_eq!(extents.total(), 50.0);
+
+        assert!(extents.update(3, &ItemHeight::estimated(|_| 5.0)));
+        assert_eq!(extents.total(), 15.0);
+
+        assert!(extents.update(3, &ItemHeight::Fixed(20.0)));
+        assert!(!extents.update(3, &ItemHeight::Fixed(20.0)));
+        assert_eq!(extents.total(), 60.0);
+    }
+}
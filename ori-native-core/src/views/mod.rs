@@ -1,20 +1,57 @@
 mod animate;
+mod app_action;
+mod clipboard;
+mod context_menu;
+mod dialog;
+mod drag;
+mod dropdown;
+mod file_drop;
 mod flex;
+mod grid;
 mod image;
+mod lazy_list;
+mod on_key;
+mod on_pointer;
+mod popover;
 mod pressable;
+mod progress;
 mod scroll;
+mod shortcuts;
+mod slider;
 mod text;
 mod textinput;
+mod toggle;
 mod transition;
 mod window;
 
 pub use animate::{Animate, animate};
+pub use app_action::{AppAction, AppActionState, app_action};
+pub use clipboard::{Clipboard, ClipboardState, clipboard, copy, paste};
+pub use context_menu::{ContextMenu, ContextMenuState, context_menu};
+pub use dialog::{Dialog, DialogResponse, DialogState, dialog, respond};
+pub use drag::{
+    Draggable, DraggableState, DropTarget, DropTargetState, OnDrag, OnDragState, draggable,
+    drop_target, on_drag,
+};
+pub use dropdown::{Dropdown, DropdownOption, DropdownState, dropdown};
+pub use file_drop::{FileDrop, FileDropState, file_drop};
 pub use flex::{Flex, column, row};
+pub use grid::{Grid, grid};
 pub use image::{Image, image};
+pub use lazy_list::{ItemHeight, LazyList, LazyListState, lazy_list};
+pub use on_key::{OnKey, OnKeyState, on_key};
+pub use on_pointer::{OnPointer, OnPointerState, on_pointer};
+pub use popover::{Popover, PopoverState, popover};
 pub use pressable::{PressState, Pressable, pressable};
-pub use scroll::{Scroll, hscroll, vscroll};
+pub use progress::{Progress, ProgressState, Spinner, progress, spinner};
+pub use scroll::{Scroll, ScrollState, hscroll, scroll, vscroll};
+pub use shortcuts::{Shortcuts, ShortcutsState, shortcuts};
+pub use slider::{Slider, SliderMark, SliderState, slider};
 pub use text::{Text, text};
-pub use textinput::{Newline, Submit, TextInput, textinput};
+pub use textinput::{InputMode, InputPurpose, Newline, Submit, TextInput, textinput};
+pub use toggle::{
+    CheckState, RadioGroup, RadioGroupState, Toggle, ToggleState, checkbox, radio_group, switch,
+};
 pub use transition::{
     Back, BackIn, BackInOut, Ease, Elastic, ElasticIn, Lerp, Linear, Transition, transition,
 };
@@ -0,0 +1,173 @@
+use std::{cell::Cell, rc::Rc};
+
+use ori::{Action, Message, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{
+    Context, KeyEvent, Lifecycle, NativeWidget, Pod, PodMut, Propagation, WidgetView,
+    native::{HasKeyHandler, NativeKeyHandler},
+};
+
+pub fn on_key<V, T, A>(
+    contents: V,
+    on_key: impl FnMut(&mut T, KeyEvent) -> (A, Propagation) + 'static,
+) -> OnKey<V, T>
+where
+    A: Into<Action>,
+{
+    OnKey::new(contents, on_key)
+}
+
+/// Handles the key events, while the focus is within the contents.
+///
+/// The handler returns whether the event propagates to the parents, e.g. stopping the arrow
+/// keys keeps them from moving the focus. Only the propagation of pressed keys can be stopped.
+///
+/// The contents are made focusable, and take the focus when clicked.
+#[allow(clippy::type_complexity)]
+pub struct OnKey<V, T> {
+    contents: V,
+    on_key:   Box<dyn FnMut(&mut T, KeyEvent) -> (Action, Propagation)>,
+}
+
+impl<V, T> OnKey<V, T> {
+    pub fn new<A>(
+        contents: V,
+        mut on_key: impl FnMut(&mut T, KeyEvent) -> (A, Propagation) + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        Self {
+            contents,
+            on_key: Box::new(move |data, event| {
+                let (action, propagation) = on_key(data, event);
+                (action.into(), propagation)
+            }),
+        }
+    }
+}
+
+enum OnKeyMessage {
+    Key(KeyEvent),
+}
+
+impl<V, T> ViewMarker for OnKey<V, T> {}
+impl<P, T, V> View<Context<P>, T> for OnKey<V, T>
+where
+    P: HasKeyHandler + Proxied,
+    V: WidgetView<P, T>,
+{
+    type Element = Pod<P, P::KeyHandler>;
+    type State = (V::Widget, OnKeyState<T, V::State>);
+
+    fn build(self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
+        let (contents, state) = self.contents.build(cx, data);
+
+        let mut widget = P::KeyHandler::build(
+            &mut cx.platform,
+            contents.widget.widget(),
+        );
+
+        let view_id = ViewId::next();
+        let propagation = Rc::new(Cell::new(None));
+
+        widget.set_on_key(
+            {
+                let proxy = cx.proxy();
+                let propagation = propagation.clone();
+
+                move |event| {
+                    propagation.set(None);
+                    proxy.message(Message::new(
+                        OnKeyMessage::Key(event),
+                        view_id,
+                    ));
+                }
+            },
+            {
+                let propagation = propagation.clone();
+                move || propagation.get()
+            },
+        );
+
+        let pod = Pod::new(contents.node, widget);
+
+        let state = OnKeyState {
+            view_id,
+            on_key: self.on_key,
+            propagation,
+            state,
+        };
+
+        (pod, (contents.widget, state))
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) {
+        state.on_key = self.on_key;
+
+        let pod = PodMut {
+            parent_node:   element.parent_node,
+            parent_widget: element.parent_widget,
+
+            index:  element.index,
+            node:   element.node,
+            widget: contents,
+        };
+
+        self.contents.rebuild(pod, &mut state.state, cx, data);
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        if let Some(OnKeyMessage::Key(event)) = message.take_targeted(state.view_id) {
+            let (action, propagation) = (state.on_key)(data, event);
+            state.propagation.set(Some(propagation));
+
+            return action;
+        }
+
+        if let Some(Lifecycle::Layout) = message.get()
+            && let Ok(layout) = cx.get_computed_layout(*element.node)
+        {
+            (element.widget).set_size(layout.size.width, layout.size.height);
+        }
+
+        let pod = PodMut {
+            parent_node:   element.parent_node,
+            parent_widget: element.parent_widget,
+
+            index:  element.index,
+            node:   element.node,
+            widget: contents,
+        };
+
+        V::message(pod, &mut state.state, cx, data, message)
+    }
+
+    fn teardown(element: Self::Element, (contents, state): Self::State, cx: &mut Context<P>) {
+        let pod = Pod::new(element.node, contents);
+
+        V::teardown(pod, state.state, cx);
+        element.widget.teardown(&mut cx.platform);
+    }
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct OnKeyState<T, S> {
+    view_id:     ViewId,
+    on_key:      Box<dyn FnMut(&mut T, KeyEvent) -> (Action, Propagation)>,
+    propagation: Rc<Cell<Option<Propagation>>>,
+    state:       S,
+}
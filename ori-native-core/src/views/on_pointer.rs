@@ -0,0 +1,152 @@
+use ori::{Action, Message, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{
+    Context, Lifecycle, NativeWidget, Pod, PodMut, PointerEvent, WidgetView,
+    native::{HasPointerHandler, NativePointerHandler},
+};
+
+pub fn on_pointer<V, T, A>(
+    contents: V,
+    on_pointer: impl FnMut(&mut T, PointerEvent) -> A + 'static,
+) -> OnPointer<V, T>
+where
+    A: Into<Action>,
+{
+    OnPointer::new(contents, on_pointer)
+}
+
+/// Handles the pointer events within the layout box of the contents.
+///
+/// The events are also delivered to the contents, e.g. a [`pressable`](crate::views::pressable)
+/// inside still receives presses.
+#[allow(clippy::type_complexity)]
+pub struct OnPointer<V, T> {
+    contents:   V,
+    on_pointer: Box<dyn FnMut(&mut T, PointerEvent) -> Action>,
+}
+
+impl<V, T> OnPointer<V, T> {
+    pub fn new<A>(
+        contents: V,
+        mut on_pointer: impl FnMut(&mut T, PointerEvent) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        Self {
+            contents,
+            on_pointer: Box::new(move |data, event| on_pointer(data, event).into()),
+        }
+    }
+}
+
+enum OnPointerMessage {
+    Pointer(PointerEvent),
+}
+
+impl<V, T> ViewMarker for OnPointer<V, T> {}
+impl<P, T, V> View<Context<P>, T> for OnPointer<V, T>
+where
+    P: HasPointerHandler + Proxied,
+    V: WidgetView<P, T>,
+{
+    type Element = Pod<P, P::PointerHandler>;
+    type State = (V::Widget, OnPointerState<T, V::State>);
+
+    fn build(self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
+        let (contents, state) = self.contents.build(cx, data);
+
+        let mut widget = P::PointerHandler::build(
+            &mut cx.platform,
+            contents.widget.widget(),
+        );
+
+        let view_id = ViewId::next();
+
+        widget.set_on_pointer({
+            let proxy = cx.proxy();
+
+            move |event| {
+                proxy.message(Message::new(
+                    OnPointerMessage::Pointer(event),
+                    view_id,
+                ));
+            }
+        });
+
+        let pod = Pod::new(contents.node, widget);
+
+        let state = OnPointerState {
+            view_id,
+            on_pointer: self.on_pointer,
+            state,
+        };
+
+        (pod, (contents.widget, state))
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) {
+        state.on_pointer = self.on_pointer;
+
+        let pod = PodMut {
+            parent_node:   element.parent_node,
+            parent_widget: element.parent_widget,
+
+            index:  element.index,
+            node:   element.node,
+            widget: contents,
+        };
+
+        self.contents.rebuild(pod, &mut state.state, cx, data);
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        if let Some(OnPointerMessage::Pointer(event)) = message.take_targeted(state.view_id) {
+            return (state.on_pointer)(data, event);
+        }
+
+        if let Some(Lifecycle::Layout) = message.get()
+            && let Ok(layout) = cx.get_computed_layout(*element.node)
+        {
+            (element.widget).set_size(layout.size.width, layout.size.height);
+        }
+
+        let pod = PodMut {
+            parent_node:   element.parent_node,
+            parent_widget: element.parent_widget,
+
+            index:  element.index,
+            node:   element.node,
+            widget: contents,
+        };
+
+        V::message(pod, &mut state.state, cx, data, message)
+    }
+
+    fn teardown(element: Self::Element, (contents, state): Self::State, cx: &mut Context<P>) {
+        let pod = Pod::new(element.node, contents);
+
+        V::teardown(pod, state.state, cx);
+        element.widget.teardown(&mut cx.platform);
+    }
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct OnPointerState<T, S> {
+    view_id:    ViewId,
+    on_pointer: Box<dyn FnMut(&mut T, PointerEvent) -> Action>,
+    state:      S,
+}
@@ -0,0 +1,260 @@
+use ori::{Action, Message, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{
+    Context, Layout, Lifecycle, NativeWidget, Pod, WidgetView,
+    native::{HasPopover, NativePopover, Placement},
+};
+
+pub fn popover<A, V, T>(anchor: A, contents: V) -> Popover<A, V, T> {
+    Popover::new(anchor, contents)
+}
+
+/// Contents shown in a popup next to an `anchor`, outside the clip of its parents.
+///
+/// The contents are laid out at their max-content size, independent of the layout of the
+/// window.
+#[allow(clippy::type_complexity)]
+pub struct Popover<A, V, T> {
+    anchor:     A,
+    contents:   V,
+    style:      taffy::Style,
+    open:       bool,
+    placement:  Placement,
+    flip:       bool,
+    on_dismiss: Box<dyn FnMut(&mut T) -> Action>,
+}
+
+impl<A, V, T> Popover<A, V, T> {
+    pub fn new(anchor: A, contents: V) -> Self {
+        Self {
+            anchor,
+            contents,
+            style: taffy::Style::default(),
+            open: false,
+            placement: Placement::Below,
+            flip: true,
+            on_dismiss: Box::new(|_| Action::new()),
+        }
+    }
+
+    /// Set whether the popover is open, defaults to `false`.
+    ///
+    /// The state is only applied when it changes, use [`Popover::on_dismiss`] to keep track
+    /// of the popover being dismissed by the user.
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    /// Set where the contents are placed relative to the anchor, defaults to
+    /// [`Placement::Below`].
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Set whether the contents are flipped to the opposite side of the anchor when they
+    /// don't fit, defaults to `true`.
+    ///
+    /// GTK always flips popovers that don't fit, since the compositor places them, so this
+    /// has no effect there.
+    pub fn flip(mut self, flip: bool) -> Self {
+        self.flip = flip;
+        self
+    }
+
+    /// Handle the user dismissing the popover, by clicking outside of it or pressing escape.
+    pub fn on_dismiss<B>(mut self, mut on_dismiss: impl FnMut(&mut T) -> B + 'static) -> Self
+    where
+        B: Into<Action>,
+    {
+        self.on_dismiss = Box::new(move |data| on_dismiss(data).into());
+        self
+    }
+}
+
+impl<A, V, T> Layout for Popover<A, V, T> {
+    fn style_mut(&mut self) -> &mut taffy::Style {
+        &mut self.style
+    }
+}
+
+enum PopoverMessage {
+    Dismissed,
+}
+
+impl<A, V, T> ViewMarker for Popover<A, V, T> {}
+impl<P, T, A, V> View<Context<P>, T> for Popover<A, V, T>
+where
+    P: HasPopover + Proxied,
+    A: WidgetView<P, T>,
+    V: WidgetView<P, T>,
+{
+    type Element = Pod<P, P::Popover>;
+    type State = PopoverState<P, T, A, V>;
+
+    fn build(self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
+        let (anchor, anchor_state) = self.anchor.build(cx, data);
+        let (contents, contents_state) = self.contents.build(cx, data);
+
+        let node = cx.new_layout_node(self.style, &[anchor.node]);
+        let root = cx.new_layout_node(Default::default(), &[contents.node]);
+
+        let mut widget = P::Popover::build(
+            &mut cx.platform,
+            anchor.widget.widget(),
+            contents.widget.widget(),
+        );
+
+        let view_id = ViewId::next();
+
+        widget.set_placement(self.placement, self.flip);
+        widget.set_open(self.open);
+
+        widget.set_on_dismiss({
+            let proxy = cx.proxy();
+
+            move || {
+                proxy.message(Message::new(
+                    PopoverMessage::Dismissed,
+                    view_id,
+                ));
+            }
+        });
+
+        let pod = Pod::new(node, widget);
+
+        let state = PopoverState {
+            view_id,
+            root,
+            open: self.open,
+            placement: self.placement,
+            flip: self.flip,
+            on_dismiss: self.on_dismiss,
+            anchor,
+            anchor_state,
+            contents,
+            contents_state,
+        };
+
+        (pod, state)
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) {
+        let _ = cx.set_layout_style(*element.node, self.style);
+
+        self.anchor.rebuild(
+            state.anchor.as_mut(*element.node, element.widget, 0),
+            &mut state.anchor_state,
+            cx,
+            data,
+        );
+
+        self.contents.rebuild(
+            state.contents.as_mut(state.root, element.widget, 1),
+            &mut state.contents_state,
+            cx,
+            data,
+        );
+
+        if self.placement != state.placement || self.flip != state.flip {
+            element.widget.set_placement(self.placement, self.flip);
+            state.placement = self.placement;
+            state.flip = self.flip;
+        }
+
+        if self.open != state.open {
+            element.widget.set_open(self.open);
+            state.open = self.open;
+        }
+
+        state.on_dismiss = self.on_dismiss;
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        if let Some(PopoverMessage::Dismissed) = message.take_targeted(state.view_id) {
+            return (state.on_dismiss)(data);
+        }
+
+        if let Some(Lifecycle::Layout) = message.get() {
+            if let Ok(layout) = cx.get_computed_layout(*element.node) {
+                (element.widget).set_size(layout.size.width, layout.size.height);
+            }
+
+            if let Ok(layout) = cx.get_computed_layout(state.anchor.node) {
+                element.widget.set_anchor_layout(
+                    layout.location.x,
+                    layout.location.y,
+                    layout.size.width,
+                    layout.size.height,
+                );
+            }
+
+            let _ = cx.compute_layout(state.root, taffy::Size::max_content());
+
+            if let Ok(layout) = cx.get_computed_layout(state.root) {
+                (element.widget).set_contents_size(layout.size.width, layout.size.height);
+            }
+        }
+
+        let mut action = A::message(
+            state.anchor.as_mut(*element.node, element.widget, 0),
+            &mut state.anchor_state,
+            cx,
+            data,
+            message,
+        );
+
+        action |= V::message(
+            state.contents.as_mut(state.root, element.widget, 1),
+            &mut state.contents_state,
+            cx,
+            data,
+            message,
+        );
+
+        action
+    }
+
+    fn teardown(element: Self::Element, state: Self::State, cx: &mut Context<P>) {
+        A::teardown(state.anchor, state.anchor_state, cx);
+        V::teardown(state.contents, state.contents_state, cx);
+
+        element.widget.teardown(&mut cx.platform);
+        let _ = cx.remove_layout_node(state.root);
+        let _ = cx.remove_layout_node(element.node);
+    }
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct PopoverState<P, T, A, V>
+where
+    P: HasPopover,
+    A: WidgetView<P, T>,
+    V: WidgetView<P, T>,
+{
+    view_id:    ViewId,
+    root:       taffy::NodeId,
+    open:       bool,
+    placement:  Placement,
+    flip:       bool,
+    on_dismiss: Box<dyn FnMut(&mut T) -> Action>,
+
+    anchor:         Pod<P, A::Widget>,
+    anchor_state:   A::State,
+    contents:       Pod<P, V::Widget>,
+    contents_state: V::State,
+}
@@ -0,0 +1,266 @@
+use ori::{Action, Message, Mut, View, ViewMarker};
+
+use crate::{
+    Context, Layout, Lifecycle, Pod,
+    native::{HasProgress, HasSpinner, NativeProgress, NativeSpinner},
+    views::{Lerp, Transition},
+};
+
+/// Create a progress bar, `fraction` is how much is done, from `0.0` to `1.0`.
+pub fn progress(fraction: f32) -> Progress {
+    Progress::new(fraction)
+}
+
+/// Create a spinner, for work of unknown length, it's paused while hidden.
+pub fn spinner() -> Spinner {
+    Spinner::new()
+}
+
+pub struct Progress {
+    style:      taffy::Style,
+    fraction:   f32,
+    transition: Option<Box<dyn Transition>>,
+}
+
+impl Progress {
+    pub fn new(fraction: f32) -> Self {
+        Self {
+            style:      Default::default(),
+            fraction:   fraction.clamp(0.0, 1.0),
+            transition: None,
+        }
+    }
+
+    /// Animate changes of the fraction with `transition`.
+    ///
+    /// A transition without a positive duration shows the new fraction right away.
+    pub fn transition(mut self, transition: impl Transition + 'static) -> Self {
+        self.transition = Some(Box::new(transition));
+        self
+    }
+}
+
+impl Layout for Progress {
+    fn style_mut(&mut self) -> &mut taffy::Style {
+        &mut self.style
+    }
+}
+
+impl ViewMarker for Progress {}
+impl<P, T> View<Context<P>, T> for Progress
+where
+    P: HasProgress,
+{
+    type Element = Pod<P, P::Progress>;
+    type State = ProgressState;
+
+    fn build(self, cx: &mut Context<P>, _data: &mut T) -> (Self::Element, Self::State) {
+        let mut widget = P::Progress::build(&mut cx.platform);
+        widget.set_fraction(self.fraction);
+
+        let layout = widget.get_layout(&mut cx.platform);
+        let node = cx.new_layout_leaf(self.style, layout);
+
+        let state = ProgressState {
+            from:       self.fraction,
+            target:     self.fraction,
+            time:       1.0,
+            transition: self.transition,
+        };
+
+        (Pod::new(node, widget), state)
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context<P>,
+        _data: &mut T,
+    ) {
+        let _ = cx.set_layout_style(*element.node, self.style);
+
+        state.transition = self.transition;
+
+        if self.fraction == state.target {
+            return;
+        }
+
+        let animated = (state.transition)
+            .as_ref()
+            .is_some_and(|transition| transition.duration() > 0.0);
+
+        if !animated {
+            if state.is_animating() {
+                cx.stop_animating();
+            }
+
+            state.from = self.fraction;
+            state.target = self.fraction;
+            state.time = 1.0;
+
+            element.widget.set_fraction(self.fraction);
+            return;
+        }
+
+        if !state.is_animating() {
+            cx.start_animating();
+        }
+
+        // start from where the bar is shown, which may be in the middle of a transition
+        state.from = state.fraction();
+        state.target = self.fraction;
+        state.time = 0.0;
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context<P>,
+        _data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        if let Some(Lifecycle::Animate(delta)) = message.get()
+            && state.is_animating()
+        {
+            let duration = state.transition.as_ref().map_or(0.0, |t| t.duration());
+
+            // a rebuild may have removed the transition, or replaced it with one without a duration
+            state.time = match duration > 0.0 {
+                true => (state.time + delta.as_secs_f32() / duration).clamp(0.0, 1.0),
+                false => 1.0,
+            };
+
+            element.widget.set_fraction(state.fraction());
+
+            if !state.is_animating() {
+                cx.stop_animating();
+            }
+        }
+
+        Action::new()
+    }
+
+    fn teardown(element: Self::Element, state: Self::State, cx: &mut Context<P>) {
+        if state.is_animating() {
+            cx.stop_animating();
+        }
+
+        element.widget.teardown(&mut cx.platform);
+        let _ = cx.remove_layout_node(element.node);
+    }
+}
+
+#[doc(hidden)]
+pub struct ProgressState {
+    from:       f32,
+    target:     f32,
+    time:       f32,
+    transition: Option<Box<dyn Transition>>,
+}
+
+impl ProgressState {
+    fn is_animating(&self) -> bool {
+        self.time < 1.0
+    }
+
+    /// The fraction currently shown.
+    fn fraction(&self) -> f32 {
+        match self.transition {
+            Some(ref transition) if self.is_animating() => {
+                let t = transition.curve(self.time);
+                f32::lerp(&self.from, &self.target, t)
+            }
+
+            _ => self.target,
+        }
+    }
+}
+
+pub struct Spinner {
+    style:    taffy::Style,
+    spinning: bool,
+}
+
+impl Spinner {
+    pub fn new() -> Self {
+        Self {
+            style:    Default::default(),
+            spinning: true,
+        }
+    }
+
+    /// Set whether the spinner spins, defaults to `true`.
+    ///
+    /// The spinner is also paused while it isn't shown, e.g. when a parent is hidden.
+    pub fn spinning(mut self, spinning: bool) -> Self {
+        self.spinning = spinning;
+        self
+    }
+}
+
+impl Default for Spinner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Layout for Spinner {
+    fn style_mut(&mut self) -> &mut taffy::Style {
+        &mut self.style
+    }
+}
+
+impl ViewMarker for Spinner {}
+impl<P, T> View<Context<P>, T> for Spinner
+where
+    P: HasSpinner,
+{
+    type Element = Pod<P, P::Spinner>;
+    type State = bool;
+
+    fn build(self, cx: &mut Context<P>, _data: &mut T) -> (Self::Element, Self::State) {
+        let spinning = self.spinning && self.style.display != taffy::Display::None;
+
+        let mut widget = P::Spinner::build(&mut cx.platform);
+        widget.set_spinning(spinning);
+
+        let layout = widget.get_layout(&mut cx.platform);
+        let node = cx.new_layout_leaf(self.style, layout);
+
+        (Pod::new(node, widget), spinning)
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        spinning: &mut Self::State,
+        cx: &mut Context<P>,
+        _data: &mut T,
+    ) {
+        // there's nothing to see of a hidden spinner, so it's paused
+        let should_spin = self.spinning && self.style.display != taffy::Display::None;
+
+        if *spinning != should_spin {
+            *spinning = should_spin;
+            element.widget.set_spinning(should_spin);
+        }
+
+        let _ = cx.set_layout_style(*element.node, self.style);
+    }
+
+    fn message(
+        _element: Mut<'_, Self::Element>,
+        _spinning: &mut Self::State,
+        _cx: &mut Context<P>,
+        _data: &mut T,
+        _message: &mut Message,
+    ) -> Action {
+        Action::new()
+    }
+
+    fn teardown(element: Self::Element, _spinning: Self::State, cx: &mut Context<P>) {
+        element.widget.teardown(&mut cx.platform);
+        let _ = cx.remove_layout_node(element.node);
+    }
+}
@@ -1,8 +1,10 @@
-use ori::{Action, Message, Mut, View, ViewMarker};
+use std::any::Any;
+
+use ori::{Action, Message, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
 
 use crate::{
-    Context, Direction, Layout, Lifecycle, NativeWidget, Pod, WidgetView,
-    native::{HasScroll, NativeScroll},
+    Context, Direction, Layout, Lifecycle, NativeWidget, Pod, ScrollEvent, WidgetView,
+    native::{HasScroll, NativeScroll, ScrollbarPolicy},
 };
 
 pub fn hscroll<V>(contents: V) -> Scroll<V> {
@@ -13,43 +15,184 @@ pub fn vscroll<V>(contents: V) -> Scroll<V> {
     Scroll::new(contents, Direction::Vertical)
 }
 
+/// Scroll the contents in both directions, e.g. a large table or image.
+pub fn scroll<V>(contents: V) -> Scroll<V> {
+    Scroll::both(contents)
+}
+
+type OnScroll<T> = Box<dyn FnMut(&mut T, ScrollEvent) -> Action>;
+
+/// A view scrolling its contents when they don't fit.
 pub struct Scroll<V> {
-    contents:  V,
-    style:     taffy::Style,
-    direction: Direction,
+    contents:         V,
+    style:            taffy::Style,
+    hscrollbar:       ScrollbarPolicy,
+    vscrollbar:       ScrollbarPolicy,
+    kinetic:          bool,
+    offset_x:         Option<f32>,
+    offset_y:         Option<f32>,
+    scroll_to_end:    bool,
+    scroll_into_view: Option<usize>,
+    on_scroll:        Option<Box<dyn Any>>,
 }
 
 impl<V> Scroll<V> {
+    /// Create a scroll in `direction`, the other axis doesn't scroll.
     pub fn new(contents: V, direction: Direction) -> Self {
         let flex_direction = match direction {
             Direction::Horizontal => taffy::FlexDirection::Row,
             Direction::Vertical => taffy::FlexDirection::Column,
         };
 
-        let overflow_x = match direction {
-            Direction::Horizontal => taffy::Overflow::Scroll,
-            Direction::Vertical => taffy::Overflow::Hidden,
+        let (hscrollbar, vscrollbar) = match direction {
+            Direction::Horizontal => (
+                ScrollbarPolicy::Auto,
+                ScrollbarPolicy::Never,
+            ),
+            Direction::Vertical => (
+                ScrollbarPolicy::Never,
+                ScrollbarPolicy::Auto,
+            ),
         };
 
-        let overflow_y = match direction {
-            Direction::Horizontal => taffy::Overflow::Hidden,
-            Direction::Vertical => taffy::Overflow::Scroll,
-        };
+        Self::with_scrollbars(
+            contents,
+            flex_direction,
+            hscrollbar,
+            vscrollbar,
+        )
+    }
+
+    /// Create a scroll in both directions.
+    pub fn both(contents: V) -> Self {
+        let mut scroll = Self::with_scrollbars(
+            contents,
+            taffy::FlexDirection::Column,
+            ScrollbarPolicy::Auto,
+            ScrollbarPolicy::Auto,
+        );
 
+        // keep the contents from being stretched to the width of the scroll
+        scroll.style.align_items = Some(taffy::AlignItems::Start);
+        scroll
+    }
+
+    fn with_scrollbars(
+        contents: V,
+        flex_direction: taffy::FlexDirection,
+        hscrollbar: ScrollbarPolicy,
+        vscrollbar: ScrollbarPolicy,
+    ) -> Self {
         Self {
             contents,
             style: taffy::Style {
                 display: taffy::Display::Flex,
                 overflow: taffy::Point {
-                    x: overflow_x,
-                    y: overflow_y,
+                    x: overflow(hscrollbar),
+                    y: overflow(vscrollbar),
                 },
                 flex_direction,
                 ..Default::default()
             },
-            direction,
+            hscrollbar,
+            vscrollbar,
+            kinetic: true,
+            offset_x: None,
+            offset_y: None,
+            scroll_to_end: false,
+            scroll_into_view: None,
+            on_scroll: None,
         }
     }
+
+    /// Set when the horizontal scrollbar is shown, [`ScrollbarPolicy::Never`] also keeps the
+    /// contents from scrolling horizontally.
+    pub fn hscrollbar(mut self, policy: ScrollbarPolicy) -> Self {
+        self.hscrollbar = policy;
+        self.style.overflow.x = overflow(policy);
+        self
+    }
+
+    /// Set when the vertical scrollbar is shown, [`ScrollbarPolicy::Never`] also keeps the
+    /// contents from scrolling vertically.
+    pub fn vscrollbar(mut self, policy: ScrollbarPolicy) -> Self {
+        self.vscrollbar = policy;
+        self.style.overflow.y = overflow(policy);
+        self
+    }
+
+    /// Set whether scrolling continues with momentum after a swipe, defaults to `true`.
+    pub fn kinetic(mut self, kinetic: bool) -> Self {
+        self.kinetic = kinetic;
+        self
+    }
+
+    /// Set the horizontal scroll offset.
+    ///
+    /// The offset is only applied when it differs from the current one, so it can be bound
+    /// to data updated by [`Scroll::on_scroll`], e.g. to restore the position of a list.
+    pub fn offset_x(mut self, offset: f32) -> Self {
+        self.offset_x = Some(offset);
+        self
+    }
+
+    /// Set the vertical scroll offset, see [`Scroll::offset_x`].
+    pub fn offset_y(mut self, offset: f32) -> Self {
+        self.offset_y = Some(offset);
+        self
+    }
+
+    /// Keep the contents scrolled to the end while `scroll_to_end` is `true`, e.g. to keep
+    /// the newest message of a chat log visible as messages are added.
+    ///
+    /// The contents are scrolled to the end when this turns `true`, and after that only when
+    /// they grow while scrolled to the end, so the user can still scroll back.
+    pub fn scroll_to_end(mut self, scroll_to_end: bool) -> Self {
+        self.scroll_to_end = scroll_to_end;
+        self
+    }
+
+    /// Scroll the child at `index` of the contents into view, e.g. an item of a
+    /// [`column`](crate::views::column). This happens when the scroll is built, and
+    /// whenever `index` changes.
+    pub fn scroll_into_view(mut self, index: impl Into<Option<usize>>) -> Self {
+        self.scroll_into_view = index.into();
+        self
+    }
+
+    /// Handle the scroll offset changing, by the user or otherwise.
+    pub fn on_scroll<T, A>(
+        mut self,
+        mut on_scroll: impl FnMut(&mut T, ScrollEvent) -> A + 'static,
+    ) -> Self
+    where
+        T: 'static,
+        A: Into<Action>,
+    {
+        let on_scroll: OnScroll<T> = Box::new(move |data, event| on_scroll(data, event).into());
+        self.on_scroll = Some(Box::new(on_scroll));
+        self
+    }
+}
+
+/// Take the [`Scroll::on_scroll`] handler, that is stored without the type of the data.
+#[track_caller]
+fn take_on_scroll<T>(on_scroll: Option<Box<dyn Any>>) -> Option<OnScroll<T>>
+where
+    T: 'static,
+{
+    on_scroll.map(|on_scroll| {
+        *on_scroll
+            .downcast()
+            .expect("the scroll handler should take the data of the view")
+    })
+}
+
+fn overflow(policy: ScrollbarPolicy) -> taffy::Overflow {
+    match policy {
+        ScrollbarPolicy::Never => taffy::Overflow::Hidden,
+        _ => taffy::Overflow::Scroll,
+    }
 }
 
 impl<V> Layout for Scroll<V> {
@@ -58,14 +201,19 @@ impl<V> Layout for Scroll<V> {
     }
 }
 
+enum ScrollMessage {
+    Scrolled(ScrollEvent),
+}
+
 impl<V> ViewMarker for Scroll<V> {}
 impl<P, T, V> View<Context<P>, T> for Scroll<V>
 where
-    P: HasScroll,
+    P: HasScroll + Proxied,
+    T: 'static,
     V: WidgetView<P, T>,
 {
     type Element = Pod<P, P::Scroll>;
-    type State = (V::Element, V::State);
+    type State = (V::Element, ScrollState<T, V::State>);
 
     fn build(self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
         let (contents, state) = self.contents.build(cx, data);
@@ -76,10 +224,44 @@ where
             contents.widget.widget(),
         );
 
-        widget.set_direction(self.direction);
+        let view_id = ViewId::next();
+
+        widget.set_scrollbars(self.hscrollbar, self.vscrollbar);
+        widget.set_kinetic(self.kinetic);
+        widget.set_on_scroll({
+            let proxy = cx.proxy();
+
+            move |event| {
+                proxy.message(Message::new(
+                    ScrollMessage::Scrolled(event),
+                    view_id,
+                ));
+            }
+        });
+
+        let offset = (
+            self.offset_x.unwrap_or(0.0),
+            self.offset_y.unwrap_or(0.0),
+        );
+
+        if offset != (0.0, 0.0) {
+            widget.set_offset(offset.0, offset.1);
+        }
 
         let pod = Pod::new(node, widget);
 
+        let state = ScrollState {
+            view_id,
+            offset,
+            scroll_to_end: self.scroll_to_end,
+            pending_to_end: self.scroll_to_end,
+            at_end: false,
+            scroll_into_view: self.scroll_into_view,
+            pending_into_view: self.scroll_into_view,
+            on_scroll: take_on_scroll(self.on_scroll),
+            state,
+        };
+
         (pod, (contents, state))
     }
 
@@ -91,11 +273,36 @@ where
         data: &mut T,
     ) {
         let _ = cx.set_layout_style(*element.node, self.style);
-        element.widget.set_direction(self.direction);
+        element
+            .widget
+            .set_scrollbars(self.hscrollbar, self.vscrollbar);
+        element.widget.set_kinetic(self.kinetic);
+
+        let offset = (
+            self.offset_x.unwrap_or(state.offset.0),
+            self.offset_y.unwrap_or(state.offset.1),
+        );
+
+        if offset != state.offset {
+            element.widget.set_offset(offset.0, offset.1);
+            state.offset = offset;
+        }
+
+        if self.scroll_into_view != state.scroll_into_view {
+            state.pending_into_view = self.scroll_into_view;
+            state.scroll_into_view = self.scroll_into_view;
+        }
+
+        if self.scroll_to_end && !state.scroll_to_end {
+            state.pending_to_end = true;
+        }
+
+        state.scroll_to_end = self.scroll_to_end;
+        state.on_scroll = take_on_scroll(self.on_scroll);
 
         self.contents.rebuild(
             contents.as_mut(*element.node, element.widget, 0),
-            state,
+            &mut state.state,
             cx,
             data,
         );
@@ -108,23 +315,71 @@ where
         data: &mut T,
         message: &mut Message,
     ) -> Action {
-        if let Some(Lifecycle::Layout) = message.get()
-            && let Ok(layout) = cx.get_computed_layout(*element.node)
-        {
+        if let Some(ScrollMessage::Scrolled(event)) = message.take_targeted(state.view_id) {
+            state.offset = (event.offset_x, event.offset_y);
+            state.at_end = event.is_at_end();
+
+            return match state.on_scroll {
+                Some(ref mut on_scroll) => on_scroll(data, event),
+                None => Action::new(),
+            };
+        }
+
+        let is_layout = matches!(message.get(), Some(Lifecycle::Layout));
+
+        if is_layout && let Ok(layout) = cx.get_computed_layout(*element.node) {
             (element.widget).set_size(layout.size.width, layout.size.height);
         }
 
-        V::message(
+        let action = V::message(
             contents.as_mut(*element.node, element.widget, 0),
-            state,
+            &mut state.state,
             cx,
             data,
             message,
-        )
+        );
+
+        // the contents are laid out by now, so they can be scrolled to
+        if is_layout {
+            // only follow the end if the contents were at the end before they grew, the
+            // user may have scrolled back
+            if state.scroll_to_end && (state.pending_to_end || state.at_end) {
+                element.widget.scroll_to_end();
+                state.pending_to_end = false;
+                state.at_end = true;
+            }
+
+            if let Some(index) = state.pending_into_view.take()
+                && let Ok(child) = cx.get_layout_child(contents.node, index)
+                && let Ok(layout) = cx.get_computed_layout(child)
+            {
+                element.widget.scroll_into_view(
+                    layout.location.x,
+                    layout.location.y,
+                    layout.size.width,
+                    layout.size.height,
+                );
+            }
+        }
+
+        action
     }
 
     fn teardown(element: Self::Element, (contents, state): Self::State, cx: &mut Context<P>) {
-        V::teardown(contents, state, cx);
+        V::teardown(contents, state.state, cx);
         element.widget.teardown(&mut cx.platform);
     }
 }
+
+#[doc(hidden)]
+pub struct ScrollState<T, S> {
+    view_id:           ViewId,
+    offset:            (f32, f32),
+    scroll_to_end:     bool,
+    pending_to_end:    bool,
+    at_end:            bool,
+    scroll_into_view:  Option<usize>,
+    pending_into_view: Option<usize>,
+    on_scroll:         Option<OnScroll<T>>,
+    state:             S,
+}
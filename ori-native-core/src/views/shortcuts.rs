@@ -0,0 +1,196 @@
+use ori::{Action, Message, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{
+    Accelerator, Context, Lifecycle, NativeWidget, Pod, PodMut, WidgetView,
+    native::{HasShortcuts, NativeShortcuts, ShortcutScope},
+};
+
+pub fn shortcuts<V, T>(contents: V) -> Shortcuts<V, T> {
+    Shortcuts::new(contents)
+}
+
+/// Handles keyboard shortcuts, while the focus is within the contents, or anywhere in the
+/// window with [`ShortcutScope::Window`].
+#[allow(clippy::type_complexity)]
+pub struct Shortcuts<V, T> {
+    contents:     V,
+    scope:        ShortcutScope,
+    accelerators: Vec<Accelerator>,
+    handlers:     Vec<Box<dyn FnMut(&mut T) -> Action>>,
+}
+
+impl<V, T> Shortcuts<V, T> {
+    pub fn new(contents: V) -> Self {
+        Self {
+            contents,
+            scope: ShortcutScope::Focus,
+            accelerators: Vec::new(),
+            handlers: Vec::new(),
+        }
+    }
+
+    /// Set where the focus must be for the shortcuts to trigger, defaults to
+    /// [`ShortcutScope::Focus`].
+    pub fn scope(mut self, scope: ShortcutScope) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    /// Call `on_trigger` when `accelerator` is pressed, e.g. `Ctrl+S`.
+    ///
+    /// # Panics
+    ///
+    /// If `accelerator` can't be parsed, see [`Accelerator::parse`].
+    #[track_caller]
+    pub fn on<A>(self, accelerator: &str, on_trigger: impl FnMut(&mut T) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        match Accelerator::parse(accelerator) {
+            Ok(accelerator) => self.on_accelerator(accelerator, on_trigger),
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Call `on_trigger` when `accelerator` is pressed.
+    pub fn on_accelerator<A>(
+        mut self,
+        accelerator: Accelerator,
+        mut on_trigger: impl FnMut(&mut T) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.accelerators.push(accelerator);
+        self.handlers.push(Box::new(move |data| {
+            on_trigger(data).into()
+        }));
+        self
+    }
+}
+
+enum ShortcutsMessage {
+    Triggered(usize),
+}
+
+impl<V, T> ViewMarker for Shortcuts<V, T> {}
+impl<P, T, V> View<Context<P>, T> for Shortcuts<V, T>
+where
+    P: HasShortcuts + Proxied,
+    V: WidgetView<P, T>,
+{
+    type Element = Pod<P, P::Shortcuts>;
+    type State = (V::Widget, ShortcutsState<T, V::State>);
+
+    fn build(self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
+        let (contents, state) = self.contents.build(cx, data);
+
+        let mut widget = P::Shortcuts::build(
+            &mut cx.platform,
+            contents.widget.widget(),
+        );
+
+        let view_id = ViewId::next();
+
+        widget.set_on_shortcut({
+            let proxy = cx.proxy();
+
+            move |index| {
+                proxy.message(Message::new(
+                    ShortcutsMessage::Triggered(index),
+                    view_id,
+                ));
+            }
+        });
+
+        widget.set_shortcuts(&self.accelerators, self.scope);
+
+        let pod = Pod::new(contents.node, widget);
+
+        let state = ShortcutsState {
+            view_id,
+            scope: self.scope,
+            accelerators: self.accelerators,
+            handlers: self.handlers,
+            state,
+        };
+
+        (pod, (contents.widget, state))
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) {
+        if self.accelerators != state.accelerators || self.scope != state.scope {
+            (element.widget).set_shortcuts(&self.accelerators, self.scope);
+            state.accelerators = self.accelerators;
+            state.scope = self.scope;
+        }
+
+        state.handlers = self.handlers;
+
+        let pod = PodMut {
+            parent_node:   element.parent_node,
+            parent_widget: element.parent_widget,
+
+            index:  element.index,
+            node:   element.node,
+            widget: contents,
+        };
+
+        self.contents.rebuild(pod, &mut state.state, cx, data);
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        if let Some(ShortcutsMessage::Triggered(index)) = message.take_targeted(state.view_id) {
+            return match state.handlers.get_mut(index) {
+                Some(on_trigger) => on_trigger(data),
+                None => Action::new(),
+            };
+        }
+
+        if let Some(Lifecycle::Layout) = message.get()
+            && let Ok(layout) = cx.get_computed_layout(*element.node)
+        {
+            (element.widget).set_size(layout.size.width, layout.size.height);
+        }
+
+        let pod = PodMut {
+            parent_node:   element.parent_node,
+            parent_widget: element.parent_widget,
+
+            index:  element.index,
+            node:   element.node,
+            widget: contents,
+        };
+
+        V::message(pod, &mut state.state, cx, data, message)
+    }
+
+    fn teardown(element: Self::Element, (contents, state): Self::State, cx: &mut Context<P>) {
+        let pod = Pod::new(element.node, contents);
+
+        V::teardown(pod, state.state, cx);
+        element.widget.teardown(&mut cx.platform);
+    }
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct ShortcutsState<T, S> {
+    view_id:      ViewId,
+    scope:        ShortcutScope,
+    accelerators: Vec<Accelerator>,
+    handlers:     Vec<Box<dyn FnMut(&mut T) -> Action>>,
+    state:        S,
+}
@@ -0,0 +1,263 @@
+use std::ops::RangeInclusive;
+
+use ori::{Action, Message, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{
+    Context, Direction, Layout, NativeWidget, Pod,
+    native::{HasSlider, NativeSlider},
+};
+
+pub fn slider<T>(value: f32, range: RangeInclusive<f32>) -> Slider<T> {
+    Slider::new(value, range)
+}
+
+/// A mark along a [`Slider`], e.g. at a value of note.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SliderMark {
+    pub value: f32,
+    pub label: Option<String>,
+}
+
+/// A slider selecting a value in a range.
+///
+/// The value is bound to the data, [`Slider::on_change`] should update the data it was created
+/// from.
+#[allow(clippy::type_complexity)]
+pub struct Slider<T> {
+    layout:    taffy::Style,
+    value:     f32,
+    range:     RangeInclusive<f32>,
+    step:      Option<f32>,
+    direction: Direction,
+    marks:     Vec<SliderMark>,
+    on_change: Box<dyn FnMut(&mut T, f32) -> Action>,
+    on_commit: Box<dyn FnMut(&mut T, f32) -> Action>,
+}
+
+impl<T> Slider<T> {
+    pub fn new(value: f32, range: RangeInclusive<f32>) -> Self {
+        Self {
+            layout: Default::default(),
+            value,
+            range,
+            step: None,
+            direction: Direction::Horizontal,
+            marks: Vec::new(),
+            on_change: Box::new(|_, _| Action::new()),
+            on_commit: Box::new(|_, _| Action::new()),
+        }
+    }
+
+    /// Snap the value to multiples of `step` from the start of the range.
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// Set the direction of the slider, a vertical slider has the end of the range at the top.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Add a mark at `value`.
+    pub fn mark(mut self, value: f32) -> Self {
+        self.marks.push(SliderMark { value, label: None });
+        self
+    }
+
+    /// Add a mark at `value`, with a label.
+    pub fn labeled_mark(mut self, value: f32, label: impl Into<String>) -> Self {
+        self.marks.push(SliderMark {
+            value,
+            label: Some(label.into()),
+        });
+        self
+    }
+
+    /// Handle the user changing the value, this is called continuously while dragging.
+    pub fn on_change<A>(mut self, mut on_change: impl FnMut(&mut T, f32) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_change = Box::new(move |data, value| on_change(data, value).into());
+        self
+    }
+
+    /// Handle the user being done changing the value, e.g. when a drag ends, for work too
+    /// expensive to do on every change.
+    pub fn on_commit<A>(mut self, mut on_commit: impl FnMut(&mut T, f32) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_commit = Box::new(move |data, value| on_commit(data, value).into());
+        self
+    }
+}
+
+impl<T> Layout for Slider<T> {
+    fn style_mut(&mut self) -> &mut taffy::Style {
+        &mut self.layout
+    }
+}
+
+enum SliderMessage {
+    Changed(f32),
+    Committed(f32),
+}
+
+impl<T> ViewMarker for Slider<T> {}
+impl<P, T> View<Context<P>, T> for Slider<T>
+where
+    P: HasSlider + Proxied,
+{
+    type Element = Pod<P, P::Slider>;
+    type State = SliderState<T>;
+
+    fn build(self, cx: &mut Context<P>, _data: &mut T) -> (Self::Element, Self::State) {
+        let mut widget = P::Slider::build(&mut cx.platform, self.direction);
+
+        widget.set_range(*self.range.start(), *self.range.end());
+        widget.set_step(self.step);
+        widget.set_value(self.value);
+        widget.set_marks(&self.marks);
+
+        let view_id = ViewId::next();
+
+        widget.set_on_change({
+            let proxy = cx.proxy();
+
+            move |value| {
+                proxy.message(Message::new(
+                    SliderMessage::Changed(value),
+                    view_id,
+                ));
+            }
+        });
+
+        widget.set_on_commit({
+            let proxy = cx.proxy();
+
+            move |value| {
+                proxy.message(Message::new(
+                    SliderMessage::Committed(value),
+                    view_id,
+                ));
+            }
+        });
+
+        let layout = widget.get_layout(&mut cx.platform);
+        let node = cx.new_layout_leaf(self.layout, layout);
+
+        let state = SliderState {
+            view_id,
+            value: self.value,
+            range: self.range,
+            step: self.step,
+            direction: self.direction,
+            marks: self.marks,
+            on_change: self.on_change,
+            on_commit: self.on_commit,
+        };
+
+        (Pod::new(node, widget), state)
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) {
+        // the direction can't be changed, so the slider is built anew
+        if self.direction != state.direction {
+            let (mut new_element, new_state) = self.build(cx, data);
+
+            element.parent_widget.replace_child(
+                &mut cx.platform,
+                element.index,
+                new_element.widget.widget(),
+            );
+
+            let _ = cx.replace_layout_child(
+                element.parent_node,
+                element.index,
+                new_element.node,
+            );
+
+            std::mem::swap(element.widget, &mut new_element.widget);
+            std::mem::swap(element.node, &mut new_element.node);
+
+            let old_state = std::mem::replace(state, new_state);
+            Self::teardown(new_element, old_state, cx);
+
+            return;
+        }
+
+        let _ = cx.set_layout_style(*element.node, self.layout);
+
+        if self.range != state.range {
+            state.range = self.range.clone();
+            (element.widget).set_range(*self.range.start(), *self.range.end());
+        }
+
+        if self.step != state.step {
+            state.step = self.step;
+            element.widget.set_step(self.step);
+        }
+
+        if self.value != state.value {
+            state.value = self.value;
+            element.widget.set_value(self.value);
+        }
+
+        if self.marks != state.marks {
+            element.widget.set_marks(&self.marks);
+            state.marks = self.marks;
+
+            let layout = element.widget.get_layout(&mut cx.platform);
+            let _ = cx.set_leaf_layout(*element.node, layout);
+        }
+
+        state.on_change = self.on_change;
+        state.on_commit = self.on_commit;
+    }
+
+    fn message(
+        _element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        _cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        match message.take_targeted(state.view_id) {
+            Some(SliderMessage::Changed(value)) => {
+                state.value = value;
+                (state.on_change)(data, value)
+            }
+
+            Some(SliderMessage::Committed(value)) => (state.on_commit)(data, value),
+
+            None => Action::new(),
+        }
+    }
+
+    fn teardown(element: Self::Element, _state: Self::State, cx: &mut Context<P>) {
+        element.widget.teardown(&mut cx.platform);
+        let _ = cx.remove_layout_node(element.node);
+    }
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct SliderState<T> {
+    view_id:   ViewId,
+    value:     f32,
+    range:     RangeInclusive<f32>,
+    step:      Option<f32>,
+    direction: Direction,
+    marks:     Vec<SliderMark>,
+    on_change: Box<dyn FnMut(&mut T, f32) -> Action>,
+    on_commit: Box<dyn FnMut(&mut T, f32) -> Action>,
+}
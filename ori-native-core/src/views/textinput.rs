@@ -3,7 +3,7 @@ use std::borrow::Cow;
 use ori::{Action, Message, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
 
 use crate::{
-    Color, Context, Font, Layout, Pod, Stretch, Weight,
+    Color, Context, Font, Layout, NativeWidget, Pod, Stretch, Weight,
     native::{HasTextInput, NativeTextInput},
 };
 
@@ -23,6 +23,28 @@ pub enum Submit {
     None,
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum InputMode {
+    #[default]
+    MultiLine,
+
+    /// A single line, where enter always submits, regardless of [`Newline`].
+    SingleLine,
+
+    /// A single line with the text masked, e.g. for passwords.
+    Password,
+}
+
+/// The kind of text expected, used as a hint for e.g. on-screen keyboards.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum InputPurpose {
+    #[default]
+    Text,
+    Email,
+    Number,
+    Url,
+}
+
 #[allow(clippy::type_complexity)]
 pub struct TextInput<T> {
     layout: taffy::Style,
@@ -34,6 +56,10 @@ pub struct TextInput<T> {
 
     newline:    Newline,
     accept_tab: bool,
+    mode:       InputMode,
+    max_length: Option<usize>,
+    purpose:    InputPurpose,
+    editable:   bool,
     on_change:  Box<dyn FnMut(&mut T, String) -> Action>,
     on_submit:  Box<dyn FnMut(&mut T, String) -> Action>,
 }
@@ -65,6 +91,10 @@ impl<T> TextInput<T> {
 
             newline:    Newline::Enter,
             accept_tab: true,
+            mode:       InputMode::MultiLine,
+            max_length: None,
+            purpose:    InputPurpose::Text,
+            editable:   true,
             on_change:  Box::new(|_, _| Action::new()),
             on_submit:  Box::new(|_, _| Action::new()),
         }
@@ -166,6 +196,28 @@ impl<T> TextInput<T> {
         self
     }
 
+    pub fn mode(mut self, mode: InputMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Limit the number of characters that can be entered.
+    pub fn max_length(mut self, max_length: impl Into<Option<usize>>) -> Self {
+        self.max_length = max_length.into();
+        self
+    }
+
+    pub fn purpose(mut self, purpose: InputPurpose) -> Self {
+        self.purpose = purpose;
+        self
+    }
+
+    /// Set whether the text can be edited, a read-only input can still be selected and copied.
+    pub fn editable(mut self, editable: bool) -> Self {
+        self.editable = editable;
+        self
+    }
+
     pub fn on_change<A>(mut self, mut on_change: impl FnMut(&mut T, String) -> A + 'static) -> Self
     where
         A: Into<Action>,
@@ -204,7 +256,7 @@ where
     type State = TextInputState<T>;
 
     fn build(self, cx: &mut Context<P>, _data: &mut T) -> (Self::Element, Self::State) {
-        let mut widget = P::TextInput::build(&mut cx.platform);
+        let mut widget = P::TextInput::build(&mut cx.platform, self.mode);
 
         widget.set_font(&mut cx.platform, self.font.clone());
 
@@ -223,6 +275,9 @@ where
 
         widget.set_newline(&mut cx.platform, self.newline);
         widget.set_accept_tab(&mut cx.platform, self.accept_tab);
+        widget.set_max_length(&mut cx.platform, self.max_length);
+        widget.set_purpose(&mut cx.platform, self.purpose);
+        widget.set_editable(&mut cx.platform, self.editable);
 
         let layout = widget.get_layout(&mut cx.platform);
         let node = cx.new_layout_leaf(self.layout, layout);
@@ -255,6 +310,10 @@ where
 
             newline: self.newline,
             accept_tab: self.accept_tab,
+            mode: self.mode,
+            max_length: self.max_length,
+            purpose: self.purpose,
+            editable: self.editable,
 
             view_id,
             on_change: self.on_change,
@@ -269,8 +328,33 @@ where
         element: Mut<'_, Self::Element>,
         state: &mut Self::State,
         cx: &mut Context<P>,
-        _data: &mut T,
+        data: &mut T,
     ) {
+        // the mode can't be changed, so the input is built anew
+        if self.mode != state.mode {
+            let (mut new_element, new_state) = self.build(cx, data);
+
+            element.parent_widget.replace_child(
+                &mut cx.platform,
+                element.index,
+                new_element.widget.widget(),
+            );
+
+            let _ = cx.replace_layout_child(
+                element.parent_node,
+                element.index,
+                new_element.node,
+            );
+
+            std::mem::swap(element.widget, &mut new_element.widget);
+            std::mem::swap(element.node, &mut new_element.node);
+
+            let old_state = std::mem::replace(state, new_state);
+            Self::teardown(new_element, old_state, cx);
+
+            return;
+        }
+
         let _ = cx.set_layout_style(*element.node, self.layout);
 
         let mut changed = false;
@@ -315,6 +399,23 @@ where
                 .set_accept_tab(&mut cx.platform, self.accept_tab);
         }
 
+        if self.max_length != state.max_length {
+            state.max_length = self.max_length;
+            element
+                .widget
+                .set_max_length(&mut cx.platform, self.max_length);
+        }
+
+        if self.purpose != state.purpose {
+            state.purpose = self.purpose;
+            element.widget.set_purpose(&mut cx.platform, self.purpose);
+        }
+
+        if self.editable != state.editable {
+            state.editable = self.editable;
+            element.widget.set_editable(&mut cx.platform, self.editable);
+        }
+
         if changed {
             let layout = element.widget.get_layout(&mut cx.platform);
             let _ = cx.set_leaf_layout(*element.node, layout);
@@ -362,6 +463,10 @@ pub struct TextInputState<T> {
 
     newline:    Newline,
     accept_tab: bool,
+    mode:       InputMode,
+    max_length: Option<usize>,
+    purpose:    InputPurpose,
+    editable:   bool,
 
     view_id:   ViewId,
     on_change: Box<dyn FnMut(&mut T, String) -> Action>,
@@ -0,0 +1,414 @@
+use ori::{Action, Message, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{
+    ContainerLayout, Context, Layout, Lifecycle, NativeWidget, Pod,
+    native::{HasGroup, HasToggle, NativeGroup, NativeToggle, ToggleKind},
+};
+
+pub fn checkbox<T>(checked: impl Into<CheckState>) -> Toggle<T> {
+    Toggle::new(ToggleKind::Checkbox, checked.into())
+}
+
+/// Create a switch, a [`CheckState::Mixed`] switch is shown as unchecked.
+pub fn switch<T>(on: impl Into<CheckState>) -> Toggle<T> {
+    Toggle::new(ToggleKind::Switch, on.into())
+}
+
+/// Create a group of radios, one for each of `options`, of which the one at `selected` is
+/// checked.
+pub fn radio_group<T>(
+    selected: impl Into<Option<usize>>,
+    options: impl IntoIterator<Item = impl Into<String>>,
+) -> RadioGroup<T> {
+    RadioGroup::new(selected.into(), options)
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CheckState {
+    #[default]
+    Unchecked,
+    Checked,
+
+    /// Neither checked nor unchecked, e.g. a checkbox for a group of items of which only some
+    /// are checked.
+    Mixed,
+}
+
+impl CheckState {
+    pub fn is_checked(self) -> bool {
+        matches!(self, CheckState::Checked)
+    }
+}
+
+impl From<bool> for CheckState {
+    fn from(checked: bool) -> Self {
+        match checked {
+            true => CheckState::Checked,
+            false => CheckState::Unchecked,
+        }
+    }
+}
+
+/// A checkbox or switch.
+///
+/// The checked state is bound to the data, [`Toggle::on_toggle`] should update the data it was
+/// created from.
+#[allow(clippy::type_complexity)]
+pub struct Toggle<T> {
+    layout:    taffy::Style,
+    kind:      ToggleKind,
+    checked:   CheckState,
+    label:     String,
+    on_toggle: Box<dyn FnMut(&mut T, bool) -> Action>,
+}
+
+impl<T> Toggle<T> {
+    pub fn new(kind: ToggleKind, checked: CheckState) -> Self {
+        Self {
+            layout: Default::default(),
+            kind,
+            checked,
+            label: String::new(),
+            on_toggle: Box::new(|_, _| Action::new()),
+        }
+    }
+
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Handle the user toggling, by clicking or with the keyboard, with whether it's checked.
+    pub fn on_toggle<A>(mut self, mut on_toggle: impl FnMut(&mut T, bool) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_toggle = Box::new(move |data, checked| on_toggle(data, checked).into());
+        self
+    }
+}
+
+impl<T> Layout for Toggle<T> {
+    fn style_mut(&mut self) -> &mut taffy::Style {
+        &mut self.layout
+    }
+}
+
+enum ToggleMessage {
+    Toggled(bool),
+}
+
+impl<T> ViewMarker for Toggle<T> {}
+impl<P, T> View<Context<P>, T> for Toggle<T>
+where
+    P: HasToggle + Proxied,
+{
+    type Element = Pod<P, P::Toggle>;
+    type State = ToggleState<T>;
+
+    fn build(self, cx: &mut Context<P>, _data: &mut T) -> (Self::Element, Self::State) {
+        let mut widget = P::Toggle::build(&mut cx.platform, self.kind);
+
+        widget.set_checked(self.checked);
+        widget.set_label(self.label.clone());
+
+        let view_id = ViewId::next();
+
+        widget.set_on_toggle({
+            let proxy = cx.proxy();
+
+            move |checked| {
+                proxy.message(Message::new(
+                    ToggleMessage::Toggled(checked),
+                    view_id,
+                ));
+            }
+        });
+
+        let layout = widget.get_layout(&mut cx.platform);
+        let node = cx.new_layout_leaf(self.layout, layout);
+
+        let state = ToggleState {
+            view_id,
+            checked: self.checked,
+            label: self.label,
+            on_toggle: self.on_toggle,
+        };
+
+        (Pod::new(node, widget), state)
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context<P>,
+        _data: &mut T,
+    ) {
+        let _ = cx.set_layout_style(*element.node, self.layout);
+
+        if self.checked != state.checked {
+            state.checked = self.checked;
+            element.widget.set_checked(self.checked);
+        }
+
+        if self.label != state.label {
+            state.label = self.label.clone();
+            element.widget.set_label(self.label);
+
+            let layout = element.widget.get_layout(&mut cx.platform);
+            let _ = cx.set_leaf_layout(*element.node, layout);
+        }
+
+        state.on_toggle = self.on_toggle;
+    }
+
+    fn message(
+        _element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        _cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        match message.take_targeted(state.view_id) {
+            Some(ToggleMessage::Toggled(checked)) => {
+                // the widget is toggled already, keep the state in sync so a rebuild with the
+                // previous state toggles it back
+                state.checked = CheckState::from(checked);
+                (state.on_toggle)(data, checked)
+            }
+
+            None => Action::new(),
+        }
+    }
+
+    fn teardown(element: Self::Element, _state: Self::State, cx: &mut Context<P>) {
+        element.widget.teardown(&mut cx.platform);
+        let _ = cx.remove_layout_node(element.node);
+    }
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct ToggleState<T> {
+    view_id:   ViewId,
+    checked:   CheckState,
+    label:     String,
+    on_toggle: Box<dyn FnMut(&mut T, bool) -> Action>,
+}
+
+/// A group of radios, of which one is selected.
+#[allow(clippy::type_complexity)]
+pub struct RadioGroup<T> {
+    layout:    taffy::Style,
+    selected:  Option<usize>,
+    options:   Vec<String>,
+    on_select: Box<dyn FnMut(&mut T, usize) -> Action>,
+}
+
+impl<T> RadioGroup<T> {
+    pub fn new(
+        selected: Option<usize>,
+        options: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        Self {
+            layout: taffy::Style {
+                display: taffy::Display::Flex,
+                flex_direction: taffy::FlexDirection::Column,
+                ..Default::default()
+            },
+            selected,
+            options: options.into_iter().map(Into::into).collect(),
+            on_select: Box::new(|_, _| Action::new()),
+        }
+    }
+
+    /// Lay out the radios in a row instead of a column.
+    pub fn horizontal(mut self) -> Self {
+        self.layout.flex_direction = taffy::FlexDirection::Row;
+        self
+    }
+
+    /// Handle the user selecting the option at an index.
+    pub fn on_select<A>(mut self, mut on_select: impl FnMut(&mut T, usize) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_select = Box::new(move |data, index| on_select(data, index).into());
+        self
+    }
+}
+
+impl<T> Layout for RadioGroup<T> {
+    fn style_mut(&mut self) -> &mut taffy::Style {
+        &mut self.layout
+    }
+}
+
+impl<T> ContainerLayout for RadioGroup<T> {}
+
+enum RadioGroupMessage {
+    Selected(usize),
+}
+
+impl<T> ViewMarker for RadioGroup<T> {}
+impl<P, T> View<Context<P>, T> for RadioGroup<T>
+where
+    P: HasGroup + HasToggle + Proxied,
+{
+    type Element = Pod<P, P::Group>;
+    type State = RadioGroupState<P, T>;
+
+    fn build(self, cx: &mut Context<P>, _data: &mut T) -> (Self::Element, Self::State) {
+        let node = cx.new_layout_node(self.layout, &[]);
+        let mut widget = P::Group::build(&mut cx.platform);
+
+        let mut state = RadioGroupState {
+            view_id:   ViewId::next(),
+            selected:  self.selected,
+            options:   self.options,
+            radios:    Vec::new(),
+            on_select: self.on_select,
+        };
+
+        state.build_radios(node, &mut widget, cx);
+
+        (Pod::new(node, widget), state)
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context<P>,
+        _data: &mut T,
+    ) {
+        let _ = cx.set_layout_style(*element.node, self.layout);
+
+        state.on_select = self.on_select;
+
+        if self.options != state.options {
+            state.teardown_radios(element.widget, cx);
+
+            state.selected = self.selected;
+            state.options = self.options;
+            state.build_radios(*element.node, element.widget, cx);
+
+            return;
+        }
+
+        if self.selected != state.selected {
+            state.selected = self.selected;
+
+            for (index, (_, radio)) in state.radios.iter_mut().enumerate() {
+                radio.set_checked(CheckState::from(
+                    self.selected == Some(index),
+                ));
+            }
+        }
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        if let Some(RadioGroupMessage::Selected(index)) = message.take_targeted(state.view_id) {
+            state.selected = Some(index);
+            return (state.on_select)(data, index);
+        }
+
+        if let Some(Lifecycle::Layout) = message.get() {
+            if let Ok(layout) = cx.get_computed_layout(*element.node) {
+                (element.widget).set_size(layout.size.width, layout.size.height);
+            }
+
+            for (index, (node, _)) in state.radios.iter().enumerate() {
+                if let Ok(layout) = cx.get_computed_layout(*node) {
+                    element.widget.set_child_layout(
+                        index,
+                        layout.location.x,
+                        layout.location.y,
+                        layout.size.width,
+                        layout.size.height,
+                    );
+                }
+            }
+        }
+
+        Action::new()
+    }
+
+    fn teardown(mut element: Self::Element, mut state: Self::State, cx: &mut Context<P>) {
+        state.teardown_radios(&mut element.widget, cx);
+
+        element.widget.teardown(&mut cx.platform);
+        let _ = cx.remove_layout_node(element.node);
+    }
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct RadioGroupState<P, T>
+where
+    P: HasToggle,
+{
+    view_id:   ViewId,
+    selected:  Option<usize>,
+    options:   Vec<String>,
+    radios:    Vec<(taffy::NodeId, P::Toggle)>,
+    on_select: Box<dyn FnMut(&mut T, usize) -> Action>,
+}
+
+impl<P, T> RadioGroupState<P, T>
+where
+    P: HasGroup + HasToggle + Proxied,
+{
+    fn build_radios(&mut self, node: taffy::NodeId, group: &mut P::Group, cx: &mut Context<P>) {
+        for (index, option) in self.options.iter().enumerate() {
+            let mut radio = P::Toggle::build(&mut cx.platform, ToggleKind::Radio);
+
+            if let Some((_, leader)) = self.radios.first() {
+                radio.set_group(leader);
+            }
+
+            radio.set_label(option.clone());
+            radio.set_checked(CheckState::from(
+                self.selected == Some(index),
+            ));
+
+            radio.set_on_toggle({
+                let proxy = cx.proxy();
+                let view_id = self.view_id;
+
+                move |checked| {
+                    // the radio that was checked is unchecked too
+                    if checked {
+                        proxy.message(Message::new(
+                            RadioGroupMessage::Selected(index),
+                            view_id,
+                        ));
+                    }
+                }
+            });
+
+            let layout = radio.get_layout(&mut cx.platform);
+            let leaf = cx.new_layout_leaf(Default::default(), layout);
+
+            let _ = cx.insert_layout_child(node, index, leaf);
+            group.insert_child(index, radio.widget());
+
+            self.radios.push((leaf, radio));
+        }
+    }
+
+    fn teardown_radios(&mut self, group: &mut P::Group, cx: &mut Context<P>) {
+        for (index, (node, radio)) in self.radios.drain(..).enumerate().rev() {
+            group.remove_child(index);
+            radio.teardown(&mut cx.platform);
+            let _ = cx.remove_layout_node(node);
+        }
+    }
+}
@@ -1,9 +1,10 @@
-use std::time::Duration;
+use std::{any::Any, time::Duration};
 
 use ori::{Action, Message, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
 
 use crate::{
-    Context, Lifecycle, NativeWidget, Pod, Sizing, WidgetView,
+    Context, Lifecycle, Menu, MenuModel, NativeWidget, Pod, Sizing, WidgetView,
+    menu::MenuHandler,
     native::{HasWindow, NativeWindow},
 };
 
@@ -11,9 +12,12 @@ pub fn window<V>(contents: V) -> Window<V> {
     Window::new(contents)
 }
 
+/// A window showing `contents`.
 pub struct Window<V> {
-    contents: V,
-    sizing:   Sizing,
+    contents:   V,
+    sizing:     Sizing,
+    properties: WindowProperties,
+    handlers:   Option<Box<dyn Any>>,
 }
 
 impl<V> Window<V> {
@@ -21,6 +25,8 @@ impl<V> Window<V> {
         Window {
             contents,
             sizing: Sizing::User,
+            properties: WindowProperties::default(),
+            handlers: None,
         }
     }
 
@@ -28,6 +34,277 @@ impl<V> Window<V> {
         self.sizing = sizing;
         self
     }
+
+    /// Name the window, so dialogs can be owned by it, see [`Dialog::owner`].
+    ///
+    /// [`Dialog::owner`]: crate::views::Dialog::owner
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.properties.name = Some(name.into());
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.properties.title = Some(title.into());
+        self
+    }
+
+    /// Set the icon of the window, by the name of an icon in the icon theme.
+    pub fn icon_name(mut self, icon_name: impl Into<String>) -> Self {
+        self.properties.icon_name = Some(icon_name.into());
+        self
+    }
+
+    /// Set whether the window has decorations, e.g. a title bar, defaults to `true`.
+    pub fn decorated(mut self, decorated: bool) -> Self {
+        self.properties.decorated = decorated;
+        self
+    }
+
+    /// Set whether the window is maximized.
+    ///
+    /// The state is only applied when it changes, use [`Window::on_maximized`] to keep track
+    /// of changes made by the user.
+    pub fn maximized(mut self, maximized: bool) -> Self {
+        self.properties.maximized = Some(maximized);
+        self
+    }
+
+    /// Set whether the window is fullscreen.
+    ///
+    /// The state is only applied when it changes, use [`Window::on_fullscreen`] to keep track
+    /// of changes made by the user.
+    pub fn fullscreen(mut self, fullscreen: bool) -> Self {
+        self.properties.fullscreen = Some(fullscreen);
+        self
+    }
+
+    /// Set whether the window is minimized.
+    ///
+    /// The state is only applied when it changes, use [`Window::on_minimized`] to keep track
+    /// of changes made by the user.
+    pub fn minimized(mut self, minimized: bool) -> Self {
+        self.properties.minimized = Some(minimized);
+        self
+    }
+
+    /// Set the menubar of the window.
+    ///
+    /// The menu is diffed on every rebuild, so items can be enabled and checked from data.
+    /// Accelerators of the items are registered with the application, and trigger the items
+    /// while the window is focused.
+    pub fn menubar<T>(mut self, menu: Menu<T>) -> Self
+    where
+        T: 'static,
+    {
+        let (model, handlers) = menu.into_model();
+        self.properties.menubar = Some(model);
+        self.handlers().on_menu = handlers;
+        self
+    }
+
+    /// Handle the user asking to close the window, e.g. by pressing the close button.
+    ///
+    /// Without a handler the window is closed when a close is requested, and stays closed
+    /// until it's removed from the view tree. With a handler the window stays open, to accept
+    /// the close, remove the window from the view tree.
+    pub fn on_close_requested<T, A>(
+        mut self,
+        mut on_close_requested: impl FnMut(&mut T) -> A + 'static,
+    ) -> Self
+    where
+        T: 'static,
+        A: Into<Action>,
+    {
+        self.handlers().on_close_requested = Some(Box::new(move |data| {
+            on_close_requested(data).into()
+        }));
+        self
+    }
+
+    pub fn on_maximized<T, A>(
+        mut self,
+        mut on_maximized: impl FnMut(&mut T, bool) -> A + 'static,
+    ) -> Self
+    where
+        T: 'static,
+        A: Into<Action>,
+    {
+        self.handlers().on_maximized = Some(Box::new(move |data, maximized| {
+            on_maximized(data, maximized).into()
+        }));
+        self
+    }
+
+    pub fn on_fullscreen<T, A>(
+        mut self,
+        mut on_fullscreen: impl FnMut(&mut T, bool) -> A + 'static,
+    ) -> Self
+    where
+        T: 'static,
+        A: Into<Action>,
+    {
+        self.handlers().on_fullscreen = Some(Box::new(move |data, fullscreen| {
+            on_fullscreen(data, fullscreen).into()
+        }));
+        self
+    }
+
+    pub fn on_minimized<T, A>(
+        mut self,
+        mut on_minimized: impl FnMut(&mut T, bool) -> A + 'static,
+    ) -> Self
+    where
+        T: 'static,
+        A: Into<Action>,
+    {
+        self.handlers().on_minimized = Some(Box::new(move |data, minimized| {
+            on_minimized(data, minimized).into()
+        }));
+        self
+    }
+
+    /// Get the handlers, all of which take the same data.
+    #[track_caller]
+    fn handlers<T>(&mut self) -> &mut WindowHandlers<T>
+    where
+        T: 'static,
+    {
+        let handlers =
+            (self.handlers).get_or_insert_with(|| Box::new(WindowHandlers::<T>::default()));
+
+        handlers
+            .downcast_mut()
+            .expect("the handlers of a window should all take the same data")
+    }
+
+    /// Map the contents of the window, keeping its properties and handlers.
+    pub(crate) fn map_contents<U>(self, f: impl FnOnce(V) -> U) -> Window<U> {
+        Window {
+            contents:   f(self.contents),
+            sizing:     self.sizing,
+            properties: self.properties,
+            handlers:   self.handlers,
+        }
+    }
+
+    /// Build the contents and the [`WindowState`], using `build` to create the native window.
+    pub(crate) fn build_state<P, T, W>(
+        self,
+        cx: &mut Context<P>,
+        data: &mut T,
+        build: impl FnOnce(&mut P, &P::Widget) -> W,
+    ) -> WindowState<P, T, V, W>
+    where
+        P: HasWindow,
+        T: 'static,
+        V: WidgetView<P, T>,
+        W: NativeWindow<P>,
+    {
+        let view_id = ViewId::next();
+
+        let (contents, state) = cx.with_window(view_id, |cx| {
+            self.contents.build(cx, data)
+        });
+
+        let window = build(
+            &mut cx.platform,
+            contents.widget.widget(),
+        );
+
+        let mut state = WindowState::new(
+            cx,
+            window,
+            view_id,
+            self.sizing,
+            contents,
+            state,
+        );
+
+        state.set_properties(self.properties);
+        state.handlers = WindowHandlers::from_any(self.handlers);
+
+        state
+    }
+
+    pub(crate) fn rebuild_state<P, T, W>(
+        self,
+        state: &mut WindowState<P, T, V, W>,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) where
+        P: HasWindow,
+        T: 'static,
+        V: WidgetView<P, T>,
+        W: NativeWindow<P>,
+    {
+        state.rebuild(cx, data, self.contents, self.sizing);
+        state.set_properties(self.properties);
+        state.handlers = WindowHandlers::from_any(self.handlers);
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct WindowProperties {
+    name:       Option<String>,
+    title:      Option<String>,
+    icon_name:  Option<String>,
+    decorated:  bool,
+    maximized:  Option<bool>,
+    fullscreen: Option<bool>,
+    minimized:  Option<bool>,
+    menubar:    Option<MenuModel>,
+}
+
+impl Default for WindowProperties {
+    fn default() -> Self {
+        Self {
+            name:       None,
+            title:      None,
+            icon_name:  None,
+            decorated:  true,
+            maximized:  None,
+            fullscreen: None,
+            minimized:  None,
+            menubar:    None,
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) struct WindowHandlers<T> {
+    on_close_requested: Option<Box<dyn FnMut(&mut T) -> Action>>,
+    on_maximized:       Option<Box<dyn FnMut(&mut T, bool) -> Action>>,
+    on_fullscreen:      Option<Box<dyn FnMut(&mut T, bool) -> Action>>,
+    on_minimized:       Option<Box<dyn FnMut(&mut T, bool) -> Action>>,
+    on_menu:            Vec<MenuHandler<T>>,
+}
+
+impl<T> WindowHandlers<T>
+where
+    T: 'static,
+{
+    /// Take the handlers of a [`Window`], that are stored without the type of the data.
+    #[track_caller]
+    fn from_any(handlers: Option<Box<dyn Any>>) -> Self {
+        match handlers {
+            Some(handlers) => *handlers
+                .downcast()
+                .expect("the handlers of a window should take the data of the view"),
+            None => Self::default(),
+        }
+    }
+}
+
+impl<T> Default for WindowHandlers<T> {
+    fn default() -> Self {
+        Self {
+            on_close_requested: None,
+            on_maximized:       None,
+            on_fullscreen:      None,
+            on_minimized:       None,
+            on_menu:            Vec::new(),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -36,6 +313,8 @@ pub enum WindowMessage {
     StartAnimating,
     StopAnimating,
     CloseRequested,
+    StateChanged,
+    MenuSelected(usize),
     Relayout,
     Resized,
 }
@@ -44,32 +323,14 @@ impl<V> ViewMarker for Window<V> {}
 impl<P, T, V> View<Context<P>, T> for Window<V>
 where
     P: HasWindow + Proxied,
+    T: 'static,
     V: WidgetView<P, T>,
 {
     type Element = ();
     type State = WindowState<P, T, V>;
 
     fn build(self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
-        let view_id = ViewId::next();
-
-        let (contents, state) = cx.with_window(view_id, |cx| {
-            self.contents.build(cx, data)
-        });
-
-        let window = P::Window::build(
-            &mut cx.platform,
-            contents.widget.widget(),
-        );
-
-        let state = WindowState::new(
-            cx,
-            window,
-            view_id,
-            self.sizing,
-            contents,
-            state,
-        );
-
+        let state = self.build_state(cx, data, P::Window::build);
         ((), state)
     }
 
@@ -80,7 +341,7 @@ where
         cx: &mut Context<P>,
         data: &mut T,
     ) {
-        state.rebuild(cx, data, self.contents, self.sizing);
+        self.rebuild_state(state, cx, data);
     }
 
     fn message(
@@ -99,12 +360,13 @@ where
 }
 
 #[doc(hidden)]
-pub struct WindowState<P, T, V>
+pub struct WindowState<P, T, V, W = <P as HasWindow>::Window>
 where
     P: HasWindow,
     V: WidgetView<P, T>,
+    W: NativeWindow<P>,
 {
-    pub window:  P::Window,
+    pub window:  W,
     pub view_id: ViewId,
 
     node:   taffy::NodeId,
@@ -115,18 +377,26 @@ where
 
     animating: u32,
 
+    maximized:  bool,
+    fullscreen: bool,
+    minimized:  bool,
+
     contents: Pod<P, V::Widget>,
     state:    V::State,
+
+    properties:          WindowProperties,
+    pub(crate) handlers: WindowHandlers<T>,
 }
 
-impl<P, T, V> WindowState<P, T, V>
+impl<P, T, V, W> WindowState<P, T, V, W>
 where
     P: HasWindow,
     V: WidgetView<P, T>,
+    W: NativeWindow<P>,
 {
     pub fn new(
         cx: &mut Context<P>,
-        mut window: P::Window,
+        mut window: W,
         view_id: ViewId,
         sizing: Sizing,
         contents: Pod<P, V::Widget>,
@@ -156,6 +426,28 @@ where
             }
         });
 
+        window.set_on_state_changed({
+            let proxy = cx.proxy();
+
+            move || {
+                proxy.message(Message::new(
+                    WindowMessage::StateChanged,
+                    view_id,
+                ));
+            }
+        });
+
+        window.set_on_menu_select({
+            let proxy = cx.proxy();
+
+            move |id| {
+                proxy.message(Message::new(
+                    WindowMessage::MenuSelected(id),
+                    view_id,
+                ));
+            }
+        });
+
         window.set_on_animation_frame({
             let proxy = cx.proxy();
 
@@ -171,16 +463,99 @@ where
         let (width, height) = window.get_size();
 
         Self {
-            window,
             view_id,
             node,
             sizing,
             width,
             height,
             animating: 0,
+            maximized: window.is_maximized(),
+            fullscreen: window.is_fullscreen(),
+            minimized: window.is_minimized(),
+            window,
             contents,
             state,
+            properties: WindowProperties::default(),
+            handlers: WindowHandlers::default(),
+        }
+    }
+
+    pub(crate) fn set_properties(&mut self, properties: WindowProperties) {
+        let old = &self.properties;
+
+        if properties.name != old.name {
+            self.window.set_name(properties.name.as_deref());
+        }
+
+        if properties.title != old.title {
+            (self.window).set_title(properties.title.as_deref().unwrap_or_default());
+        }
+
+        if properties.icon_name != old.icon_name {
+            (self.window).set_icon_name(properties.icon_name.as_deref());
+        }
+
+        if properties.decorated != old.decorated {
+            (self.window).set_decorated(properties.decorated);
+        }
+
+        if properties.maximized != old.maximized
+            && let Some(maximized) = properties.maximized
+        {
+            self.window.set_maximized(maximized);
+        }
+
+        if properties.fullscreen != old.fullscreen
+            && let Some(fullscreen) = properties.fullscreen
+        {
+            self.window.set_fullscreen(fullscreen);
+        }
+
+        if properties.minimized != old.minimized
+            && let Some(minimized) = properties.minimized
+        {
+            self.window.set_minimized(minimized);
+        }
+
+        if properties.menubar != old.menubar {
+            self.window.set_menubar(properties.menubar.as_ref());
+        }
+
+        self.properties = properties;
+    }
+
+    fn state_changed(&mut self, data: &mut T) -> Action {
+        let mut action = Action::new();
+
+        let maximized = self.window.is_maximized();
+        let fullscreen = self.window.is_fullscreen();
+        let minimized = self.window.is_minimized();
+
+        if self.maximized != maximized {
+            self.maximized = maximized;
+
+            if let Some(ref mut on_maximized) = self.handlers.on_maximized {
+                action |= on_maximized(data, maximized);
+            }
+        }
+
+        if self.fullscreen != fullscreen {
+            self.fullscreen = fullscreen;
+
+            if let Some(ref mut on_fullscreen) = self.handlers.on_fullscreen {
+                action |= on_fullscreen(data, fullscreen);
+            }
+        }
+
+        if self.minimized != minimized {
+            self.minimized = minimized;
+
+            if let Some(ref mut on_minimized) = self.handlers.on_minimized {
+                action |= on_minimized(data, minimized);
+            }
         }
+
+        action
     }
 
     pub fn rebuild(&mut self, cx: &mut Context<P>, data: &mut T, contents: V, sizing: Sizing) {
@@ -326,11 +701,22 @@ where
                     Action::new()
                 }
 
-                WindowMessage::CloseRequested => {
-                    cx.platform.quit();
+                WindowMessage::CloseRequested => match self.handlers.on_close_requested {
+                    Some(ref mut on_close_requested) => on_close_requested(data),
 
-                    Action::new()
-                }
+                    None => {
+                        self.window.close(&mut cx.platform);
+
+                        Action::new()
+                    }
+                },
+
+                WindowMessage::StateChanged => self.state_changed(data),
+
+                WindowMessage::MenuSelected(id) => match self.handlers.on_menu.get_mut(id) {
+                    Some(on_select) => on_select(data),
+                    None => Action::new(),
+                },
 
                 WindowMessage::Relayout => self.layout(cx, data),
 
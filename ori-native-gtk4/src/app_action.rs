@@ -0,0 +1,47 @@
+use gio::prelude::ActionMapExt;
+use glib::prelude::CastNone;
+use gtk4::prelude::GtkApplicationExt;
+use ori_native_core::native::HasAppActions;
+
+use crate::{Platform, menu};
+
+impl HasAppActions for Platform {
+    fn add_app_action(
+        &mut self,
+        name: &str,
+        accelerator: Option<&str>,
+        on_activate: impl Fn() + 'static,
+    ) {
+        let action = gio::SimpleAction::new(name, None);
+        action.connect_activate(move |_, _| on_activate());
+
+        // adding an action replaces the one with the same name
+        self.application.add_action(&action);
+
+        let accel = accelerator.and_then(|accelerator| {
+            let accel = menu::accelerator(accelerator);
+
+            if accel.is_none() {
+                tracing::warn!("invalid accelerator `{accelerator}` of action `{name}`");
+            }
+
+            accel
+        });
+
+        let accels: Vec<&str> = accel.iter().map(String::as_str).collect();
+        (self.application).set_accels_for_action(&format!("app.{name}"), &accels);
+    }
+
+    fn remove_app_action(&mut self, name: &str) {
+        self.application.remove_action(name);
+        (self.application).set_accels_for_action(&format!("app.{name}"), &[]);
+    }
+
+    fn set_app_action_enabled(&mut self, name: &str, enabled: bool) {
+        let action = self.application.lookup_action(name);
+
+        if let Some(action) = action.and_downcast::<gio::SimpleAction>() {
+            action.set_enabled(enabled);
+        }
+    }
+}
@@ -1,10 +1,18 @@
-use gtk4::prelude::ApplicationExt;
+use std::{
+    cell::RefCell,
+    task::{self, Poll},
+};
+
+use gtk4::prelude::{ApplicationExt, GtkApplicationExt, WidgetExt};
 use ori::{Effect, Message, Proxied};
-use ori_native_core::Context;
+use ori_native_core::{Context, Platform as _, QuitPolicy};
+use tokio::sync::mpsc::UnboundedReceiver;
 
 use crate::Platform;
 
-pub struct Application {}
+pub struct Application {
+    quit_policy: QuitPolicy,
+}
 
 impl Default for Application {
     fn default() -> Self {
@@ -14,7 +22,14 @@ impl Default for Application {
 
 impl Application {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            quit_policy: QuitPolicy::default(),
+        }
+    }
+
+    pub fn quit_policy(mut self, quit_policy: QuitPolicy) -> Self {
+        self.quit_policy = quit_policy;
+        self
     }
 
     pub fn run<T, V>(self, data: &mut T, ui: impl FnMut(&T) -> V)
@@ -24,18 +39,34 @@ impl Application {
         Self::init_log();
         gtk4::init().unwrap();
 
-        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
 
         let app = gtk4::Application::default();
         let display = gdk4::Display::default().unwrap();
         let platform = Platform::new(sender.clone(), display, app.clone());
-        let mut state = State {
+        let dispatcher = platform.dispatcher.clone();
+        let state = RefCell::new(State {
             data,
             build: ui,
             state: None,
             context: Context::new(platform),
+            receiver,
             running: true,
-        };
+            quit_policy: self.quit_policy,
+        });
+
+        // native callbacks handle the pending events through the dispatcher, while the loop
+        // below is waiting for the next event
+        let guard = dispatcher.scoped(|| {
+            if let Ok(mut state) = state.try_borrow_mut() {
+                state.handle_pending();
+
+                // wake the loop up, for it to see that the application stopped
+                if !state.running {
+                    state.context.platform.quit();
+                }
+            }
+        });
 
         app.connect_activate(move |_| {
             let _ = sender.send(Event::Activate);
@@ -47,19 +78,18 @@ impl Application {
         app.activate();
 
         main_context.block_on(async {
-            while state.running
-                && let Some(event) = receiver.recv().await
+            // `state` is only borrowed between the awaits, for the dispatcher to borrow it
+            while let Some(event) =
+                std::future::poll_fn(|cx| state.borrow_mut().poll_event(cx)).await
             {
+                let mut state = state.borrow_mut();
                 state.handle_event(event);
-
-                // handle all events before giving control back to gtk
-                while let Ok(event) = receiver.try_recv() {
-                    state.handle_event(event);
-                }
+                state.handle_pending();
             }
-
-            state.teardown();
         });
+
+        drop(guard);
+        state.into_inner().teardown();
     }
 
     fn init_log() {
@@ -105,11 +135,14 @@ struct State<'a, T, V, B>
 where
     V: Effect<Context<Platform>, T>,
 {
-    data:    &'a mut T,
-    build:   B,
-    state:   Option<V::State>,
-    context: Context<Platform>,
-    running: bool,
+    data:     &'a mut T,
+    build:    B,
+    state:    Option<V::State>,
+    context:  Context<Platform>,
+    receiver: UnboundedReceiver<Event>,
+    running:  bool,
+
+    quit_policy: QuitPolicy,
 }
 
 impl<T, V, B> State<'_, T, V, B>
@@ -117,6 +150,22 @@ where
     V: Effect<Context<Platform>, T>,
     B: FnMut(&T) -> V,
 {
+    fn poll_event(&mut self, cx: &mut task::Context<'_>) -> Poll<Option<Event>> {
+        match self.running {
+            true => self.receiver.poll_recv(cx),
+            false => Poll::Ready(None),
+        }
+    }
+
+    /// Handle all pending events before giving control back to gtk.
+    fn handle_pending(&mut self) {
+        while let Ok(event) = self.receiver.try_recv() {
+            self.handle_event(event);
+        }
+
+        self.apply_quit_policy();
+    }
+
     fn handle_event(&mut self, event: Event) {
         match event {
             Event::Activate => {
@@ -158,6 +207,25 @@ where
         }
     }
 
+    fn apply_quit_policy(&mut self) {
+        if self.state.is_none() {
+            return;
+        }
+
+        match self.quit_policy {
+            QuitPolicy::LastWindowClosed => {
+                // hidden windows are still open, only closed windows are removed
+                let application = &self.context.platform.application;
+
+                if application.windows().is_empty() {
+                    self.running = false;
+                }
+            }
+
+            QuitPolicy::Never => {}
+        }
+    }
+
     fn teardown(mut self) {
         if let Some(state) = self.state {
             V::teardown((), state, &mut self.context);
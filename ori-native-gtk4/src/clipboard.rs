@@ -0,0 +1,69 @@
+use gdk4::{
+    gdk_pixbuf::{Pixbuf, PixbufLoader, prelude::PixbufLoaderExt},
+    prelude::DisplayExt,
+};
+use ori_native_core::{ClipboardContent, ClipboardKind, native::HasClipboard};
+
+use crate::Platform;
+
+impl HasClipboard for Platform {
+    fn write_clipboard(&mut self, content: ClipboardContent) {
+        let clipboard = self.display.clipboard();
+
+        match content {
+            ClipboardContent::Text(text) => clipboard.set_text(&text),
+            ClipboardContent::Image(image) => match load_pixbuf(&image) {
+                Ok(pixbuf) => clipboard.set_texture(&gdk4::Texture::for_pixbuf(&pixbuf)),
+                Err(err) => tracing::warn!("failed to copy image to the clipboard: {err}"),
+            },
+        }
+    }
+
+    fn read_clipboard(
+        &mut self,
+        kind: ClipboardKind,
+        on_read: impl FnOnce(Option<ClipboardContent>) + 'static,
+    ) {
+        let clipboard = self.display.clipboard();
+
+        match kind {
+            ClipboardKind::Text => {
+                clipboard.read_text_async(
+                    None::<&gio::Cancellable>,
+                    move |result| {
+                        let text = result.ok().flatten();
+                        on_read(text.map(|text| ClipboardContent::Text(text.into())));
+                    },
+                );
+            }
+
+            ClipboardKind::Image => {
+                clipboard.read_texture_async(
+                    None::<&gio::Cancellable>,
+                    move |result| {
+                        let png = result
+                            .ok()
+                            .flatten()
+                            .and_then(|texture| gdk4::pixbuf_get_from_texture(&texture))
+                            .and_then(|pixbuf| pixbuf.save_to_bufferv("png", &[]).ok());
+
+                        on_read(png.map(ClipboardContent::Image));
+                    },
+                );
+            }
+        }
+    }
+}
+
+fn load_pixbuf(data: &[u8]) -> Result<Pixbuf, glib::Error> {
+    let loader = PixbufLoader::new();
+    loader.write(data)?;
+    loader.close()?;
+
+    loader.pixbuf().ok_or_else(|| {
+        glib::Error::new(
+            gdk4::gdk_pixbuf::PixbufError::Failed,
+            "no pixbuf",
+        )
+    })
+}
@@ -0,0 +1,124 @@
+use glib::translate::FromGlib;
+use ori_native_core::{Accelerator, Key, Modifiers};
+
+/// Convert `key` to a gdk keyval.
+pub(crate) fn to_gdk_key(key: Key) -> gdk4::Key {
+    match key {
+        Key::Character(c) => {
+            let keyval = gdk4::unicode_to_keyval(c as u32);
+
+            // SAFETY: keyvals are plain integers, and any value is a valid key
+            unsafe { gdk4::Key::from_glib(keyval) }
+        }
+
+        Key::Enter => gdk4::Key::Return,
+        Key::Escape => gdk4::Key::Escape,
+        Key::Tab => gdk4::Key::Tab,
+        Key::Backspace => gdk4::Key::BackSpace,
+        Key::Delete => gdk4::Key::Delete,
+        Key::Insert => gdk4::Key::Insert,
+        Key::Space => gdk4::Key::space,
+        Key::Up => gdk4::Key::Up,
+        Key::Down => gdk4::Key::Down,
+        Key::Left => gdk4::Key::Left,
+        Key::Right => gdk4::Key::Right,
+        Key::Home => gdk4::Key::Home,
+        Key::End => gdk4::Key::End,
+        Key::PageUp => gdk4::Key::Page_Up,
+        Key::PageDown => gdk4::Key::Page_Down,
+        Key::Shift => gdk4::Key::Shift_L,
+        Key::Control => gdk4::Key::Control_L,
+        Key::Alt => gdk4::Key::Alt_L,
+        Key::Super => gdk4::Key::Super_L,
+        Key::Unidentified => gdk4::Key::VoidSymbol,
+        Key::F(number) => {
+            gdk4::Key::from_name(format!("F{number}")).unwrap_or(gdk4::Key::VoidSymbol)
+        }
+    }
+}
+
+/// Convert a gdk keyval to a [`Key`].
+pub(crate) fn from_gdk_key(key: gdk4::Key) -> Key {
+    match key {
+        gdk4::Key::Return | gdk4::Key::KP_Enter => Key::Enter,
+        gdk4::Key::Escape => Key::Escape,
+        gdk4::Key::Tab | gdk4::Key::ISO_Left_Tab => Key::Tab,
+        gdk4::Key::BackSpace => Key::Backspace,
+        gdk4::Key::Delete | gdk4::Key::KP_Delete => Key::Delete,
+        gdk4::Key::Insert | gdk4::Key::KP_Insert => Key::Insert,
+        gdk4::Key::space => Key::Space,
+        gdk4::Key::Up | gdk4::Key::KP_Up => Key::Up,
+        gdk4::Key::Down | gdk4::Key::KP_Down => Key::Down,
+        gdk4::Key::Left | gdk4::Key::KP_Left => Key::Left,
+        gdk4::Key::Right | gdk4::Key::KP_Right => Key::Right,
+        gdk4::Key::Home | gdk4::Key::KP_Home => Key::Home,
+        gdk4::Key::End | gdk4::Key::KP_End => Key::End,
+        gdk4::Key::Page_Up | gdk4::Key::KP_Page_Up => Key::PageUp,
+        gdk4::Key::Page_Down | gdk4::Key::KP_Page_Down => Key::PageDown,
+        gdk4::Key::Shift_L | gdk4::Key::Shift_R => Key::Shift,
+        gdk4::Key::Control_L | gdk4::Key::Control_R => Key::Control,
+        gdk4::Key::Alt_L | gdk4::Key::Alt_R => Key::Alt,
+        gdk4::Key::Super_L | gdk4::Key::Super_R => Key::Super,
+        key => {
+            if let Some(number) = key
+                .name()
+                .and_then(|name| name.strip_prefix('F')?.parse().ok())
+            {
+                return Key::F(number);
+            }
+
+            match key.to_lower().to_unicode() {
+                Some(c) if !c.is_control() => Key::Character(c),
+                _ => Key::Unidentified,
+            }
+        }
+    }
+}
+
+pub(crate) fn from_gdk_modifiers(state: gdk4::ModifierType) -> Modifiers {
+    Modifiers {
+        shift: state.contains(gdk4::ModifierType::SHIFT_MASK),
+        ctrl:  state.contains(gdk4::ModifierType::CONTROL_MASK),
+        alt:   state.contains(gdk4::ModifierType::ALT_MASK),
+        meta:  state.contains(gdk4::ModifierType::SUPER_MASK),
+    }
+}
+
+pub(crate) fn to_gdk_modifiers(modifiers: Modifiers) -> gdk4::ModifierType {
+    let mut state = gdk4::ModifierType::empty();
+
+    if modifiers.shift {
+        state |= gdk4::ModifierType::SHIFT_MASK;
+    }
+
+    if modifiers.ctrl {
+        state |= gdk4::ModifierType::CONTROL_MASK;
+    }
+
+    if modifiers.alt {
+        state |= gdk4::ModifierType::ALT_MASK;
+    }
+
+    if modifiers.meta {
+        state |= gdk4::ModifierType::SUPER_MASK;
+    }
+
+    state
+}
+
+/// Create a trigger for `accelerator`, for use in a [`gtk4::Shortcut`].
+pub(crate) fn trigger(accelerator: Accelerator) -> gtk4::KeyvalTrigger {
+    gtk4::KeyvalTrigger::new(
+        to_gdk_key(accelerator.key),
+        to_gdk_modifiers(accelerator.modifiers),
+    )
+}
+
+/// Convert `accelerator` to the format gtk uses, e.g. `<Control><Shift>s`.
+pub(crate) fn accelerator_name(accelerator: Accelerator) -> String {
+    gtk4::accelerator_name(
+        to_gdk_key(accelerator.key),
+        to_gdk_modifiers(accelerator.modifiers),
+    )
+    .into()
+}
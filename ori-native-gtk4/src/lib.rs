@@ -1,4 +1,8 @@
+mod app_action;
 mod application;
+mod clipboard;
+mod key;
+mod menu;
 mod platform;
 
 pub mod views;
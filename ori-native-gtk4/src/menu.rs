@@ -0,0 +1,122 @@
+use std::rc::Rc;
+
+use gio::prelude::{ActionMapExt, ToVariant};
+use ori_native_core::{Accelerator, MenuEntry, MenuModel};
+
+use crate::key;
+
+/// Build a [`gio::Menu`] from `model`, adding an action for every item to `actions`.
+///
+/// The actions are referred to as `{prefix}.{name}`, where `prefix` is the name `actions` are
+/// inserted with, and selecting an item calls `on_select` with its id.
+pub(crate) fn build_menu(
+    model: &MenuModel,
+    prefix: &str,
+    actions: &impl ActionMapExt,
+    on_select: &Rc<dyn Fn(usize)>,
+) -> gio::Menu {
+    let mut submenus = 0;
+    build_menu_with(
+        model,
+        prefix,
+        actions,
+        on_select,
+        &mut submenus,
+    )
+}
+
+fn build_menu_with(
+    model: &MenuModel,
+    prefix: &str,
+    actions: &impl ActionMapExt,
+    on_select: &Rc<dyn Fn(usize)>,
+    submenus: &mut usize,
+) -> gio::Menu {
+    let menu = gio::Menu::new();
+
+    // separators are represented by sections in gio menus
+    let mut section = gio::Menu::new();
+
+    for entry in &model.entries {
+        match entry {
+            MenuEntry::Item {
+                id,
+                label,
+                accelerator,
+                enabled,
+                checked,
+            } => {
+                let name = format!("item-{id}");
+
+                let action = match checked {
+                    Some(checked) => {
+                        gio::SimpleAction::new_stateful(&name, None, &checked.to_variant())
+                    }
+                    None => gio::SimpleAction::new(&name, None),
+                };
+
+                action.set_enabled(*enabled);
+                action.connect_activate({
+                    let on_select = on_select.clone();
+                    let id = *id;
+
+                    move |_, _| on_select(id)
+                });
+
+                actions.add_action(&action);
+
+                let item = gio::MenuItem::new(
+                    Some(label),
+                    Some(&format!("{prefix}.{name}")),
+                );
+
+                if let Some(accel) = accelerator.as_deref().and_then(self::accelerator) {
+                    item.set_attribute_value("accel", Some(&accel.to_variant()));
+                }
+
+                section.append_item(&item);
+            }
+
+            MenuEntry::Submenu {
+                label,
+                enabled,
+                menu,
+            } => {
+                let submenu = build_menu_with(
+                    menu, prefix, actions, on_select, submenus,
+                );
+                let item = gio::MenuItem::new_submenu(Some(label), &submenu);
+
+                // submenus can only be disabled through an action
+                let name = format!("submenu-{submenus}");
+                *submenus += 1;
+
+                let action = gio::SimpleAction::new(&name, None);
+                action.set_enabled(*enabled);
+                actions.add_action(&action);
+
+                item.set_attribute_value(
+                    "submenu-action",
+                    Some(&format!("{prefix}.{name}").to_variant()),
+                );
+
+                section.append_item(&item);
+            }
+
+            MenuEntry::Separator => {
+                menu.append_section(None, &section);
+                section = gio::Menu::new();
+            }
+        }
+    }
+
+    menu.append_section(None, &section);
+    menu
+}
+
+/// Convert an accelerator like `Ctrl+Shift+S` to the format gtk uses, `<Control><Shift>s`.
+pub(crate) fn accelerator(accelerator: &str) -> Option<String> {
+    Accelerator::parse(accelerator)
+        .ok()
+        .map(key::accelerator_name)
+}
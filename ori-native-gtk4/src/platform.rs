@@ -1,4 +1,4 @@
-use std::{collections::HashMap, pin::Pin, sync::Arc};
+use std::{cell::RefCell, collections::HashMap, marker::PhantomData, pin::Pin, rc::Rc, sync::Arc};
 
 use ori::{Message, Proxied, Proxy};
 use tokio::sync::mpsc::UnboundedSender;
@@ -16,6 +16,7 @@ impl StyleNode {
 
 pub struct Platform {
     pub(crate) proxy:         Gtk4Proxy,
+    pub(crate) dispatcher:    Dispatcher,
     pub(crate) display:       gdk4::Display,
     pub(crate) application:   gtk4::Application,
     pub(crate) css_providers: HashMap<StyleNode, gtk4::CssProvider>,
@@ -41,6 +42,7 @@ impl Platform {
 
         Self {
             proxy: Gtk4Proxy { sender, runtime },
+            dispatcher: Dispatcher::default(),
             display,
             application,
             css_providers: HashMap::new(),
@@ -48,6 +50,11 @@ impl Platform {
         }
     }
 
+    /// Get the [`gtk4::Application`], e.g. to add actions or accelerators to it.
+    pub fn application(&self) -> &gtk4::Application {
+        &self.application
+    }
+
     pub fn add_style(&mut self, styles: &str) -> StyleNode {
         let node = StyleNode(self.next_css_node);
         self.next_css_node += 1;
@@ -126,3 +133,55 @@ impl Proxy for Gtk4Proxy {
         self.runtime.spawn(future);
     }
 }
+
+/// Handles the pending events right away, for native callbacks that need the result of
+/// them before returning, e.g. whether a key event propagates.
+#[derive(Clone, Default)]
+#[allow(clippy::type_complexity)]
+pub(crate) struct Dispatcher {
+    dispatch: Rc<RefCell<Option<Box<dyn FnMut()>>>>,
+}
+
+impl Dispatcher {
+    /// Handle the pending events, does nothing while they're already being handled.
+    pub(crate) fn dispatch(&self) {
+        if let Ok(mut dispatch) = self.dispatch.try_borrow_mut()
+            && let Some(ref mut dispatch) = *dispatch
+        {
+            dispatch();
+        }
+    }
+
+    /// Use `dispatch` to handle the pending events, until the returned guard is dropped.
+    ///
+    /// The guard must be dropped, leaking it leaves `dispatch` around after `'a` ends.
+    pub(crate) fn scoped<'a>(&self, dispatch: impl FnMut() + 'a) -> DispatcherGuard<'a> {
+        let dispatch: Box<dyn FnMut() + 'a> = Box::new(dispatch);
+
+        // SAFETY: `dispatch` is removed when the guard is dropped, which happens before `'a`
+        // ends, see the comment above
+        let dispatch = unsafe {
+            std::mem::transmute::<Box<dyn FnMut() + 'a>, Box<dyn FnMut() + 'static>>(dispatch)
+        };
+
+        *self.dispatch.borrow_mut() = Some(dispatch);
+
+        DispatcherGuard {
+            dispatcher: self.clone(),
+            marker:     PhantomData,
+        }
+    }
+}
+
+pub(crate) struct DispatcherGuard<'a> {
+    dispatcher: Dispatcher,
+    marker:     PhantomData<&'a ()>,
+}
+
+impl Drop for DispatcherGuard<'_> {
+    fn drop(&mut self) {
+        if let Ok(mut dispatch) = self.dispatcher.dispatch.try_borrow_mut() {
+            dispatch.take();
+        }
+    }
+}
@@ -191,7 +191,7 @@ where
             self.contents.build(cx, data)
         });
 
-        window.set_child(Some(contents.widget.widget()));
+        window.set_contents(contents.widget.widget());
         window.show();
 
         let state = WindowState::new(
@@ -49,7 +49,7 @@ where
         (self.instance).assign_window_to_monitor(&window, &self.monitor);
         window.set_size_request(1, 1);
 
-        window.set_child(Some(contents.widget.widget()));
+        window.set_contents(contents.widget.widget());
         window.show();
 
         let state = WindowState::new(
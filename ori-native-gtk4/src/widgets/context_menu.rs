@@ -0,0 +1,93 @@
+use std::{cell::RefCell, rc::Rc};
+
+use glib::object::Cast;
+use gtk4::prelude::{GestureExt, GestureSingleExt, PopoverExt, WidgetExt};
+use ori_native_core::{
+    MenuModel, NativeWidget,
+    native::{HasContextMenu, NativeContextMenu},
+};
+
+use crate::{Platform, menu, widgets::group::GroupWidget};
+
+impl HasContextMenu for Platform {
+    type ContextMenu = ContextMenu;
+}
+
+pub struct ContextMenu {
+    widget:    GroupWidget,
+    popover:   gtk4::PopoverMenu,
+    on_select: Rc<RefCell<Rc<dyn Fn(usize)>>>,
+}
+
+impl NativeWidget<Platform> for ContextMenu {
+    fn widget(&self) -> &gtk4::Widget {
+        self.widget.as_ref()
+    }
+}
+
+impl NativeContextMenu<Platform> for ContextMenu {
+    fn build(_platform: &mut Platform, contents: &gtk4::Widget) -> Self {
+        let widget = GroupWidget::new();
+        widget.insert_child(0, contents);
+
+        let popover = gtk4::PopoverMenu::from_model(None::<&gio::MenuModel>);
+        popover.set_has_arrow(false);
+        widget.set_popover(Some(popover.upcast_ref()));
+
+        let controller = gtk4::GestureClick::new();
+        controller.set_button(gdk4::BUTTON_SECONDARY);
+        controller.connect_pressed({
+            let popover = popover.clone();
+
+            move |gesture, _, x, y| {
+                let rect = gdk4::Rectangle::new(x as i32, y as i32, 1, 1);
+                popover.set_pointing_to(Some(&rect));
+                popover.popup();
+
+                gesture.set_state(gtk4::EventSequenceState::Claimed);
+            }
+        });
+
+        widget.add_controller(controller);
+
+        Self {
+            widget,
+            popover,
+            on_select: Rc::new(RefCell::new(Rc::new(|_| {}))),
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {
+        self.widget.set_popover(None);
+    }
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        let width = width.round() as i32;
+        let height = height.round() as i32;
+
+        self.widget.set_size(width, height);
+        (self.widget).set_child_layout(0, 0, 0, width, height);
+    }
+
+    fn set_menu(&mut self, _platform: &mut Platform, model: &MenuModel) {
+        let on_select: Rc<dyn Fn(usize)> = Rc::new({
+            let on_select = self.on_select.clone();
+
+            move |id| {
+                let on_select = on_select.borrow().clone();
+                on_select(id);
+            }
+        });
+
+        // replace the whole action group, dropping the actions of the previous menu
+        let actions = gio::SimpleActionGroup::new();
+        let menu = menu::build_menu(model, "context", &actions, &on_select);
+
+        (self.widget).insert_action_group("context", Some(&actions));
+        self.popover.set_menu_model(Some(&menu));
+    }
+
+    fn set_on_select(&mut self, on_select: impl Fn(usize) + 'static) {
+        *self.on_select.borrow_mut() = Rc::new(on_select);
+    }
+}
@@ -0,0 +1,301 @@
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use gdk4::prelude::{ContentProviderExtManual, DragExt};
+use glib::{BoxedAnyObject, prelude::StaticType, value::ToValue};
+use gtk4::prelude::{EventControllerExt, GestureDragExt, GestureExt, GestureSingleExt, WidgetExt};
+use ori_native_core::{
+    DragEvent, DragPhase, NativeWidget,
+    native::{
+        HasDragGesture, HasDragSource, HasDropTarget, NativeDragGesture, NativeDragSource,
+        NativeDropTarget,
+    },
+};
+
+use crate::{Platform, widgets::group::GroupWidget};
+
+impl HasDragGesture for Platform {
+    type DragGesture = DragGesture;
+}
+
+impl HasDragSource for Platform {
+    type DragSource = DragSource;
+}
+
+impl HasDropTarget for Platform {
+    type DropTarget = DropTarget;
+}
+
+pub struct DragGesture {
+    widget:    GroupWidget,
+    gesture:   Option<gtk4::GestureDrag>,
+    threshold: Rc<Cell<f32>>,
+}
+
+impl NativeWidget<Platform> for DragGesture {
+    fn widget(&self) -> &gtk4::Widget {
+        self.widget.as_ref()
+    }
+}
+
+impl NativeDragGesture<Platform> for DragGesture {
+    fn build(_platform: &mut Platform, contents: &gtk4::Widget) -> Self {
+        let widget = GroupWidget::new();
+        widget.insert_child(0, contents);
+
+        Self {
+            widget,
+            gesture: None,
+            threshold: Rc::new(Cell::new(0.0)),
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        let width = width.round() as i32;
+        let height = height.round() as i32;
+
+        self.widget.set_size(width, height);
+        (self.widget).set_child_layout(0, 0, 0, width, height);
+    }
+
+    fn set_threshold(&mut self, threshold: f32) {
+        self.threshold.set(threshold);
+    }
+
+    fn set_on_drag(&mut self, on_drag: impl Fn(DragEvent) + 'static) {
+        if let Some(gesture) = self.gesture.take() {
+            self.widget.remove_controller(&gesture);
+        }
+
+        let on_drag = Rc::new(on_drag);
+        let dragging = Rc::new(Cell::new(false));
+
+        let send = {
+            let on_drag = on_drag.clone();
+
+            move |gesture: &gtk4::GestureDrag, phase, offset_x: f64, offset_y: f64| {
+                let (start_x, start_y) = gesture.start_point().unwrap_or_default();
+
+                on_drag(DragEvent {
+                    phase,
+                    start_x: start_x as f32,
+                    start_y: start_y as f32,
+                    offset_x: offset_x as f32,
+                    offset_y: offset_y as f32,
+                });
+            }
+        };
+
+        let gesture = gtk4::GestureDrag::new();
+        gesture.set_button(gdk4::BUTTON_PRIMARY);
+
+        gesture.connect_drag_update({
+            let threshold = self.threshold.clone();
+            let dragging = dragging.clone();
+            let send = send.clone();
+
+            move |gesture, offset_x, offset_y| {
+                if dragging.get() {
+                    send(
+                        gesture,
+                        DragPhase::Update,
+                        offset_x,
+                        offset_y,
+                    );
+                    return;
+                }
+
+                if offset_x.hypot(offset_y) >= threshold.get() as f64 {
+                    dragging.set(true);
+
+                    // claim the presses, so the contents don't see a click
+                    gesture.set_state(gtk4::EventSequenceState::Claimed);
+                    send(
+                        gesture,
+                        DragPhase::Start,
+                        offset_x,
+                        offset_y,
+                    );
+                }
+            }
+        });
+
+        gesture.connect_drag_end({
+            let dragging = dragging.clone();
+
+            move |gesture, offset_x, offset_y| {
+                if dragging.replace(false) {
+                    send(
+                        gesture,
+                        DragPhase::End,
+                        offset_x,
+                        offset_y,
+                    );
+                }
+            }
+        });
+
+        self.widget.add_controller(gesture.clone());
+        self.gesture = Some(gesture);
+    }
+}
+
+pub struct DragSource {
+    widget:  GroupWidget,
+    source:  gtk4::DragSource,
+    payload: Rc<RefCell<Rc<dyn Any>>>,
+}
+
+impl NativeWidget<Platform> for DragSource {
+    fn widget(&self) -> &gtk4::Widget {
+        self.widget.as_ref()
+    }
+}
+
+impl NativeDragSource<Platform> for DragSource {
+    fn build(_platform: &mut Platform, contents: &gtk4::Widget) -> Self {
+        let widget = GroupWidget::new();
+        widget.insert_child(0, contents);
+
+        let payload: Rc<RefCell<Rc<dyn Any>>> = Rc::new(RefCell::new(Rc::new(())));
+
+        let source = gtk4::DragSource::new();
+        source.set_actions(gdk4::DragAction::MOVE);
+
+        // payloads never leave the process, so they're wrapped as is
+        source.connect_prepare({
+            let payload = payload.clone();
+
+            move |source, x, y| {
+                if let Some(widget) = source.widget() {
+                    let icon = gtk4::WidgetPaintable::new(Some(&widget));
+                    source.set_icon(Some(&icon), x as i32, y as i32);
+                }
+
+                let payload = BoxedAnyObject::new(payload.borrow().clone());
+                Some(gdk4::ContentProvider::for_value(
+                    &payload.to_value(),
+                ))
+            }
+        });
+
+        widget.add_controller(source.clone());
+
+        Self {
+            widget,
+            source,
+            payload,
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        let width = width.round() as i32;
+        let height = height.round() as i32;
+
+        self.widget.set_size(width, height);
+        (self.widget).set_child_layout(0, 0, 0, width, height);
+    }
+
+    fn set_payload(&mut self, payload: Rc<dyn Any>) {
+        *self.payload.borrow_mut() = payload;
+    }
+
+    fn set_on_drag_start(&mut self, on_drag_start: impl Fn() + 'static) {
+        self.source.connect_drag_begin(move |_, _| on_drag_start());
+    }
+
+    fn set_on_drag_end(&mut self, on_drag_end: impl Fn(bool) + 'static) {
+        // the data is only deleted when a move was performed, i.e. the payload was dropped
+        (self.source).connect_drag_end(move |_, _, delete_data| on_drag_end(delete_data));
+    }
+}
+
+pub struct DropTarget {
+    widget: GroupWidget,
+    target: gtk4::DropTarget,
+}
+
+impl NativeWidget<Platform> for DropTarget {
+    fn widget(&self) -> &gtk4::Widget {
+        self.widget.as_ref()
+    }
+}
+
+impl NativeDropTarget<Platform> for DropTarget {
+    fn build(_platform: &mut Platform, contents: &gtk4::Widget) -> Self {
+        let widget = GroupWidget::new();
+        widget.insert_child(0, contents);
+
+        let target = gtk4::DropTarget::new(
+            BoxedAnyObject::static_type(),
+            gdk4::DragAction::MOVE,
+        );
+
+        widget.add_controller(target.clone());
+
+        Self { widget, target }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        let width = width.round() as i32;
+        let height = height.round() as i32;
+
+        self.widget.set_size(width, height);
+        (self.widget).set_child_layout(0, 0, 0, width, height);
+    }
+
+    fn set_accept(&mut self, accept: impl Fn(&dyn Any) -> bool + 'static) {
+        self.target.connect_accept(move |_, drop| {
+            // drags within the process carry their content, which can be inspected directly
+            let Some(drag) = drop.drag() else {
+                return false;
+            };
+
+            let Ok(value) = drag.content().value(BoxedAnyObject::static_type()) else {
+                return false;
+            };
+
+            match value.get::<BoxedAnyObject>() {
+                Ok(payload) => accept(&**payload.borrow::<Rc<dyn Any>>()),
+                Err(_) => false,
+            }
+        });
+    }
+
+    fn set_on_hover(&mut self, on_hover: impl Fn(bool) + 'static) {
+        let on_hover = Rc::new(on_hover);
+
+        self.target.connect_enter({
+            let on_hover = on_hover.clone();
+
+            move |_, _, _| {
+                on_hover(true);
+                gdk4::DragAction::MOVE
+            }
+        });
+
+        self.target.connect_leave(move |_| on_hover(false));
+    }
+
+    fn set_on_drop(&mut self, on_drop: impl Fn(&dyn Any) + 'static) {
+        self.target.connect_drop(
+            move |_, value, _, _| match value.get::<BoxedAnyObject>() {
+                Ok(payload) => {
+                    on_drop(&**payload.borrow::<Rc<dyn Any>>());
+                    true
+                }
+
+                Err(_) => false,
+            },
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use glib::prelude::{Cast, CastNone, StaticType};
+use gtk4::prelude::{ListItemExt, ListModelExt, WidgetExt};
+use ori_native_core::{
+    LayoutLeaf, NativeWidget,
+    native::{HasDropdown, NativeDropdown},
+    views::DropdownOption,
+};
+
+use crate::Platform;
+
+impl HasDropdown for Platform {
+    type Dropdown = Dropdown;
+}
+
+pub struct Dropdown {
+    dropdown: gtk4::DropDown,
+    model:    gtk4::StringList,
+    disabled: Rc<RefCell<Vec<bool>>>,
+    updating: Rc<Cell<bool>>,
+
+    /// The selected position, to go back to if a disabled option is selected.
+    selected: Rc<Cell<u32>>,
+}
+
+impl NativeWidget<Platform> for Dropdown {
+    fn widget(&self) -> &gtk4::Widget {
+        self.dropdown.as_ref()
+    }
+}
+
+impl NativeDropdown<Platform> for Dropdown {
+    fn build(_platform: &mut Platform) -> Self {
+        let model = gtk4::StringList::new(&[]);
+        let disabled = Rc::new(RefCell::new(Vec::<bool>::new()));
+
+        let dropdown = gtk4::DropDown::new(
+            Some(model.clone()),
+            None::<gtk4::Expression>,
+        );
+
+        // searching matches the strings of the model
+        dropdown.set_expression(Some(gtk4::PropertyExpression::new(
+            gtk4::StringObject::static_type(),
+            None::<gtk4::Expression>,
+            "string",
+        )));
+
+        let factory = gtk4::SignalListItemFactory::new();
+
+        factory.connect_setup(|_, item| {
+            if let Some(item) = item.dynamic_cast_ref::<gtk4::ListItem>() {
+                let label = gtk4::Label::new(None);
+                label.set_xalign(0.0);
+                item.set_child(Some(&label));
+            }
+        });
+
+        factory.connect_bind({
+            let model = model.clone();
+            let disabled = disabled.clone();
+
+            move |_, item| {
+                let Some(item) = item.dynamic_cast_ref::<gtk4::ListItem>() else {
+                    return;
+                };
+
+                let object = item.item();
+                let string = (object.clone())
+                    .and_downcast::<gtk4::StringObject>()
+                    .map(|object| object.string());
+
+                // the position of the item is in the filtered options while searching
+                let position = (0..model.n_items()).find(|&i| model.item(i) == object);
+
+                let enabled = !position
+                    .and_then(|position| disabled.borrow().get(position as usize).copied())
+                    .unwrap_or(false);
+
+                // disabled options are shown, but can't be picked
+                item.set_selectable(enabled);
+                item.set_activatable(enabled);
+
+                if let Some(label) = item.child().and_downcast::<gtk4::Label>() {
+                    label.set_label(string.as_deref().unwrap_or_default());
+                    label.set_sensitive(enabled);
+                }
+            }
+        });
+
+        dropdown.set_factory(Some(&factory));
+
+        Self {
+            dropdown,
+            model,
+            disabled,
+            updating: Rc::new(Cell::new(false)),
+            selected: Rc::new(Cell::new(gtk4::INVALID_LIST_POSITION)),
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_options(&mut self, options: &[DropdownOption]) {
+        *self.disabled.borrow_mut() = options.iter().map(|option| option.disabled).collect();
+
+        let labels: Vec<_> = options.iter().map(|option| option.label.as_str()).collect();
+
+        self.updating.set(true);
+        (self.model).splice(0, self.model.n_items(), &labels);
+        self.updating.set(false);
+    }
+
+    fn set_selected(&mut self, selected: Option<usize>) {
+        let position = match selected {
+            Some(index) => index as u32,
+            None => gtk4::INVALID_LIST_POSITION,
+        };
+
+        self.updating.set(true);
+        self.dropdown.set_selected(position);
+        self.selected.set(self.dropdown.selected());
+        self.updating.set(false);
+    }
+
+    fn set_searchable(&mut self, searchable: bool) {
+        self.dropdown.set_enable_search(searchable);
+    }
+
+    fn set_on_select(&mut self, on_select: impl Fn(usize) + 'static) {
+        let disabled = self.disabled.clone();
+        let updating = self.updating.clone();
+        let selected = self.selected.clone();
+
+        self.dropdown.connect_selected_notify(move |dropdown| {
+            if updating.get() {
+                return;
+            }
+
+            let position = dropdown.selected();
+
+            if position == gtk4::INVALID_LIST_POSITION {
+                return;
+            }
+
+            let is_disabled = disabled.borrow().get(position as usize).copied();
+
+            if is_disabled.unwrap_or(false) {
+                updating.set(true);
+                dropdown.set_selected(selected.get());
+                updating.set(false);
+
+                return;
+            }
+
+            selected.set(position);
+            on_select(position as usize);
+        });
+    }
+
+    fn get_layout(&mut self, _platform: &mut Platform) -> impl LayoutLeaf<Platform> {
+        Layout {
+            widget: self.widget().clone(),
+        }
+    }
+}
+
+struct Layout {
+    widget: gtk4::Widget,
+}
+
+impl LayoutLeaf<Platform> for Layout {
+    fn measure(
+        &mut self,
+        _platform: &mut Platform,
+        _known_size: taffy::Size<Option<f32>>,
+        _available_space: taffy::Size<taffy::AvailableSpace>,
+    ) -> taffy::Size<f32> {
+        let (_, width, _, _) = self.widget.measure(gtk4::Orientation::Horizontal, -1);
+        let (_, height, _, _) = self.widget.measure(gtk4::Orientation::Vertical, width);
+
+        taffy::Size {
+            width:  width as f32,
+            height: height as f32,
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use std::rc::Rc;
+
+use gio::prelude::FileExt;
+use glib::prelude::StaticType;
+use gtk4::prelude::WidgetExt;
+use ori_native_core::{
+    ExternalDrop, NativeWidget,
+    native::{HasFileDrop, NativeFileDrop},
+};
+
+use crate::{Platform, widgets::group::GroupWidget};
+
+impl HasFileDrop for Platform {
+    type FileDrop = FileDrop;
+}
+
+pub struct FileDrop {
+    widget: GroupWidget,
+    target: gtk4::DropTarget,
+}
+
+impl NativeWidget<Platform> for FileDrop {
+    fn widget(&self) -> &gtk4::Widget {
+        self.widget.as_ref()
+    }
+}
+
+impl NativeFileDrop<Platform> for FileDrop {
+    fn build(_platform: &mut Platform, contents: &gtk4::Widget) -> Self {
+        let widget = GroupWidget::new();
+        widget.insert_child(0, contents);
+
+        // the accepted types are set by `set_accepts`
+        let target = gtk4::DropTarget::new(
+            glib::Type::INVALID,
+            gdk4::DragAction::COPY,
+        );
+
+        widget.add_controller(target.clone());
+
+        Self { widget, target }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        let width = width.round() as i32;
+        let height = height.round() as i32;
+
+        self.widget.set_size(width, height);
+        (self.widget).set_child_layout(0, 0, 0, width, height);
+    }
+
+    fn set_accepts(&mut self, files: bool, text: bool) {
+        let mut types = Vec::new();
+
+        if files {
+            types.push(gdk4::FileList::static_type());
+        }
+
+        if text {
+            types.push(String::static_type());
+        }
+
+        self.target.set_types(&types);
+    }
+
+    fn set_on_hover(&mut self, on_hover: impl Fn(bool) + 'static) {
+        let on_hover = Rc::new(on_hover);
+
+        self.target.connect_enter({
+            let on_hover = on_hover.clone();
+
+            move |_, _, _| {
+                on_hover(true);
+                gdk4::DragAction::COPY
+            }
+        });
+
+        self.target.connect_leave(move |_| on_hover(false));
+    }
+
+    fn set_on_drop(&mut self, on_drop: impl Fn(ExternalDrop) + 'static) {
+        self.target.connect_drop(move |_, value, _, _| {
+            if let Ok(files) = value.get::<gdk4::FileList>() {
+                let paths = files
+                    .files()
+                    .iter()
+                    .filter_map(|file| file.path())
+                    .collect();
+                on_drop(ExternalDrop::Files(paths));
+                return true;
+            }
+
+            if let Ok(text) = value.get::<String>() {
+                on_drop(ExternalDrop::Text(text));
+                return true;
+            }
+
+            false
+        });
+    }
+}
@@ -73,6 +73,10 @@ impl NativeGroup<Platform> for Group {
         );
     }
 
+    fn set_child_visible(&mut self, index: usize, visible: bool) {
+        self.group.set_child_visible(index, visible);
+    }
+
     fn set_background_color(&mut self, _platform: &mut Platform, color: Color) {
         self.group.set_background_color(gdk4::RGBA::new(
             color.r, color.g, color.b, color.a,
@@ -160,6 +164,18 @@ impl GroupWidget {
         }
     }
 
+    /// Attach a popover, which is positioned relative to the group.
+    pub fn set_popover(&self, popover: Option<&gtk4::Popover>) {
+        if let Some(previous) = self.imp().popover.take() {
+            previous.unparent();
+        }
+
+        if let Some(popover) = popover {
+            popover.set_parent(self);
+            self.imp().popover.replace(Some(popover.clone()));
+        }
+    }
+
     pub fn insert_child(&self, index: usize, child: &gtk4::Widget) {
         let mut children = self.imp().children.borrow_mut();
 
@@ -218,6 +234,12 @@ impl GroupWidget {
         children.swap(a, b);
     }
 
+    pub fn set_child_visible(&self, index: usize, visible: bool) {
+        if let Some(child) = self.imp().children.borrow().get(index) {
+            child.widget.set_child_visible(visible);
+        }
+    }
+
     pub fn set_child_layout(&self, index: usize, x: i32, y: i32, width: i32, height: i32) {
         if let Some(child) = self.imp().children.borrow_mut().get_mut(index) {
             if child.x != x || child.y != y || child.width != width || child.height != height {
@@ -238,12 +260,13 @@ mod imp {
 
     use glib::subclass::{object::ObjectImpl, types::ObjectSubclass};
     use gtk4::{
-        prelude::{SnapshotExt, SnapshotExtManual, WidgetExt},
+        prelude::{PopoverExt, SnapshotExt, SnapshotExtManual, WidgetExt},
         subclass::widget::{WidgetClassExt, WidgetImpl, WidgetImplExt},
     };
 
     pub struct GroupWidget {
         pub(super) children: RefCell<Vec<Child>>,
+        pub(super) popover:  RefCell<Option<gtk4::Popover>>,
 
         pub(super) width:  Cell<i32>,
         pub(super) height: Cell<i32>,
@@ -267,6 +290,7 @@ mod imp {
         fn default() -> Self {
             Self {
                 children: RefCell::default(),
+                popover:  RefCell::default(),
 
                 width:  Cell::new(0),
                 height: Cell::new(0),
@@ -296,6 +320,10 @@ mod imp {
             for child in self.children.borrow().iter() {
                 child.widget.unparent();
             }
+
+            if let Some(popover) = self.popover.take() {
+                popover.unparent();
+            }
         }
     }
 
@@ -354,6 +382,10 @@ mod imp {
                     -1,
                 );
             }
+
+            if let Some(popover) = self.popover.borrow().as_ref() {
+                popover.present();
+            }
         }
 
         fn measure(&self, orientation: gtk4::Orientation, for_size: i32) -> (i32, i32, i32, i32) {
@@ -0,0 +1,132 @@
+use std::{cell::RefCell, collections::HashSet, rc::Rc};
+
+use glib::object::Cast;
+use gtk4::prelude::{EventControllerExt, GestureExt, WidgetExt};
+use ori_native_core::{
+    KeyEvent, KeyState, NativeWidget, Propagation,
+    native::{HasKeyHandler, NativeKeyHandler},
+};
+
+use crate::{Platform, key, platform::Dispatcher, widgets::group::GroupWidget};
+
+impl HasKeyHandler for Platform {
+    type KeyHandler = KeyHandler;
+}
+
+pub struct KeyHandler {
+    widget:      GroupWidget,
+    dispatcher:  Dispatcher,
+    controllers: Vec<gtk4::EventController>,
+}
+
+impl NativeWidget<Platform> for KeyHandler {
+    fn widget(&self) -> &gtk4::Widget {
+        self.widget.as_ref()
+    }
+}
+
+impl NativeKeyHandler<Platform> for KeyHandler {
+    fn build(platform: &mut Platform, contents: &gtk4::Widget) -> Self {
+        let widget = GroupWidget::new();
+        widget.insert_child(0, contents);
+        widget.set_focusable(true);
+
+        // take the focus when clicked, without claiming the click from the contents
+        let click = gtk4::GestureClick::new();
+        click.set_propagation_phase(gtk4::PropagationPhase::Capture);
+        click.connect_pressed(|gesture, _, _, _| {
+            if let Some(widget) = gesture.widget()
+                && !widget.has_focus()
+            {
+                widget.grab_focus();
+            }
+
+            gesture.set_state(gtk4::EventSequenceState::Denied);
+        });
+
+        widget.add_controller(click);
+
+        Self {
+            widget,
+            dispatcher: platform.dispatcher.clone(),
+            controllers: Vec::new(),
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        let width = width.round() as i32;
+        let height = height.round() as i32;
+
+        self.widget.set_size(width, height);
+        (self.widget).set_child_layout(0, 0, 0, width, height);
+    }
+
+    fn set_on_key(
+        &mut self,
+        on_key: impl Fn(KeyEvent) + 'static,
+        propagation: impl Fn() -> Option<Propagation> + 'static,
+    ) {
+        for controller in self.controllers.drain(..) {
+            self.widget.remove_controller(&controller);
+        }
+
+        let on_key = Rc::new(on_key);
+
+        // gtk doesn't report repeats, so keep track of the keys held down
+        let held = Rc::new(RefCell::new(HashSet::new()));
+
+        let controller = gtk4::EventControllerKey::new();
+        controller.connect_key_pressed({
+            let on_key = on_key.clone();
+            let held = held.clone();
+            let dispatcher = self.dispatcher.clone();
+
+            move |_, keyval, code, state| {
+                let event = KeyEvent {
+                    key: key::from_gdk_key(keyval),
+                    code,
+                    modifiers: key::from_gdk_modifiers(state),
+                    repeat: !held.borrow_mut().insert(code),
+                    state: KeyState::Pressed,
+                };
+
+                // handle the event right away, for the view to decide whether it propagates
+                on_key(event);
+                dispatcher.dispatch();
+
+                match propagation() {
+                    Some(Propagation::Stop) => glib::Propagation::Stop,
+                    Some(Propagation::Proceed) | None => glib::Propagation::Proceed,
+                }
+            }
+        });
+
+        controller.connect_key_released({
+            let held = held.clone();
+
+            move |_, keyval, code, state| {
+                held.borrow_mut().remove(&code);
+
+                on_key(KeyEvent {
+                    key: key::from_gdk_key(keyval),
+                    code,
+                    modifiers: key::from_gdk_modifiers(state),
+                    repeat: false,
+                    state: KeyState::Released,
+                });
+            }
+        });
+
+        // releases aren't delivered once the focus leaves, so forget the held keys
+        let focus = gtk4::EventControllerFocus::new();
+        focus.connect_leave(move |_| held.borrow_mut().clear());
+
+        self.widget.add_controller(controller.clone());
+        self.widget.add_controller(focus.clone());
+
+        self.controllers.push(controller.upcast());
+        self.controllers.push(focus.upcast());
+    }
+}
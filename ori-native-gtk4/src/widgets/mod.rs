@@ -1,9 +1,20 @@
+mod context_menu;
+mod drag;
+mod dropdown;
+mod file_drop;
 mod group;
 mod image;
+mod key_handler;
+mod pointer_handler;
+mod popover;
 mod pressable;
+mod progress;
 mod scroll;
+mod shortcuts;
+mod slider;
 mod text;
 mod textinput;
+mod toggle;
 mod window;
 
 pub use window::Window;
@@ -0,0 +1,235 @@
+use std::{cell::Cell, rc::Rc};
+
+use glib::object::Cast;
+use gtk4::prelude::{EventControllerExt, GestureDragExt, GestureExt, GestureSingleExt, WidgetExt};
+use ori_native_core::{
+    NativeWidget, PointerButton, PointerEvent, PointerEventKind,
+    native::{HasPointerHandler, NativePointerHandler},
+};
+
+use crate::{Platform, key, widgets::group::GroupWidget};
+
+impl HasPointerHandler for Platform {
+    type PointerHandler = PointerHandler;
+}
+
+pub struct PointerHandler {
+    widget:      GroupWidget,
+    controllers: Vec<gtk4::EventController>,
+}
+
+impl NativeWidget<Platform> for PointerHandler {
+    fn widget(&self) -> &gtk4::Widget {
+        self.widget.as_ref()
+    }
+}
+
+impl NativePointerHandler<Platform> for PointerHandler {
+    fn build(_platform: &mut Platform, contents: &gtk4::Widget) -> Self {
+        let widget = GroupWidget::new();
+        widget.insert_child(0, contents);
+
+        Self {
+            widget,
+            controllers: Vec::new(),
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        let width = width.round() as i32;
+        let height = height.round() as i32;
+
+        self.widget.set_size(width, height);
+        (self.widget).set_child_layout(0, 0, 0, width, height);
+    }
+
+    fn set_on_pointer(&mut self, on_pointer: impl Fn(PointerEvent) + 'static) {
+        for controller in self.controllers.drain(..) {
+            self.widget.remove_controller(&controller);
+        }
+
+        let on_pointer = Rc::new(on_pointer);
+
+        // scroll events have no position, so keep track of the last one
+        let position = Rc::new(Cell::new((0.0, 0.0)));
+        let button = Rc::new(Cell::new(None));
+
+        let send = {
+            let on_pointer = on_pointer.clone();
+            let position = position.clone();
+
+            move |controller: &gtk4::EventController, kind| {
+                let (x, y) = position.get();
+
+                on_pointer(PointerEvent {
+                    kind,
+                    x,
+                    y,
+                    modifiers: key::from_gdk_modifiers(controller.current_event_state()),
+                });
+            }
+        };
+
+        let motion = gtk4::EventControllerMotion::new();
+        motion.connect_enter({
+            let position = position.clone();
+            let send = send.clone();
+
+            move |controller, x, y| {
+                position.set((x as f32, y as f32));
+                send(
+                    controller.upcast_ref(),
+                    PointerEventKind::Enter,
+                );
+            }
+        });
+
+        motion.connect_motion({
+            let position = position.clone();
+            let send = send.clone();
+
+            move |controller, x, y| {
+                position.set((x as f32, y as f32));
+                send(
+                    controller.upcast_ref(),
+                    PointerEventKind::Move,
+                );
+            }
+        });
+
+        motion.connect_leave({
+            let send = send.clone();
+
+            move |controller| {
+                send(
+                    controller.upcast_ref(),
+                    PointerEventKind::Leave,
+                )
+            }
+        });
+
+        // a drag gesture keeps reporting while the button is held outside the widget, where
+        // the motion controller doesn't, and it's observed in the capture phase without
+        // claiming, so the contents still receive the presses
+        let drag = gtk4::GestureDrag::new();
+        drag.set_button(0);
+        drag.set_propagation_phase(gtk4::PropagationPhase::Capture);
+
+        drag.connect_drag_begin({
+            let position = position.clone();
+            let button = button.clone();
+            let send = send.clone();
+
+            move |gesture, x, y| {
+                let pressed = pointer_button(gesture.current_button());
+                position.set((x as f32, y as f32));
+                button.set(Some(pressed));
+
+                send(
+                    gesture.upcast_ref(),
+                    PointerEventKind::Down(pressed),
+                );
+            }
+        });
+
+        drag.connect_drag_update({
+            let position = position.clone();
+            let button = button.clone();
+            let send = send.clone();
+
+            move |gesture, offset_x, offset_y| {
+                if button.get().is_none() {
+                    return;
+                }
+
+                let Some((x, y)) = gesture.start_point() else {
+                    return;
+                };
+
+                let moved = (
+                    (x + offset_x) as f32,
+                    (y + offset_y) as f32,
+                );
+
+                // inside the widget the motion controller already reported the position
+                if moved != position.get() {
+                    position.set(moved);
+                    send(
+                        gesture.upcast_ref(),
+                        PointerEventKind::Move,
+                    );
+                }
+            }
+        });
+
+        drag.connect_drag_end({
+            let position = position.clone();
+            let button = button.clone();
+            let send = send.clone();
+
+            move |gesture, offset_x, offset_y| {
+                if let Some(released) = button.take()
+                    && let Some((x, y)) = gesture.start_point()
+                {
+                    position.set((
+                        (x + offset_x) as f32,
+                        (y + offset_y) as f32,
+                    ));
+
+                    send(
+                        gesture.upcast_ref(),
+                        PointerEventKind::Up(released),
+                    );
+                }
+            }
+        });
+
+        // the gesture is cancelled when the contents claim the sequence, the release is then
+        // no longer reported
+        drag.connect_cancel({
+            let send = send.clone();
+
+            move |gesture, _| {
+                if let Some(pressed) = button.take() {
+                    send(
+                        gesture.upcast_ref(),
+                        PointerEventKind::Cancel(pressed),
+                    );
+                }
+            }
+        });
+
+        let scroll = gtk4::EventControllerScroll::new(gtk4::EventControllerScrollFlags::BOTH_AXES);
+
+        scroll.connect_scroll(move |controller, delta_x, delta_y| {
+            let kind = PointerEventKind::Scroll {
+                delta_x: delta_x as f32,
+                delta_y: delta_y as f32,
+            };
+
+            send(controller.upcast_ref(), kind);
+            glib::Propagation::Proceed
+        });
+
+        self.widget.add_controller(motion.clone());
+        self.widget.add_controller(drag.clone());
+        self.widget.add_controller(scroll.clone());
+
+        self.controllers.push(motion.upcast());
+        self.controllers.push(drag.upcast());
+        self.controllers.push(scroll.upcast());
+    }
+}
+
+fn pointer_button(button: u32) -> PointerButton {
+    match button {
+        gdk4::BUTTON_PRIMARY => PointerButton::Primary,
+        gdk4::BUTTON_SECONDARY => PointerButton::Secondary,
+        gdk4::BUTTON_MIDDLE => PointerButton::Middle,
+        8 => PointerButton::Back,
+        9 => PointerButton::Forward,
+        button => PointerButton::Other(button),
+    }
+}
@@ -0,0 +1,172 @@
+use std::{cell::Cell, rc::Rc};
+
+use glib::object::ObjectExt;
+use gtk4::prelude::{PopoverExt, WidgetExt};
+use ori_native_core::{
+    NativeParent, NativeWidget,
+    native::{HasPopover, NativePopover, Placement},
+};
+
+use crate::{Platform, widgets::group::GroupWidget};
+
+impl HasPopover for Platform {
+    type Popover = Popover;
+}
+
+pub struct Popover {
+    widget:   GroupWidget,
+    contents: GroupWidget,
+    popover:  gtk4::Popover,
+
+    /// Whether the popover should be open, it can only be shown while the anchor is mapped,
+    /// so opening is deferred until then.
+    open: Rc<Cell<bool>>,
+
+    /// Whether the popover is being closed by [`NativePopover::set_open`], in which case it
+    /// wasn't dismissed by the user.
+    closing: Rc<Cell<bool>>,
+}
+
+impl NativeWidget<Platform> for Popover {
+    fn widget(&self) -> &gtk4::Widget {
+        self.widget.as_ref()
+    }
+}
+
+impl NativeParent<Platform> for Popover {
+    fn replace_child(&mut self, _platform: &mut Platform, index: usize, child: &gtk4::Widget) {
+        match index {
+            0 => self.widget.replace_child(0, child),
+            1 => self.contents.replace_child(0, child),
+            _ => debug_assert!(false, "popover only has two children"),
+        }
+    }
+}
+
+impl NativePopover<Platform> for Popover {
+    fn build(_platform: &mut Platform, anchor: &gtk4::Widget, contents: &gtk4::Widget) -> Self {
+        let widget = GroupWidget::new();
+        widget.insert_child(0, anchor);
+
+        let group = GroupWidget::new();
+        group.insert_child(0, contents);
+
+        let popover = gtk4::Popover::new();
+        popover.set_child(Some(&group));
+        popover.set_autohide(true);
+        popover.set_has_arrow(false);
+        widget.set_popover(Some(&popover));
+
+        let open = Rc::new(Cell::new(false));
+        let closing = Rc::new(Cell::new(false));
+
+        widget.connect_map({
+            let popover = popover.downgrade();
+            let open = open.clone();
+
+            move |_| {
+                if let Some(popover) = popover.upgrade()
+                    && open.get()
+                {
+                    popover.popup();
+                }
+            }
+        });
+
+        popover.connect_closed({
+            let widget = widget.downgrade();
+            let open = open.clone();
+            let closing = closing.clone();
+
+            move |_| {
+                // the popover is also closed when the anchor is unmapped, it's opened again
+                // when the anchor is mapped
+                if !closing.get() && widget.upgrade().is_some_and(|w| w.is_mapped()) {
+                    open.set(false);
+                }
+            }
+        });
+
+        let mut this = Self {
+            widget,
+            contents: group,
+            popover,
+            open,
+            closing,
+        };
+
+        this.set_placement(Placement::Below, true);
+        this
+    }
+
+    fn teardown(self, _platform: &mut Platform) {
+        self.widget.set_popover(None);
+    }
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        self.widget.set_size(
+            width.round() as i32,
+            height.round() as i32,
+        );
+    }
+
+    fn set_anchor_layout(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        let x = x.round() as i32;
+        let y = y.round() as i32;
+        let width = width.round() as i32;
+        let height = height.round() as i32;
+
+        (self.widget).set_child_layout(0, x, y, width, height);
+        (self.popover).set_pointing_to(Some(&gdk4::Rectangle::new(
+            x, y, width, height,
+        )));
+    }
+
+    fn set_contents_size(&mut self, width: f32, height: f32) {
+        let width = width.round() as i32;
+        let height = height.round() as i32;
+
+        self.contents.set_size(width, height);
+        (self.contents).set_child_layout(0, 0, 0, width, height);
+    }
+
+    fn set_placement(&mut self, placement: Placement, _flip: bool) {
+        // gtk always lets the compositor flip popovers that don't fit, so `flip` can't be
+        // honored here
+        let rtl = self.widget.direction() == gtk4::TextDirection::Rtl;
+
+        self.popover.set_position(match placement {
+            Placement::Above => gtk4::PositionType::Top,
+            Placement::Below => gtk4::PositionType::Bottom,
+            Placement::Start if rtl => gtk4::PositionType::Right,
+            Placement::Start => gtk4::PositionType::Left,
+            Placement::End if rtl => gtk4::PositionType::Left,
+            Placement::End => gtk4::PositionType::Right,
+        });
+    }
+
+    fn set_open(&mut self, open: bool) {
+        self.open.set(open);
+
+        if open {
+            if self.widget.is_mapped() {
+                self.popover.popup();
+            }
+        } else {
+            self.closing.set(true);
+            self.popover.popdown();
+            self.closing.set(false);
+        }
+    }
+
+    fn set_on_dismiss(&mut self, on_dismiss: impl Fn() + 'static) {
+        let widget = self.widget.downgrade();
+        let closing = self.closing.clone();
+
+        self.popover.connect_closed(move |_| {
+            if !closing.get() && widget.upgrade().is_some_and(|w| w.is_mapped()) {
+                on_dismiss();
+            }
+        });
+    }
+}
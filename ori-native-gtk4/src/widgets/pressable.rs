@@ -43,8 +43,11 @@ impl NativePressable<Platform> for Pressable {
     fn teardown(self, _plaform: &mut Platform) {}
 
     fn set_size(&mut self, width: f32, height: f32) {
-        self.widget.set_size(width as i32, height as i32);
-        (self.widget).set_child_layout(0, 0, 0, width as i32, height as i32);
+        let width = width.round() as i32;
+        let height = height.round() as i32;
+
+        self.widget.set_size(width, height);
+        (self.widget).set_child_layout(0, 0, 0, width, height);
     }
 
     fn set_on_press(&mut self, on_press: impl Fn(Press) + 'static) {
@@ -0,0 +1,122 @@
+use std::{cell::Cell, rc::Rc};
+
+use gtk4::prelude::WidgetExt;
+use ori_native_core::{
+    LayoutLeaf, NativeWidget,
+    native::{HasProgress, HasSpinner, NativeProgress, NativeSpinner},
+};
+
+use crate::Platform;
+
+impl HasProgress for Platform {
+    type Progress = Progress;
+}
+
+pub struct Progress {
+    bar: gtk4::ProgressBar,
+}
+
+impl NativeWidget<Platform> for Progress {
+    fn widget(&self) -> &gtk4::Widget {
+        self.bar.as_ref()
+    }
+}
+
+impl NativeProgress<Platform> for Progress {
+    fn build(_platform: &mut Platform) -> Self {
+        let bar = gtk4::ProgressBar::new();
+        bar.set_valign(gtk4::Align::Center);
+
+        Self { bar }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_fraction(&mut self, fraction: f32) {
+        self.bar.set_fraction(fraction as f64);
+    }
+
+    fn get_layout(&mut self, _platform: &mut Platform) -> impl LayoutLeaf<Platform> {
+        Layout {
+            widget: self.widget().clone(),
+        }
+    }
+}
+
+impl HasSpinner for Platform {
+    type Spinner = Spinner;
+}
+
+pub struct Spinner {
+    spinner: gtk4::Spinner,
+
+    /// Whether the spinner should spin, it only spins while mapped, so it isn't animated
+    /// while a parent is hidden.
+    spinning: Rc<Cell<bool>>,
+}
+
+impl NativeWidget<Platform> for Spinner {
+    fn widget(&self) -> &gtk4::Widget {
+        self.spinner.as_ref()
+    }
+}
+
+impl NativeSpinner<Platform> for Spinner {
+    fn build(_platform: &mut Platform) -> Self {
+        let spinner = gtk4::Spinner::new();
+        let spinning = Rc::new(Cell::new(false));
+
+        spinner.connect_map({
+            let spinning = spinning.clone();
+            move |spinner| spinner.set_spinning(spinning.get())
+        });
+
+        spinner.connect_unmap(|spinner| spinner.set_spinning(false));
+
+        Self { spinner, spinning }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_spinning(&mut self, spinning: bool) {
+        self.spinning.set(spinning);
+        self.spinner
+            .set_spinning(spinning && self.spinner.is_mapped());
+    }
+
+    fn get_layout(&mut self, _platform: &mut Platform) -> impl LayoutLeaf<Platform> {
+        Layout {
+            widget: self.widget().clone(),
+        }
+    }
+}
+
+struct Layout {
+    widget: gtk4::Widget,
+}
+
+impl LayoutLeaf<Platform> for Layout {
+    fn measure(
+        &mut self,
+        _platform: &mut Platform,
+        known_size: taffy::Size<Option<f32>>,
+        _available_space: taffy::Size<taffy::AvailableSpace>,
+    ) -> taffy::Size<f32> {
+        let width = match known_size.width {
+            Some(width) => width,
+            None => self.widget.measure(gtk4::Orientation::Horizontal, -1).1 as f32,
+        };
+
+        let height = match known_size.height {
+            Some(height) => height,
+            None => {
+                let for_width = width.round() as i32;
+                self.widget
+                    .measure(gtk4::Orientation::Vertical, for_width)
+                    .1 as f32
+            }
+        };
+
+        taffy::Size { width, height }
+    }
+}
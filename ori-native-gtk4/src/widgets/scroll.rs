@@ -1,7 +1,10 @@
-use gtk4::prelude::WidgetExt;
+use std::{cell::Cell, rc::Rc};
+
+use glib::object::ObjectExt;
+use gtk4::prelude::{AdjustmentExt, WidgetExt};
 use ori_native_core::{
-    Direction, NativeParent, NativeWidget,
-    native::{HasScroll, NativeScroll},
+    NativeParent, NativeWidget, ScrollEvent,
+    native::{HasScroll, NativeScroll, ScrollbarPolicy},
 };
 
 use crate::Platform;
@@ -11,7 +14,45 @@ impl HasScroll for Platform {
 }
 
 pub struct Scroll {
-    scroll: gtk4::ScrolledWindow,
+    scroll:  gtk4::ScrolledWindow,
+    pending: Rc<Pending>,
+}
+
+/// A scroll requested before the contents could reach it, applied when the size of the
+/// contents changes.
+#[derive(Default)]
+struct Pending {
+    offset:   Cell<Option<(f64, f64)>>,
+    end:      Cell<bool>,
+    applying: Cell<bool>,
+}
+
+impl Pending {
+    fn clear(&self) {
+        self.offset.set(None);
+        self.end.set(false);
+    }
+
+    fn apply(&self, scroll: &gtk4::ScrolledWindow) {
+        let hadjustment = scroll.hadjustment();
+        let vadjustment = scroll.vadjustment();
+
+        self.applying.set(true);
+
+        if self.end.get() {
+            hadjustment.set_value(hadjustment.upper() - hadjustment.page_size());
+            vadjustment.set_value(vadjustment.upper() - vadjustment.page_size());
+        } else if let Some((x, y)) = self.offset.get() {
+            hadjustment.set_value(x);
+            vadjustment.set_value(y);
+
+            if hadjustment.value() == x && vadjustment.value() == y {
+                self.offset.set(None);
+            }
+        }
+
+        self.applying.set(false);
+    }
 }
 
 impl NativeWidget<Platform> for Scroll {
@@ -33,7 +74,33 @@ impl NativeScroll<Platform> for Scroll {
         let scroll = gtk4::ScrolledWindow::new();
         scroll.set_child(Some(contents));
 
-        Self { scroll }
+        let pending = Rc::new(Pending::default());
+
+        for adjustment in [scroll.hadjustment(), scroll.vadjustment()] {
+            adjustment.connect_changed({
+                let scroll = scroll.downgrade();
+                let pending = pending.clone();
+
+                move |_| {
+                    if let Some(scroll) = scroll.upgrade() {
+                        pending.apply(&scroll);
+                    }
+                }
+            });
+
+            adjustment.connect_value_changed({
+                let pending = pending.clone();
+
+                move |_| {
+                    // the user scrolled, which takes precedence over pending scrolls
+                    if !pending.applying.get() {
+                        pending.clear();
+                    }
+                }
+            });
+        }
+
+        Self { scroll, pending }
     }
 
     fn teardown(self, _platform: &mut Platform) {}
@@ -45,15 +112,90 @@ impl NativeScroll<Platform> for Scroll {
         );
     }
 
-    fn set_direction(&mut self, direction: Direction) {
-        self.scroll.set_hscrollbar_policy(match direction {
-            Direction::Horizontal => gtk4::PolicyType::Automatic,
-            Direction::Vertical => gtk4::PolicyType::Never,
-        });
+    fn set_scrollbars(&mut self, horizontal: ScrollbarPolicy, vertical: ScrollbarPolicy) {
+        self.scroll.set_hscrollbar_policy(policy_type(horizontal));
+        self.scroll.set_vscrollbar_policy(policy_type(vertical));
+
+        // overlay scrolling applies to both scrollbars, `Always` takes precedence
+        let policies = [horizontal, vertical];
+        let overlay = !policies.contains(&ScrollbarPolicy::Always);
+        self.scroll.set_overlay_scrolling(overlay);
+    }
+
+    fn set_kinetic(&mut self, kinetic: bool) {
+        self.scroll.set_kinetic_scrolling(kinetic);
+    }
+
+    fn set_offset(&mut self, x: f32, y: f32) {
+        self.pending.end.set(false);
+        self.pending.offset.set(Some((x as f64, y as f64)));
+        self.pending.apply(&self.scroll);
+    }
+
+    fn scroll_to_end(&mut self) {
+        self.pending.offset.set(None);
+        self.pending.end.set(true);
+        self.pending.apply(&self.scroll);
+    }
+
+    fn scroll_into_view(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        let x = into_view(&self.scroll.hadjustment(), x, width);
+        let y = into_view(&self.scroll.vadjustment(), y, height);
+
+        self.set_offset(x, y);
+    }
+
+    fn set_on_scroll(&mut self, on_scroll: impl Fn(ScrollEvent) + 'static) {
+        let on_scroll = Rc::new(on_scroll);
+
+        for adjustment in [self.scroll.hadjustment(), self.scroll.vadjustment()] {
+            adjustment.connect_value_changed({
+                let scroll = self.scroll.downgrade();
+                let on_scroll = on_scroll.clone();
+
+                move |_| {
+                    if let Some(scroll) = scroll.upgrade() {
+                        on_scroll(scroll_event(&scroll));
+                    }
+                }
+            });
+        }
+    }
+}
+
+fn policy_type(policy: ScrollbarPolicy) -> gtk4::PolicyType {
+    match policy {
+        ScrollbarPolicy::Auto | ScrollbarPolicy::Overlay => gtk4::PolicyType::Automatic,
+        ScrollbarPolicy::Always => gtk4::PolicyType::Always,
+        ScrollbarPolicy::Never => gtk4::PolicyType::Never,
+    }
+}
+
+/// Get the value of `adjustment` that makes the range from `start` with `length` visible,
+/// scrolling the least distance.
+fn into_view(adjustment: &gtk4::Adjustment, start: f32, length: f32) -> f32 {
+    let value = adjustment.value() as f32;
+    let page_size = adjustment.page_size() as f32;
+
+    if start < value || length > page_size {
+        start
+    } else if start + length > value + page_size {
+        start + length - page_size
+    } else {
+        value
+    }
+}
+
+fn scroll_event(scroll: &gtk4::ScrolledWindow) -> ScrollEvent {
+    let hadjustment = scroll.hadjustment();
+    let vadjustment = scroll.vadjustment();
 
-        self.scroll.set_vscrollbar_policy(match direction {
-            Direction::Horizontal => gtk4::PolicyType::Never,
-            Direction::Vertical => gtk4::PolicyType::Automatic,
-        });
+    ScrollEvent {
+        offset_x:        hadjustment.value() as f32,
+        offset_y:        vadjustment.value() as f32,
+        viewport_width:  hadjustment.page_size() as f32,
+        viewport_height: vadjustment.page_size() as f32,
+        content_width:   hadjustment.upper() as f32,
+        content_height:  vadjustment.upper() as f32,
     }
 }
@@ -0,0 +1,87 @@
+use std::{cell::RefCell, rc::Rc};
+
+use gtk4::prelude::WidgetExt;
+use ori_native_core::{
+    Accelerator, NativeWidget,
+    native::{HasShortcuts, NativeShortcuts, ShortcutScope},
+};
+
+use crate::{Platform, key, widgets::group::GroupWidget};
+
+impl HasShortcuts for Platform {
+    type Shortcuts = Shortcuts;
+}
+
+pub struct Shortcuts {
+    widget:      GroupWidget,
+    controller:  Option<gtk4::ShortcutController>,
+    on_shortcut: Rc<RefCell<Rc<dyn Fn(usize)>>>,
+}
+
+impl NativeWidget<Platform> for Shortcuts {
+    fn widget(&self) -> &gtk4::Widget {
+        self.widget.as_ref()
+    }
+}
+
+impl NativeShortcuts<Platform> for Shortcuts {
+    fn build(_platform: &mut Platform, contents: &gtk4::Widget) -> Self {
+        let widget = GroupWidget::new();
+        widget.insert_child(0, contents);
+
+        Self {
+            widget,
+            controller: None,
+            on_shortcut: Rc::new(RefCell::new(Rc::new(|_| {}))),
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        let width = width.round() as i32;
+        let height = height.round() as i32;
+
+        self.widget.set_size(width, height);
+        (self.widget).set_child_layout(0, 0, 0, width, height);
+    }
+
+    fn set_shortcuts(&mut self, accelerators: &[Accelerator], scope: ShortcutScope) {
+        if let Some(controller) = self.controller.take() {
+            self.widget.remove_controller(&controller);
+        }
+
+        let controller = gtk4::ShortcutController::new();
+        controller.set_scope(match scope {
+            // local shortcuts are handled as events propagate through the widget, i.e. when
+            // the focus is within it
+            ShortcutScope::Focus => gtk4::ShortcutScope::Local,
+            ShortcutScope::Window => gtk4::ShortcutScope::Global,
+        });
+
+        for (index, accelerator) in accelerators.iter().enumerate() {
+            let action = gtk4::CallbackAction::new({
+                let on_shortcut = self.on_shortcut.clone();
+
+                move |_, _| {
+                    let on_shortcut = on_shortcut.borrow().clone();
+                    on_shortcut(index);
+
+                    glib::Propagation::Stop
+                }
+            });
+
+            controller.add_shortcut(gtk4::Shortcut::new(
+                Some(key::trigger(*accelerator)),
+                Some(action),
+            ));
+        }
+
+        self.widget.add_controller(controller.clone());
+        self.controller = Some(controller);
+    }
+
+    fn set_on_shortcut(&mut self, on_shortcut: impl Fn(usize) + 'static) {
+        *self.on_shortcut.borrow_mut() = Rc::new(on_shortcut);
+    }
+}
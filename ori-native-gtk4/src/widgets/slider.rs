@@ -0,0 +1,191 @@
+use std::{cell::Cell, rc::Rc};
+
+use glib::object::ObjectExt;
+use gtk4::prelude::{AdjustmentExt, EventControllerExt, RangeExt, ScaleExt, WidgetExt};
+use ori_native_core::{
+    Direction, LayoutLeaf, NativeWidget,
+    native::{HasSlider, NativeSlider},
+    views::SliderMark,
+};
+
+use crate::Platform;
+
+impl HasSlider for Platform {
+    type Slider = Slider;
+}
+
+pub struct Slider {
+    scale:     gtk4::Scale,
+    direction: Direction,
+    step:      Rc<Cell<Option<f32>>>,
+    updating:  Rc<Cell<bool>>,
+
+    /// The value last committed, so a click without moving the slider doesn't commit.
+    committed: Rc<Cell<f32>>,
+}
+
+impl NativeWidget<Platform> for Slider {
+    fn widget(&self) -> &gtk4::Widget {
+        self.scale.as_ref()
+    }
+}
+
+impl NativeSlider<Platform> for Slider {
+    fn build(_platform: &mut Platform, direction: Direction) -> Self {
+        let orientation = match direction {
+            Direction::Horizontal => gtk4::Orientation::Horizontal,
+            Direction::Vertical => gtk4::Orientation::Vertical,
+        };
+
+        let scale = gtk4::Scale::with_range(orientation, 0.0, 1.0, 0.01);
+        scale.set_draw_value(false);
+
+        // vertical scales have the start of the range at the top by default
+        scale.set_inverted(direction == Direction::Vertical);
+
+        let step = Rc::new(Cell::new(None::<f32>));
+
+        scale.connect_change_value({
+            let step = step.clone();
+
+            move |scale, _, value| {
+                let Some(step) = step.get().filter(|step| *step > 0.0) else {
+                    return glib::Propagation::Proceed;
+                };
+
+                let adjustment = scale.adjustment();
+                let (min, max) = (adjustment.lower(), adjustment.upper());
+
+                let step = step as f64;
+                let value = min + ((value - min) / step).round() * step;
+                scale.set_value(value.clamp(min, max));
+
+                glib::Propagation::Stop
+            }
+        });
+
+        Self {
+            scale,
+            direction,
+            step,
+            updating: Rc::new(Cell::new(false)),
+            committed: Rc::new(Cell::new(0.0)),
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_range(&mut self, min: f32, max: f32) {
+        self.updating.set(true);
+        self.scale.set_range(min as f64, max.max(min) as f64);
+        self.committed.set(self.scale.value() as f32);
+        self.updating.set(false);
+
+        // the increments without a step depend on the range
+        self.set_step(self.step.get());
+    }
+
+    fn set_step(&mut self, step: Option<f32>) {
+        self.step.set(step);
+
+        let adjustment = self.scale.adjustment();
+        let length = adjustment.upper() - adjustment.lower();
+
+        match step {
+            Some(step) => self.scale.set_increments(step as f64, step as f64 * 10.0),
+            None => self.scale.set_increments(length / 100.0, length / 10.0),
+        }
+    }
+
+    fn set_value(&mut self, value: f32) {
+        self.updating.set(true);
+        self.scale.set_value(value as f64);
+        self.committed.set(self.scale.value() as f32);
+        self.updating.set(false);
+    }
+
+    fn set_marks(&mut self, marks: &[SliderMark]) {
+        let position = match self.direction {
+            Direction::Horizontal => gtk4::PositionType::Bottom,
+            Direction::Vertical => gtk4::PositionType::Right,
+        };
+
+        self.scale.clear_marks();
+
+        for mark in marks {
+            (self.scale).add_mark(
+                mark.value as f64,
+                position,
+                mark.label.as_deref(),
+            );
+        }
+    }
+
+    fn set_on_change(&mut self, on_change: impl Fn(f32) + 'static) {
+        let updating = self.updating.clone();
+
+        self.scale.connect_value_changed(move |scale| {
+            if !updating.get() {
+                on_change(scale.value() as f32);
+            }
+        });
+    }
+
+    fn set_on_commit(&mut self, on_commit: impl Fn(f32) + 'static) {
+        let controller = gtk4::EventControllerLegacy::new();
+        controller.set_propagation_phase(gtk4::PropagationPhase::Capture);
+
+        // the controller is owned by the scale, so a strong reference would keep it alive
+        let scale = self.scale.downgrade();
+        let committed = self.committed.clone();
+
+        controller.connect_event(move |_, event| {
+            if let Some(scale) = scale.upgrade()
+                && matches!(
+                    event.event_type(),
+                    gdk4::EventType::ButtonRelease
+                        | gdk4::EventType::TouchEnd
+                        | gdk4::EventType::KeyRelease
+                )
+            {
+                let value = scale.value() as f32;
+
+                if value != committed.get() {
+                    committed.set(value);
+                    on_commit(value);
+                }
+            }
+
+            glib::Propagation::Proceed
+        });
+
+        self.scale.add_controller(controller);
+    }
+
+    fn get_layout(&mut self, _platform: &mut Platform) -> impl LayoutLeaf<Platform> {
+        Layout {
+            scale: self.scale.clone(),
+        }
+    }
+}
+
+struct Layout {
+    scale: gtk4::Scale,
+}
+
+impl LayoutLeaf<Platform> for Layout {
+    fn measure(
+        &mut self,
+        _platform: &mut Platform,
+        _known_size: taffy::Size<Option<f32>>,
+        _available_space: taffy::Size<taffy::AvailableSpace>,
+    ) -> taffy::Size<f32> {
+        let (_, width, _, _) = self.scale.measure(gtk4::Orientation::Horizontal, -1);
+        let (_, height, _, _) = self.scale.measure(gtk4::Orientation::Vertical, width);
+
+        taffy::Size {
+            width:  width as f32,
+            height: height as f32,
+        }
+    }
+}
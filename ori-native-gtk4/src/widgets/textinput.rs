@@ -1,11 +1,11 @@
 use std::{cell::Cell, rc::Rc};
 
-use glib::object::ObjectExt;
-use gtk4::prelude::{TextBufferExt, TextViewExt, WidgetExt};
+use glib::object::{Cast, CastNone, ObjectExt};
+use gtk4::prelude::{EditableExt, TextBufferExt, TextViewExt, WidgetExt};
 use ori_native_core::{
     Font, LayoutLeaf, NativeWidget, Stretch,
     native::{HasTextInput, NativeTextInput},
-    views::Newline,
+    views::{InputMode, InputPurpose, Newline},
 };
 
 use crate::{Platform, platform::StyleNode};
@@ -15,73 +15,69 @@ impl HasTextInput for Platform {
 }
 
 pub struct TextInput {
-    overlay:     gtk4::Overlay,
-    view:        gtk4::TextView,
-    placeholder: gtk4::TextView,
+    kind: Kind,
 
     view_style: StyleNode,
 
     font:             Font,
     placeholder_font: Font,
     newline:          Rc<Cell<Newline>>,
+    max_length:       Rc<Cell<Option<usize>>>,
+}
+
+enum Kind {
+    MultiLine {
+        overlay:     gtk4::Overlay,
+        view:        gtk4::TextView,
+        placeholder: gtk4::TextView,
+    },
+
+    /// An entry or password entry, both of which hold their text in a [`gtk4::Text`].
+    SingleLine {
+        entry:  gtk4::Widget,
+        text:   gtk4::Text,
+        masked: bool,
+    },
 }
 
 impl NativeWidget<Platform> for TextInput {
     fn widget(&self) -> &gtk4::Widget {
-        self.overlay.as_ref()
+        match self.kind {
+            Kind::MultiLine { ref overlay, .. } => overlay.as_ref(),
+            Kind::SingleLine { ref entry, .. } => entry,
+        }
     }
 }
 
 impl NativeTextInput<Platform> for TextInput {
-    fn build(platform: &mut Platform) -> Self {
-        let overlay = gtk4::Overlay::new();
-        let view = gtk4::TextView::new();
-        let placeholder = gtk4::TextView::new();
-        placeholder.set_sensitive(false);
-        placeholder.set_visible(false);
-
-        overlay.set_child(Some(&view));
-        overlay.add_overlay(&placeholder);
-
+    fn build(platform: &mut Platform, mode: InputMode) -> Self {
         let view_style = platform.add_style("");
-        view.add_css_class(&view_style.class());
-
-        let newline = Rc::new(Cell::new(Newline::Enter));
+        let max_length = Rc::new(Cell::new(None));
 
-        let controller = gtk4::EventControllerFocus::new();
-        controller.connect_enter({
-            let placeholder = placeholder.clone();
+        let kind = match mode {
+            InputMode::MultiLine => build_multi_line(&view_style, &max_length),
 
-            move |_| {
-                placeholder.set_visible(false);
+            InputMode::SingleLine => {
+                let entry = gtk4::Entry::new();
+                build_single_line(entry.upcast(), &view_style, false)
             }
-        });
-
-        controller.connect_leave({
-            let view = view.downgrade();
-            let placeholder = placeholder.clone();
 
-            move |_| {
-                if let Some(view) = view.upgrade()
-                    && view.buffer().start_iter() == view.buffer().end_iter()
-                {
-                    placeholder.set_visible(true);
-                }
+            InputMode::Password => {
+                let entry = gtk4::PasswordEntry::new();
+                entry.set_show_peek_icon(true);
+                build_single_line(entry.upcast(), &view_style, true)
             }
-        });
-
-        view.add_controller(controller);
+        };
 
         Self {
-            overlay,
-            view,
-            placeholder,
+            kind,
 
             view_style,
 
             font: Default::default(),
             placeholder_font: Default::default(),
-            newline,
+            newline: Rc::new(Cell::new(Newline::Enter)),
+            max_length,
         }
     }
 
@@ -90,25 +86,52 @@ impl NativeTextInput<Platform> for TextInput {
     }
 
     fn set_on_change(&mut self, _platform: &mut Platform, on_changed: impl Fn(String) + 'static) {
-        self.view.buffer().connect_text_notify({
-            move |buffer| {
-                let text = buffer.text(
-                    &buffer.start_iter(),
-                    &buffer.end_iter(),
-                    true,
-                );
-
-                on_changed(text.into());
+        match self.kind {
+            Kind::MultiLine { ref view, .. } => {
+                view.buffer().connect_text_notify({
+                    move |buffer| {
+                        let text = buffer.text(
+                            &buffer.start_iter(),
+                            &buffer.end_iter(),
+                            true,
+                        );
+
+                        on_changed(text.into());
+                    }
+                });
             }
-        });
+
+            Kind::SingleLine { ref text, .. } => {
+                text.connect_changed(move |text| {
+                    on_changed(text.text().into());
+                });
+            }
+        }
     }
 
     fn set_on_submit(&mut self, _platform: &mut Platform, on_submit: impl Fn(String) + 'static) {
+        let view = match self.kind {
+            Kind::MultiLine { ref view, .. } => view,
+
+            // a single line has no newlines to insert, so enter always submits
+            Kind::SingleLine { ref text, .. } => {
+                text.connect_local("activate", false, move |values| {
+                    if let Ok(text) = values[0].get::<gtk4::Text>() {
+                        on_submit(text.text().into());
+                    }
+
+                    None
+                });
+
+                return;
+            }
+        };
+
         let controller = gtk4::EventControllerKey::new();
 
         controller.connect_key_pressed({
             let enter = self.newline.clone();
-            let buffer = self.view.buffer();
+            let buffer = view.buffer();
 
             move |_, key, _, state| {
                 let shift = state.contains(gdk4::ModifierType::SHIFT_MASK);
@@ -135,7 +158,7 @@ impl NativeTextInput<Platform> for TextInput {
             }
         });
 
-        self.view.add_controller(controller);
+        view.add_controller(controller);
     }
 
     fn set_newline(&mut self, _platform: &mut Platform, newline: Newline) {
@@ -143,7 +166,49 @@ impl NativeTextInput<Platform> for TextInput {
     }
 
     fn set_accept_tab(&mut self, _platform: &mut Platform, accept_tab: bool) {
-        self.view.set_accepts_tab(accept_tab);
+        if let Kind::MultiLine { ref view, .. } = self.kind {
+            view.set_accepts_tab(accept_tab);
+        }
+    }
+
+    fn set_max_length(&mut self, _platform: &mut Platform, max_length: Option<usize>) {
+        self.max_length.set(max_length);
+
+        if let Kind::SingleLine { ref text, .. } = self.kind {
+            // a max length of 0 means no limit
+            let max_length = max_length.map_or(0, |max| {
+                max.min(i32::MAX as usize) as i32
+            });
+            text.set_max_length(max_length);
+        }
+    }
+
+    fn set_purpose(&mut self, _platform: &mut Platform, purpose: InputPurpose) {
+        let purpose = match purpose {
+            InputPurpose::Text => gtk4::InputPurpose::FreeForm,
+            InputPurpose::Email => gtk4::InputPurpose::Email,
+            InputPurpose::Number => gtk4::InputPurpose::Number,
+            InputPurpose::Url => gtk4::InputPurpose::Url,
+        };
+
+        match self.kind {
+            Kind::MultiLine { ref view, .. } => view.set_input_purpose(purpose),
+
+            // a password entry is always hinted as a password
+            Kind::SingleLine { masked: true, .. } => {}
+            Kind::SingleLine { ref text, .. } => text.set_input_purpose(purpose),
+        }
+    }
+
+    fn set_editable(&mut self, _platform: &mut Platform, editable: bool) {
+        match self.kind {
+            Kind::MultiLine { ref view, .. } => {
+                view.set_editable(editable);
+                view.set_cursor_visible(editable);
+            }
+
+            Kind::SingleLine { ref text, .. } => text.set_editable(editable),
+        }
     }
 
     fn set_font(&mut self, platform: &mut Platform, font: Font) {
@@ -152,30 +217,54 @@ impl NativeTextInput<Platform> for TextInput {
     }
 
     fn set_placeholder_font(&mut self, _platform: &mut Platform, font: Font) {
-        self.placeholder.set_visible(true);
-
-        let buffer = self.placeholder.buffer();
-        let tag_table = buffer.tag_table();
-        let tag = super::text::font_tag(&font);
-
-        tag_table.foreach(|tag| tag_table.remove(tag));
-        tag_table.add(&tag);
-
-        buffer.apply_tag(
-            &tag,
-            &buffer.start_iter(),
-            &buffer.end_iter(),
-        );
+        // the placeholder of an entry is styled by the theme
+        if let Kind::MultiLine {
+            ref placeholder, ..
+        } = self.kind
+        {
+            placeholder.set_visible(true);
+
+            let buffer = placeholder.buffer();
+            let tag_table = buffer.tag_table();
+            let tag = super::text::font_tag(&font);
+
+            tag_table.foreach(|tag| tag_table.remove(tag));
+            tag_table.add(&tag);
+
+            buffer.apply_tag(
+                &tag,
+                &buffer.start_iter(),
+                &buffer.end_iter(),
+            );
+        }
 
         self.placeholder_font = font;
     }
 
     fn set_text(&mut self, _platform: &mut Platform, text: String) {
-        self.view.buffer().set_text(&text);
+        match self.kind {
+            Kind::MultiLine { ref view, .. } => view.buffer().set_text(&text),
+            Kind::SingleLine {
+                text: ref entry, ..
+            } => entry.set_text(&text),
+        }
     }
 
     fn set_placeholder_text(&mut self, _platform: &mut Platform, text: String) {
-        let buffer = self.placeholder.buffer();
+        let placeholder = match self.kind {
+            Kind::MultiLine {
+                ref placeholder, ..
+            } => placeholder,
+
+            Kind::SingleLine {
+                text: ref entry, ..
+            } => {
+                entry.set_placeholder_text(Some(text.as_str()));
+                return;
+            }
+        };
+
+        let buffer = placeholder.buffer();
         let tag_table = buffer.tag_table();
         let tag = super::text::font_tag(&self.placeholder_font);
 
@@ -191,18 +280,117 @@ impl NativeTextInput<Platform> for TextInput {
     }
 
     fn get_layout(&mut self, _platform: &mut Platform) -> impl LayoutLeaf<Platform> {
-        Layout {
-            view:             self.view.clone(),
-            font:             self.font.clone(),
-            placeholder_font: self.placeholder_font.clone(),
+        match self.kind {
+            Kind::MultiLine { ref view, .. } => Layout::MultiLine {
+                view:             view.clone(),
+                font:             self.font.clone(),
+                placeholder_font: self.placeholder_font.clone(),
+            },
+
+            Kind::SingleLine { ref entry, .. } => Layout::SingleLine {
+                entry: entry.clone(),
+            },
         }
     }
 }
 
-struct Layout {
-    view:             gtk4::TextView,
-    font:             Font,
-    placeholder_font: Font,
+fn build_multi_line(view_style: &StyleNode, max_length: &Rc<Cell<Option<usize>>>) -> Kind {
+    let overlay = gtk4::Overlay::new();
+    let view = gtk4::TextView::new();
+    let placeholder = gtk4::TextView::new();
+    placeholder.set_sensitive(false);
+    placeholder.set_visible(false);
+
+    overlay.set_child(Some(&view));
+    overlay.add_overlay(&placeholder);
+
+    view.add_css_class(&view_style.class());
+
+    let controller = gtk4::EventControllerFocus::new();
+    controller.connect_enter({
+        let placeholder = placeholder.clone();
+
+        move |_| {
+            placeholder.set_visible(false);
+        }
+    });
+
+    controller.connect_leave({
+        let view = view.downgrade();
+        let placeholder = placeholder.clone();
+
+        move |_| {
+            if let Some(view) = view.upgrade()
+                && view.buffer().start_iter() == view.buffer().end_iter()
+            {
+                placeholder.set_visible(true);
+            }
+        }
+    });
+
+    view.add_controller(controller);
+
+    // text views have no max length, so insertions past it are cut short
+    view.buffer().connect_insert_text({
+        let max_length = max_length.clone();
+
+        move |buffer, iter, text| {
+            let Some(max_length) = max_length.get() else {
+                return;
+            };
+
+            let available = max_length.saturating_sub(buffer.char_count() as usize);
+
+            if text.chars().count() <= available {
+                return;
+            }
+
+            buffer.stop_signal_emission_by_name("insert-text");
+
+            let end = text
+                .char_indices()
+                .nth(available)
+                .map_or(text.len(), |(i, _)| i);
+
+            if end > 0 {
+                buffer.insert(iter, &text[..end]);
+            }
+        }
+    });
+
+    Kind::MultiLine {
+        overlay,
+        view,
+        placeholder,
+    }
+}
+
+fn build_single_line(entry: gtk4::Widget, view_style: &StyleNode, masked: bool) -> Kind {
+    entry.add_css_class(&view_style.class());
+
+    let text = entry
+        .dynamic_cast_ref::<gtk4::Editable>()
+        .and_then(|editable| editable.delegate())
+        .and_downcast::<gtk4::Text>()
+        .expect("entries hold their text in a text widget");
+
+    Kind::SingleLine {
+        entry,
+        text,
+        masked,
+    }
+}
+
+enum Layout {
+    MultiLine {
+        view:             gtk4::TextView,
+        font:             Font,
+        placeholder_font: Font,
+    },
+
+    SingleLine {
+        entry: gtk4::Widget,
+    },
 }
 
 impl LayoutLeaf<Platform> for Layout {
@@ -212,13 +400,30 @@ impl LayoutLeaf<Platform> for Layout {
         _known_size: taffy::Size<Option<f32>>,
         _available_space: taffy::Size<taffy::AvailableSpace>,
     ) -> taffy::Size<f32> {
-        let context = self.view.pango_context();
+        let (view, font, placeholder_font) = match self {
+            Layout::MultiLine {
+                view,
+                font,
+                placeholder_font,
+            } => (view, font, placeholder_font),
+
+            Layout::SingleLine { entry } => {
+                let (_, height, _, _) = entry.measure(gtk4::Orientation::Vertical, -1);
+
+                return taffy::Size {
+                    width:  0.0,
+                    height: height as f32,
+                };
+            }
+        };
+
+        let context = view.pango_context();
 
-        let desc = super::text::font_description(&self.font);
+        let desc = super::text::font_description(font);
         let metrics = context.metrics(Some(&desc), context.language().as_ref());
         let theight = (metrics.ascent() + metrics.descent()) as f32 / pango::SCALE as f32;
 
-        let desc = super::text::font_description(&self.placeholder_font);
+        let desc = super::text::font_description(placeholder_font);
         let metrics = context.metrics(Some(&desc), context.language().as_ref());
         let pheight = (metrics.ascent() + metrics.descent()) as f32 / pango::SCALE as f32;
 
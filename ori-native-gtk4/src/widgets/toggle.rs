@@ -0,0 +1,166 @@
+use std::{cell::Cell, rc::Rc};
+
+use glib::object::Cast;
+use gtk4::prelude::{AccessibleExtManual, BoxExt, CheckButtonExt, WidgetExt};
+use ori_native_core::{
+    LayoutLeaf, NativeWidget,
+    native::{HasToggle, NativeToggle, ToggleKind},
+    views::CheckState,
+};
+
+use crate::Platform;
+
+impl HasToggle for Platform {
+    type Toggle = Toggle;
+}
+
+pub struct Toggle {
+    kind:     Kind,
+    updating: Rc<Cell<bool>>,
+}
+
+enum Kind {
+    Check(gtk4::CheckButton),
+
+    /// A switch doesn't have a label of its own, so it's placed in a box with one.
+    Switch {
+        container: gtk4::Box,
+        switch:    gtk4::Switch,
+        label:     gtk4::Label,
+    },
+}
+
+impl NativeWidget<Platform> for Toggle {
+    fn widget(&self) -> &gtk4::Widget {
+        match self.kind {
+            Kind::Check(ref check) => check.as_ref(),
+            Kind::Switch { ref container, .. } => container.as_ref(),
+        }
+    }
+}
+
+impl NativeToggle<Platform> for Toggle {
+    fn build(_platform: &mut Platform, kind: ToggleKind) -> Self {
+        let kind = match kind {
+            ToggleKind::Checkbox | ToggleKind::Radio => Kind::Check(gtk4::CheckButton::new()),
+
+            ToggleKind::Switch => {
+                let container = gtk4::Box::new(gtk4::Orientation::Horizontal, 12);
+                let switch = gtk4::Switch::new();
+                let label = gtk4::Label::new(None);
+
+                switch.set_valign(gtk4::Align::Center);
+                label.set_visible(false);
+
+                switch.update_relation(
+                    &[gtk4::accessible::Relation::LabelledBy(
+                        &[label.upcast_ref()],
+                    )],
+                );
+
+                container.append(&switch);
+                container.append(&label);
+
+                Kind::Switch {
+                    container,
+                    switch,
+                    label,
+                }
+            }
+        };
+
+        Self {
+            kind,
+            updating: Rc::new(Cell::new(false)),
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_group(&mut self, leader: &Self) {
+        if let (Kind::Check(check), Kind::Check(leader)) = (&self.kind, &leader.kind) {
+            check.set_group(Some(leader));
+        }
+    }
+
+    fn set_checked(&mut self, checked: CheckState) {
+        self.updating.set(true);
+
+        match self.kind {
+            Kind::Check(ref check) => {
+                check.set_active(checked.is_checked());
+                check.set_inconsistent(checked == CheckState::Mixed);
+            }
+
+            Kind::Switch { ref switch, .. } => {
+                switch.set_active(checked.is_checked());
+            }
+        }
+
+        self.updating.set(false);
+    }
+
+    fn set_label(&mut self, text: String) {
+        match self.kind {
+            Kind::Check(ref check) => {
+                check.set_label((!text.is_empty()).then_some(text.as_str()));
+            }
+
+            Kind::Switch { ref label, .. } => {
+                label.set_visible(!text.is_empty());
+                label.set_label(&text);
+            }
+        }
+    }
+
+    fn set_on_toggle(&mut self, on_toggle: impl Fn(bool) + 'static) {
+        let updating = self.updating.clone();
+
+        match self.kind {
+            Kind::Check(ref check) => {
+                check.connect_toggled(move |check| {
+                    if !updating.get() {
+                        // toggling settles a mixed state
+                        check.set_inconsistent(false);
+                        on_toggle(check.is_active());
+                    }
+                });
+            }
+
+            Kind::Switch { ref switch, .. } => {
+                switch.connect_active_notify(move |switch| {
+                    if !updating.get() {
+                        on_toggle(switch.is_active());
+                    }
+                });
+            }
+        }
+    }
+
+    fn get_layout(&mut self, _platform: &mut Platform) -> impl LayoutLeaf<Platform> {
+        Layout {
+            widget: self.widget().clone(),
+        }
+    }
+}
+
+struct Layout {
+    widget: gtk4::Widget,
+}
+
+impl LayoutLeaf<Platform> for Layout {
+    fn measure(
+        &mut self,
+        _platform: &mut Platform,
+        _known_size: taffy::Size<Option<f32>>,
+        _available_space: taffy::Size<taffy::AvailableSpace>,
+    ) -> taffy::Size<f32> {
+        let (_, width, _, _) = self.widget.measure(gtk4::Orientation::Horizontal, -1);
+        let (_, height, _, _) = self.widget.measure(gtk4::Orientation::Vertical, width);
+
+        taffy::Size {
+            width:  width as f32,
+            height: height as f32,
+        }
+    }
+}
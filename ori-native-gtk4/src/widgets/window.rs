@@ -1,43 +1,62 @@
-use std::time::Duration;
+use std::{io, path::Path, rc::Rc, time::Duration};
 
-use glib::subclass::types::ObjectSubclassIsExt;
-use gtk4::prelude::{GtkWindowExt, WidgetExt};
+use gdk4::prelude::{PaintableExt, TextureExt, TextureExtManual, ToplevelExt};
+use glib::{
+    object::{Cast, IsA},
+    subclass::types::ObjectSubclassIsExt,
+};
+use gsk4::prelude::GskRendererExt;
+use gtk4::prelude::{
+    ApplicationWindowExt, BoxExt, GtkApplicationExt, GtkWindowExt, NativeExt, WidgetExt,
+};
 use ori_native_core::{
-    NativeParent,
-    native::{HasWindow, NativeWindow},
+    MenuEntry, MenuModel, NativeParent,
+    native::{HasDialog, HasWindow, NativeDialog, NativeWindow, Screenshot},
 };
 
-use crate::Platform;
+use crate::{Platform, menu};
 
 impl HasWindow for Platform {
     type Window = Window;
 }
 
+impl HasDialog for Platform {
+    type Dialog = Window;
+}
+
 impl NativeParent<Platform> for Window {
     fn replace_child(&mut self, _platform: &mut Platform, index: usize, child: &gtk4::Widget) {
         debug_assert_eq!(index, 0);
 
-        self.set_child(Some(child));
+        self.set_contents(child);
     }
 }
 
 impl NativeWindow<Platform> for Window {
     fn build(platform: &mut Platform, contents: &gtk4::Widget) -> Self {
         let window = Self::new(&platform.application);
-        window.set_child(Some(contents));
+        window.set_contents(contents);
         window.show();
 
         window
     }
 
     fn teardown(self, _platform: &mut Platform) {
+        self.clear_accels();
+        self.destroy();
+    }
+
+    fn close(&mut self, _platform: &mut Platform) {
+        // destroying the window removes it from the application, so it no longer counts as
+        // open, destroying it again when torn down does nothing
+        self.clear_accels();
         self.destroy();
     }
 
     fn get_size(&self) -> (u32, u32) {
         (
             self.width() as u32,
-            self.height() as u32,
+            (self.height() - self.menubar_height()).max(0) as u32,
         )
     }
 
@@ -56,7 +75,7 @@ impl NativeWindow<Platform> for Window {
             }
 
             if self.is_anchor(Edge::Top) && self.is_anchor(Edge::Bottom) {
-                min_height = Some(self.height() as u32);
+                min_height = Some((self.height() - self.menubar_height()).max(0) as u32);
             }
         }
 
@@ -92,6 +111,16 @@ impl NativeWindow<Platform> for Window {
         self.set_on_size_allocate(on_resize);
     }
 
+    fn set_on_state_changed(&mut self, on_state_changed: impl Fn() + 'static) {
+        if let Some(toplevel) = self.toplevel() {
+            toplevel.connect_state_notify(move |_| on_state_changed());
+        }
+    }
+
+    fn set_on_menu_select(&mut self, on_select: impl Fn(usize) + 'static) {
+        *self.imp().on_menu_select.borrow_mut() = Rc::new(on_select);
+    }
+
     fn set_min_size(&mut self, width: u32, height: u32) {
         #[cfg(feature = "layer-shell")]
         {
@@ -102,13 +131,16 @@ impl NativeWindow<Platform> for Window {
             }
         }
 
-        self.set_size_request(width as i32, height as i32);
+        self.set_size_request(
+            width as i32,
+            height as i32 + self.menubar_height(),
+        );
     }
 
     fn set_size(&mut self, width: u32, height: u32) {
         self.set_default_size(
             width.max(1) as i32,
-            height.max(1) as i32,
+            height.max(1) as i32 + self.menubar_height(),
         );
 
         #[cfg(feature = "layer-shell")]
@@ -132,6 +164,116 @@ impl NativeWindow<Platform> for Window {
         gtk4::Window::set_resizable(self.as_ref(), resizable);
     }
 
+    fn set_visible(&mut self, visible: bool) {
+        WidgetExt::set_visible(self, visible);
+    }
+
+    fn set_name(&mut self, name: Option<&str>) {
+        self.set_widget_name(name.unwrap_or_default());
+    }
+
+    fn set_title(&mut self, title: &str) {
+        GtkWindowExt::set_title(self, Some(title));
+    }
+
+    fn set_icon_name(&mut self, icon_name: Option<&str>) {
+        GtkWindowExt::set_icon_name(self, icon_name);
+    }
+
+    fn set_decorated(&mut self, decorated: bool) {
+        GtkWindowExt::set_decorated(self, decorated);
+    }
+
+    fn set_menubar(&mut self, model: Option<&MenuModel>) {
+        self.clear_accels();
+
+        let Some(model) = model else {
+            self.insert_action_group(
+                &self.menubar_prefix(),
+                None::<&gio::ActionGroup>,
+            );
+            self.imp().menubar.replace(None);
+            self.update_child();
+            return;
+        };
+
+        let on_select: Rc<dyn Fn(usize)> = Rc::new({
+            let on_select = self.imp().on_menu_select.clone();
+
+            move |id| {
+                let on_select = on_select.borrow().clone();
+                on_select(id);
+            }
+        });
+
+        // the prefix is unique to the window, as accelerators are registered with the
+        // application by action name
+        let prefix = self.menubar_prefix();
+
+        let actions = gio::SimpleActionGroup::new();
+        let menu = menu::build_menu(model, &prefix, &actions, &on_select);
+        self.insert_action_group(&prefix, Some(&actions));
+
+        if let Some(application) = GtkWindowExt::application(self) {
+            let mut accels = self.imp().accels.borrow_mut();
+
+            for (id, accelerator) in menu_accelerators(model) {
+                let Some(accel) = menu::accelerator(accelerator) else {
+                    continue;
+                };
+
+                let action = format!("{prefix}.item-{id}");
+                application.set_accels_for_action(&action, &[accel.as_str()]);
+                accels.push(action);
+            }
+        }
+
+        let menubar = self.imp().menubar.borrow().clone();
+
+        match menubar {
+            Some(menubar) => menubar.set_menu_model(Some(&menu)),
+            None => {
+                let menubar = gtk4::PopoverMenuBar::from_model(Some(&menu));
+                self.imp().menubar.replace(Some(menubar));
+                self.update_child();
+            }
+        }
+    }
+
+    fn is_maximized(&self) -> bool {
+        GtkWindowExt::is_maximized(self)
+    }
+
+    fn is_fullscreen(&self) -> bool {
+        GtkWindowExt::is_fullscreen(self)
+    }
+
+    fn is_minimized(&self) -> bool {
+        self.toplevel()
+            .is_some_and(|toplevel| toplevel.state().contains(gdk4::ToplevelState::MINIMIZED))
+    }
+
+    fn set_maximized(&mut self, maximized: bool) {
+        match maximized {
+            true => self.maximize(),
+            false => self.unmaximize(),
+        }
+    }
+
+    fn set_fullscreen(&mut self, fullscreen: bool) {
+        match fullscreen {
+            true => GtkWindowExt::fullscreen(self),
+            false => self.unfullscreen(),
+        }
+    }
+
+    fn set_minimized(&mut self, minimized: bool) {
+        match minimized {
+            true => self.minimize(),
+            false => self.unminimize(),
+        }
+    }
+
     fn start_animating(&mut self) {
         if let Some(frame_clock) = self.frame_clock() {
             frame_clock.begin_updating();
@@ -144,6 +286,119 @@ impl NativeWindow<Platform> for Window {
             frame_clock.end_updating();
         }
     }
+
+    fn screenshot(&self) -> Option<Screenshot> {
+        let texture = self.render_texture()?;
+
+        let width = texture.width() as usize;
+        let height = texture.height() as usize;
+
+        let mut pixels = vec![0; width * height * 4];
+        texture.download(&mut pixels, width * 4);
+
+        // textures are downloaded as premultiplied native endian ARGB, so convert to RGBA
+        for pixel in pixels.chunks_exact_mut(4) {
+            let argb = u32::from_ne_bytes([pixel[0], pixel[1], pixel[2], pixel[3]]);
+
+            let a = (argb >> 24) as u8;
+            let r = (argb >> 16) as u8;
+            let g = (argb >> 8) as u8;
+            let b = argb as u8;
+
+            pixel.copy_from_slice(&[
+                unpremultiply(r, a),
+                unpremultiply(g, a),
+                unpremultiply(b, a),
+                a,
+            ]);
+        }
+
+        Some(Screenshot {
+            width: width as u32,
+            height: height as u32,
+            pixels,
+        })
+    }
+}
+
+impl NativeDialog<Platform> for Window {
+    fn build(
+        platform: &mut Platform,
+        contents: &gtk4::Widget,
+        owner: Option<&str>,
+        modal: bool,
+    ) -> Self {
+        let parent = find_owner(platform, owner, None);
+
+        let window = Self::new(&platform.application);
+        window.set_transient_for(parent.as_ref());
+        window.set_destroy_with_parent(true);
+        GtkWindowExt::set_modal(&window, modal);
+        window.set_contents(contents);
+        window.show();
+
+        window
+    }
+
+    fn set_owner(&mut self, platform: &mut Platform, owner: Option<&str>) {
+        let parent = find_owner(platform, owner, Some(self));
+        self.set_transient_for(parent.as_ref());
+    }
+
+    fn set_modal(&mut self, modal: bool) {
+        GtkWindowExt::set_modal(self, modal);
+    }
+}
+
+/// Find the window named `owner`, or the active window, other than `dialog`.
+fn find_owner(
+    platform: &Platform,
+    owner: Option<&str>,
+    dialog: Option<&Window>,
+) -> Option<gtk4::Window> {
+    let application = &platform.application;
+    let is_dialog = |window: &gtk4::Window| {
+        dialog.is_some_and(|dialog| window == dialog.upcast_ref::<gtk4::Window>())
+    };
+
+    let named = owner.and_then(|owner| {
+        (application.windows().into_iter())
+            .find(|window| !is_dialog(window) && window.widget_name().as_str() == owner)
+    });
+
+    named.or_else(|| {
+        application
+            .active_window()
+            .filter(|window| !is_dialog(window))
+    })
+}
+
+/// Get the items of `model` that have an accelerator, including those of submenus.
+fn menu_accelerators(model: &MenuModel) -> Vec<(usize, &str)> {
+    let mut accelerators = Vec::new();
+
+    for entry in &model.entries {
+        match entry {
+            MenuEntry::Item {
+                id,
+                accelerator: Some(accelerator),
+                ..
+            } => accelerators.push((*id, accelerator.as_str())),
+
+            MenuEntry::Submenu { menu, .. } => accelerators.extend(menu_accelerators(menu)),
+
+            _ => {}
+        }
+    }
+
+    accelerators
+}
+
+fn unpremultiply(channel: u8, alpha: u8) -> u8 {
+    match alpha {
+        0 => 0,
+        _ => (channel as u32 * 255 / alpha as u32).min(255) as u8,
+    }
 }
 
 gtk4::glib::wrapper! {
@@ -177,6 +432,107 @@ impl Window {
             .on_size_allocate
             .replace(Box::new(on_size_allocate));
     }
+
+    /// Set the contents of the window, placing them below the menubar if there is one.
+    pub fn set_contents(&self, contents: &impl IsA<gtk4::Widget>) {
+        self.imp().contents.replace(Some(contents.clone().upcast()));
+        self.update_child();
+    }
+
+    fn update_child(&self) {
+        let contents = self.imp().contents.borrow().clone();
+        let menubar = self.imp().menubar.borrow().clone();
+
+        // detach the contents and the menubar from their current parents
+        self.set_child(None::<&gtk4::Widget>);
+
+        let children = [contents.clone(), menubar.clone().map(Cast::upcast)];
+
+        for child in children.iter().flatten() {
+            if let Some(parent) = child.parent()
+                && let Ok(parent) = parent.downcast::<gtk4::Box>()
+            {
+                parent.remove(child);
+            }
+        }
+
+        match (contents, menubar) {
+            (Some(contents), Some(menubar)) => {
+                let vbox = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+                vbox.append(&menubar);
+                vbox.append(&contents);
+                self.set_child(Some(&vbox));
+            }
+
+            (Some(contents), None) => self.set_child(Some(&contents)),
+            (None, Some(menubar)) => self.set_child(Some(&menubar)),
+            (None, None) => {}
+        }
+    }
+
+    fn menubar_prefix(&self) -> String {
+        format!("menubar-{}", self.id())
+    }
+
+    fn menubar_height(&self) -> i32 {
+        match *self.imp().menubar.borrow() {
+            Some(ref menubar) => menubar.measure(gtk4::Orientation::Vertical, -1).1,
+            None => 0,
+        }
+    }
+
+    /// Remove the accelerators of the menubar from the application.
+    fn clear_accels(&self) {
+        let accels = self.imp().accels.take();
+
+        if let Some(application) = GtkWindowExt::application(self) {
+            for action in accels {
+                application.set_accels_for_action(&action, &[]);
+            }
+        }
+    }
+
+    /// Get the toplevel surface of the window, this is only available once it's realized.
+    fn toplevel(&self) -> Option<gdk4::Toplevel> {
+        self.surface()?.downcast().ok()
+    }
+
+    /// Render the contents of the window to a texture, the menubar isn't included.
+    ///
+    /// This uses the cairo renderer, and therefore works on machines without a GPU.
+    pub fn render_texture(&self) -> Option<gdk4::Texture> {
+        let contents = self.imp().contents.borrow().clone()?;
+        let width = contents.width();
+        let height = contents.height();
+
+        if width <= 0 || height <= 0 {
+            return None;
+        }
+
+        let snapshot = gtk4::Snapshot::new();
+        let paintable = gtk4::WidgetPaintable::new(Some(&contents));
+        paintable.snapshot(&snapshot, width as f64, height as f64);
+
+        let node = snapshot.to_node()?;
+
+        let renderer = gsk4::CairoRenderer::new();
+        renderer.realize(None::<&gdk4::Surface>).ok()?;
+
+        let bounds = graphene::Rect::new(0.0, 0.0, width as f32, height as f32);
+        let texture = renderer.render_texture(node, Some(&bounds));
+        renderer.unrealize();
+
+        Some(texture)
+    }
+
+    /// Render the contents of the window and save them as a PNG file at `path`.
+    pub fn save_png(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let texture = self
+            .render_texture()
+            .ok_or_else(|| io::Error::other("window has nothing to render"))?;
+
+        texture.save_to_png(path).map_err(io::Error::other)
+    }
 }
 
 mod imp {
@@ -192,9 +548,14 @@ mod imp {
         window::WindowImpl,
     };
 
+    #[allow(clippy::type_complexity)]
     pub struct ApplicationWindow {
         pub on_size_allocate: RefCell<Box<dyn Fn()>>,
         pub previous_frame:   Rc<Cell<Option<i64>>>,
+        pub contents:         RefCell<Option<gtk4::Widget>>,
+        pub menubar:          RefCell<Option<gtk4::PopoverMenuBar>>,
+        pub accels:           RefCell<Vec<String>>,
+        pub on_menu_select:   Rc<RefCell<Rc<dyn Fn(usize)>>>,
     }
 
     impl Default for ApplicationWindow {
@@ -202,6 +563,10 @@ mod imp {
             Self {
                 on_size_allocate: RefCell::new(Box::new(|| {})),
                 previous_frame:   Rc::new(Cell::new(None)),
+                contents:         RefCell::new(None),
+                menubar:          RefCell::new(None),
+                accels:           RefCell::new(Vec::new()),
+                on_menu_select:   Rc::new(RefCell::new(Rc::new(|_| {}))),
             }
         }
     }
@@ -0,0 +1,889 @@
+use std::{any::Any, rc::Rc, time::Duration};
+
+use ori::{Effect, Proxied};
+use ori_native_core::{
+    Accelerator, Context, DragEvent, DragPhase, ExternalDrop, Key, KeyEvent, MenuEntry, MenuModel,
+    PointerEvent, Propagation, QuitPolicy,
+    native::{Press, ScrollbarPolicy, ShortcutScope},
+    views::{CheckState, InputMode},
+};
+
+use crate::{
+    Event, Platform, Widget, WidgetKind,
+    widgets::{
+        ContextMenuState, DragGestureState, DragSourceState, DropTargetState, DropdownState,
+        FileDropState, KeyHandlerState, PointerHandlerState, PopoverState, PressableState,
+        ScrollState, ShortcutsState, SliderState, TextInputState, ToggleState, WindowState,
+        truncate,
+    },
+};
+
+/// Drives an [`Effect`] on the headless [`Platform`], the same way an application would.
+///
+/// Input is simulated by invoking the callbacks registered on the native widgets, after
+/// which the resulting messages are handled and the view is rebuilt, just like the event
+/// loop of a real application.
+pub struct Harness<T, V, B>
+where
+    V: Effect<Context<Platform>, T>,
+{
+    data:    T,
+    build:   B,
+    state:   Option<V::State>,
+    context: Context<Platform>,
+    running: bool,
+
+    quit_policy: QuitPolicy,
+}
+
+impl<T, V, B> Harness<T, V, B>
+where
+    V: Effect<Context<Platform>, T>,
+    B: FnMut(&T) -> V,
+{
+    /// Build `ui` with `data`, and handle all events sent while building.
+    pub fn new(data: T, build: B) -> Self {
+        Self::with_quit_policy(data, build, QuitPolicy::default())
+    }
+
+    /// Like [`Harness::new`], but with a custom [`QuitPolicy`].
+    pub fn with_quit_policy(mut data: T, mut build: B, quit_policy: QuitPolicy) -> Self {
+        let mut context = Context::new(Platform::new());
+
+        let view = build(&data);
+        let (_, state) = view.build(&mut context, &mut data);
+
+        let mut harness = Self {
+            data,
+            build,
+            state: Some(state),
+            context,
+            running: true,
+            quit_policy,
+        };
+
+        harness.run();
+        harness
+    }
+
+    pub fn data(&self) -> &T {
+        &self.data
+    }
+
+    /// Get mutable access to the data, call [`Harness::rebuild`] to apply changes to the view.
+    pub fn data_mut(&mut self) -> &mut T {
+        &mut self.data
+    }
+
+    pub fn context(&self) -> &Context<Platform> {
+        &self.context
+    }
+
+    pub fn context_mut(&mut self) -> &mut Context<Platform> {
+        &mut self.context
+    }
+
+    /// Get the windows that are currently open, including hidden ones.
+    pub fn windows(&self) -> &[Widget] {
+        self.context.platform.windows()
+    }
+
+    /// Get the window at `index`, panicking if it doesn't exist.
+    #[track_caller]
+    pub fn window(&self, index: usize) -> Widget {
+        self.windows()
+            .get(index)
+            .cloned()
+            .expect("window should exist")
+    }
+
+    /// Whether the application is still running, that is, [`Platform::quit`] hasn't been
+    /// called, and the [`QuitPolicy`] hasn't been met.
+    ///
+    /// [`Platform::quit`]: ori_native_core::Platform::quit
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    /// Rebuild the view with the current data, and handle all resulting events.
+    pub fn rebuild(&mut self) {
+        self.rebuild_view();
+        self.run();
+    }
+
+    /// Handle all pending events, waiting for spawned futures to complete, and handling the
+    /// events they send.
+    ///
+    /// A future that never completes blocks this forever.
+    pub fn run(&mut self) {
+        loop {
+            while let Some(event) = self.context.platform.next_event() {
+                self.handle_event(event);
+            }
+
+            if !self.context.platform.wait_for_tasks() {
+                break;
+            }
+        }
+
+        match self.quit_policy {
+            QuitPolicy::LastWindowClosed => {
+                if self.windows().is_empty() {
+                    self.running = false;
+                }
+            }
+
+            QuitPolicy::Never => {}
+        }
+    }
+
+    /// Press and release `widget`, or the closest pressable ancestor of it.
+    #[track_caller]
+    pub fn press(&mut self, widget: &Widget) {
+        let on_press = find_handler(widget, |pressable: &PressableState| {
+            pressable.on_press.clone()
+        });
+
+        on_press(Press::Pressed);
+        self.run();
+
+        on_press(Press::Released);
+        self.run();
+    }
+
+    /// Send a single `press` to `widget`, or the closest pressable ancestor of it.
+    #[track_caller]
+    pub fn press_state(&mut self, widget: &Widget, press: Press) {
+        let on_press = find_handler(widget, |pressable: &PressableState| {
+            pressable.on_press.clone()
+        });
+
+        on_press(press);
+        self.run();
+    }
+
+    /// Move the pointer in or out of `widget`, or the closest hoverable ancestor of it.
+    #[track_caller]
+    pub fn hover(&mut self, widget: &Widget, hovered: bool) {
+        let on_hover = find_handler(widget, |pressable: &PressableState| {
+            pressable.on_hover.clone()
+        });
+
+        on_hover(hovered);
+        self.run();
+    }
+
+    /// Move focus in or out of `widget`, or the closest focusable ancestor of it.
+    #[track_caller]
+    pub fn focus(&mut self, widget: &Widget, focused: bool) {
+        let on_focus = find_handler(widget, |pressable: &PressableState| {
+            pressable.on_focus.clone()
+        });
+
+        on_focus(focused);
+        self.run();
+    }
+
+    /// Dismiss the closest popover containing `widget`, as if the user clicked outside of it.
+    #[track_caller]
+    pub fn dismiss(&mut self, widget: &Widget) {
+        let on_dismiss = find_handler(widget, |popover: &PopoverState| {
+            popover.on_dismiss.clone()
+        });
+
+        let mut current = Some(widget.clone());
+
+        while let Some(widget) = current {
+            if widget.kind() == WidgetKind::Popover {
+                widget.state_mut::<PopoverState>().open = false;
+                break;
+            }
+
+            current = widget.parent();
+        }
+
+        on_dismiss();
+        self.run();
+    }
+
+    /// Select an item from the menu of `widget`, or the closest ancestor with a menu.
+    ///
+    /// The item is found by following `labels` through submenus, panicking if it doesn't
+    /// exist or is disabled. Items of the menubar are selected by passing the window.
+    #[track_caller]
+    pub fn select_menu_item(&mut self, widget: &Widget, labels: &[&str]) {
+        let mut current = Some(widget.clone());
+        let mut menu = None;
+
+        while let Some(widget) = current {
+            let on_select = match widget.kind() {
+                WidgetKind::ContextMenu => widget.state::<ContextMenuState>().on_select.clone(),
+                WidgetKind::Window => widget.state::<WindowState>().on_menu_select.clone(),
+                _ => None,
+            };
+
+            if let Some(on_select) = on_select {
+                menu = Some((widget.menu(), on_select));
+                break;
+            }
+
+            current = widget.parent();
+        }
+
+        let (menu, on_select) =
+            menu.expect("neither the widget nor any of its ancestors has a menu");
+
+        let id = find_menu_item(&menu, labels);
+        let id = id.expect("menu item should exist and be enabled");
+
+        on_select(id);
+        self.run();
+    }
+
+    /// Press `accelerator` while `focus` is focused, returning whether a shortcut handled it.
+    ///
+    /// Shortcuts containing `focus` are tried first, from the innermost outwards, followed by
+    /// the shortcuts with [`ShortcutScope::Window`] in the window of `focus`, and finally the
+    /// enabled app actions.
+    ///
+    /// # Panics
+    ///
+    /// If `accelerator` can't be parsed, see [`Accelerator::parse`].
+    #[track_caller]
+    pub fn press_shortcut(&mut self, focus: &Widget, accelerator: &str) -> bool {
+        let accelerator = match Accelerator::parse(accelerator) {
+            Ok(accelerator) => accelerator,
+            Err(err) => panic!("{err}"),
+        };
+
+        let mut current = Some(focus.clone());
+        let mut root = focus.clone();
+
+        while let Some(widget) = current {
+            if self.trigger_shortcut(&widget, accelerator) {
+                return true;
+            }
+
+            current = widget.parent();
+            root = widget;
+        }
+
+        let window_shortcuts = root.find_all(|widget| {
+            widget.kind() == WidgetKind::Shortcuts
+                && widget.shortcut_scope() == ShortcutScope::Window
+        });
+
+        if window_shortcuts
+            .iter()
+            .any(|widget| self.trigger_shortcut(widget, accelerator))
+        {
+            return true;
+        }
+
+        let on_activate = (self.context.platform.app_actions.iter())
+            .find(|action| action.enabled && action.accelerator == Some(accelerator))
+            .map(|action| action.on_activate.clone());
+
+        match on_activate {
+            Some(on_activate) => {
+                on_activate();
+                self.run();
+                true
+            }
+
+            None => false,
+        }
+    }
+
+    /// Activate the app action named `name`, as if the user activated it.
+    ///
+    /// # Panics
+    ///
+    /// If there's no such action, or it's disabled.
+    #[track_caller]
+    pub fn activate_app_action(&mut self, name: &str) {
+        let on_activate = match self.context.platform.app_action(name) {
+            Some(action) if action.enabled => action.on_activate.clone(),
+            _ => panic!("app action `{name}` should exist and be enabled"),
+        };
+
+        on_activate();
+        self.run();
+    }
+
+    fn trigger_shortcut(&mut self, widget: &Widget, accelerator: Accelerator) -> bool {
+        let (index, on_shortcut) = match widget.try_state::<ShortcutsState>() {
+            Some(shortcuts) => (
+                shortcuts
+                    .accelerators
+                    .iter()
+                    .position(|a| *a == accelerator),
+                shortcuts.on_shortcut.clone(),
+            ),
+            None => return false,
+        };
+
+        match (index, on_shortcut) {
+            (Some(index), Some(on_shortcut)) => {
+                on_shortcut(index);
+                self.run();
+                true
+            }
+
+            _ => false,
+        }
+    }
+
+    /// Send a key `event` while `focus` is focused, returning whether propagation was stopped.
+    ///
+    /// The event propagates from `focus` outwards through the key handlers containing it,
+    /// until one of them stops it. The messages of each handler are handled before asking it
+    /// whether the event propagates, like a real platform does.
+    pub fn key(&mut self, focus: &Widget, event: KeyEvent) -> bool {
+        let mut current = Some(focus.clone());
+        let mut stopped = false;
+
+        while let Some(widget) = current
+            && !stopped
+        {
+            let on_key = widget.try_state::<KeyHandlerState>();
+            let on_key = on_key.and_then(|handler| handler.on_key.clone());
+
+            if let Some((on_key, propagation)) = on_key {
+                on_key(event);
+                self.run();
+
+                // like gtk, only the propagation of pressed keys can be stopped
+                stopped = event.is_pressed() && propagation() == Some(Propagation::Stop);
+            }
+
+            current = widget.parent();
+        }
+
+        self.run();
+        stopped
+    }
+
+    /// Press and release `key` while `focus` is focused.
+    pub fn press_key(&mut self, focus: &Widget, key: Key) {
+        self.key(focus, KeyEvent::pressed(key));
+        self.key(focus, KeyEvent::released(key));
+    }
+
+    /// Send a pointer `event` to the closest pointer handler containing `widget`.
+    ///
+    /// The position of the event is relative to that pointer handler.
+    #[track_caller]
+    pub fn pointer(&mut self, widget: &Widget, event: PointerEvent) {
+        let on_pointer = find_handler(
+            widget,
+            |handler: &PointerHandlerState| handler.on_pointer.clone(),
+        );
+
+        on_pointer(event);
+        self.run();
+    }
+
+    /// Scroll the closest scroll containing `widget` to `offset`, as if the user scrolled.
+    ///
+    /// The offset is clamped to the size of the contents, an axis with
+    /// [`ScrollbarPolicy::Never`] doesn't scroll.
+    #[track_caller]
+    pub fn scroll(&mut self, widget: &Widget, offset: (f32, f32)) {
+        let on_scroll = find_handler(widget, |scroll: &ScrollState| {
+            scroll.on_scroll.clone()
+        });
+
+        let mut current = Some(widget.clone());
+
+        while let Some(widget) = current {
+            if widget.kind() == WidgetKind::Scroll {
+                let event = widget.scroll_event();
+
+                let (max_x, max_y) = match widget.scrollbars() {
+                    (ScrollbarPolicy::Never, ScrollbarPolicy::Never) => (0.0, 0.0),
+                    (ScrollbarPolicy::Never, _) => (0.0, event.max_offset_y()),
+                    (_, ScrollbarPolicy::Never) => (event.max_offset_x(), 0.0),
+                    _ => (
+                        event.max_offset_x(),
+                        event.max_offset_y(),
+                    ),
+                };
+
+                widget.state_mut::<ScrollState>().offset = (
+                    offset.0.clamp(0.0, max_x),
+                    offset.1.clamp(0.0, max_y),
+                );
+
+                on_scroll(widget.scroll_event());
+                self.run();
+
+                return;
+            }
+
+            current = widget.parent();
+        }
+    }
+
+    /// Drag from `start` by `offset` within the closest drag gesture containing `widget`,
+    /// then release.
+    ///
+    /// Nothing is reported if `offset` is shorter than the threshold of the gesture.
+    #[track_caller]
+    pub fn drag(&mut self, widget: &Widget, start: (f32, f32), offset: (f32, f32)) {
+        let on_drag = find_handler(widget, |gesture: &DragGestureState| {
+            gesture.on_drag.clone()
+        });
+
+        let mut gesture = Some(widget.clone());
+        let mut threshold = 0.0;
+
+        while let Some(widget) = gesture {
+            if widget.kind() == WidgetKind::DragGesture {
+                threshold = widget.drag_threshold();
+                break;
+            }
+
+            gesture = widget.parent();
+        }
+
+        if offset.0.hypot(offset.1) < threshold {
+            return;
+        }
+
+        for phase in [DragPhase::Start, DragPhase::End] {
+            on_drag(DragEvent {
+                phase,
+                start_x: start.0,
+                start_y: start.1,
+                offset_x: offset.0,
+                offset_y: offset.1,
+            });
+
+            self.run();
+        }
+    }
+
+    /// Drag the closest drag source containing `source` and drop it on the closest drop
+    /// target containing `target`, that accepts the payload.
+    ///
+    /// Returns whether the payload was dropped.
+    #[track_caller]
+    pub fn drag_and_drop(&mut self, source: &Widget, target: &Widget) -> bool {
+        let mut current = Some(source.clone());
+        let mut payload = None;
+
+        while let Some(widget) = current {
+            if widget.kind() == WidgetKind::DragSource {
+                payload = widget.payload();
+                break;
+            }
+
+            current = widget.parent();
+        }
+
+        let payload = payload.expect("neither the widget nor any of its ancestors is draggable");
+
+        let on_drag_start = find_handler(source, |source: &DragSourceState| {
+            source.on_drag_start.clone()
+        });
+
+        let on_drag_end = find_handler(source, |source: &DragSourceState| {
+            source.on_drag_end.clone()
+        });
+
+        on_drag_start();
+        self.run();
+
+        let mut current = Some(target.clone());
+        let mut dropped = false;
+
+        while let Some(widget) = current {
+            let handlers = widget.try_state::<DropTargetState>().map(|target| {
+                (
+                    target.accept.clone(),
+                    target.on_hover.clone(),
+                    target.on_drop.clone(),
+                )
+            });
+
+            if let Some((Some(accept), Some(on_hover), Some(on_drop))) = handlers
+                && accept(payload.as_ref())
+            {
+                on_hover(true);
+                self.run();
+
+                on_drop(payload.as_ref());
+                on_hover(false);
+                self.run();
+
+                dropped = true;
+                break;
+            }
+
+            current = widget.parent();
+        }
+
+        on_drag_end(dropped);
+        self.run();
+
+        dropped
+    }
+
+    /// Drop files or text from another application on the closest file drop containing
+    /// `widget`, that accepts them.
+    ///
+    /// Returns whether the data was dropped.
+    pub fn drop_external(&mut self, widget: &Widget, drop: ExternalDrop) -> bool {
+        let mut current = Some(widget.clone());
+
+        while let Some(widget) = current {
+            let handlers = widget.try_state::<FileDropState>().map(|file_drop| {
+                let accepted = match drop {
+                    ExternalDrop::Files(_) => file_drop.accepts_files,
+                    ExternalDrop::Text(_) => file_drop.accepts_text,
+                };
+
+                (
+                    accepted,
+                    file_drop.on_hover.clone(),
+                    file_drop.on_drop.clone(),
+                )
+            });
+
+            if let Some((true, Some(on_hover), Some(on_drop))) = handlers {
+                on_hover(true);
+                self.run();
+
+                on_drop(drop);
+                on_hover(false);
+                self.run();
+
+                return true;
+            }
+
+            current = widget.parent();
+        }
+
+        false
+    }
+
+    /// Replace the text of a text input, as if the user typed it.
+    ///
+    /// The text is cut short at the max length of the input, and single line inputs drop
+    /// newlines.
+    ///
+    /// # Panics
+    ///
+    /// If the input isn't editable.
+    #[track_caller]
+    pub fn change_text(&mut self, widget: &Widget, text: impl Into<String>) {
+        let on_change = find_handler(widget, |input: &TextInputState| {
+            input.on_change.clone()
+        });
+
+        assert!(
+            widget.is_editable(),
+            "text input should be editable"
+        );
+
+        let mut text = text.into();
+
+        if widget.input_mode() != InputMode::MultiLine {
+            text.retain(|c| c != '\n' && c != '\r');
+        }
+
+        let text = truncate(text, widget.max_length());
+        widget.state_mut::<TextInputState>().text = text.clone();
+
+        on_change(text);
+        self.run();
+    }
+
+    /// Submit the current text of a text input.
+    #[track_caller]
+    pub fn submit(&mut self, widget: &Widget) {
+        let on_submit = find_handler(widget, |input: &TextInputState| {
+            input.on_submit.clone()
+        });
+
+        on_submit(widget.text());
+        self.run();
+    }
+
+    /// Toggle a checkbox, switch or radio, as if the user clicked it or activated it with the
+    /// keyboard.
+    ///
+    /// Toggling a radio checks it and unchecks the other radios of its group, toggling a
+    /// checked radio does nothing.
+    #[track_caller]
+    pub fn toggle(&mut self, widget: &Widget) {
+        let on_toggle = find_handler(widget, |toggle: &ToggleState| {
+            toggle.on_toggle.clone()
+        });
+
+        if widget.kind() != WidgetKind::Radio {
+            let checked = !widget.checked().is_checked();
+            widget.state_mut::<ToggleState>().checked = CheckState::from(checked);
+
+            on_toggle(checked);
+            self.run();
+
+            return;
+        }
+
+        if widget.checked().is_checked() {
+            return;
+        }
+
+        for radio in widget.radio_group() {
+            if radio.checked().is_checked() {
+                radio.state_mut::<ToggleState>().checked = CheckState::Unchecked;
+
+                let on_toggle = radio.state::<ToggleState>().on_toggle.clone();
+
+                if let Some(on_toggle) = on_toggle {
+                    on_toggle(false);
+                }
+            }
+        }
+
+        widget.state_mut::<ToggleState>().checked = CheckState::Checked;
+
+        on_toggle(true);
+        self.run();
+    }
+
+    /// Drag `widget` through `values`, as if the user dragged a slider.
+    ///
+    /// Every value is clamped to the range and snapped to the step of the slider, and changes
+    /// it, after which the last value is committed.
+    #[track_caller]
+    pub fn slide(&mut self, widget: &Widget, values: impl IntoIterator<Item = f32>) {
+        let on_change = find_handler(widget, |slider: &SliderState| {
+            slider.on_change.clone()
+        });
+
+        let on_commit = widget.state::<SliderState>().on_commit.clone();
+        let start = widget.value();
+
+        for value in values {
+            let (min, max) = widget.range();
+
+            let value = match widget.step() {
+                Some(step) if step > 0.0 => min + ((value - min) / step).round() * step,
+                _ => value,
+            };
+
+            let value = value.clamp(min, max);
+
+            if value != widget.value() {
+                widget.state_mut::<SliderState>().value = value;
+                on_change(value);
+            }
+        }
+
+        if let Some(on_commit) = on_commit {
+            if widget.value() != start {
+                on_commit(widget.value());
+            }
+        }
+
+        self.run();
+    }
+
+    /// Select the option at `index` of a dropdown, as if the user picked it.
+    ///
+    /// # Panics
+    ///
+    /// If the option doesn't exist or is disabled.
+    #[track_caller]
+    pub fn select_option(&mut self, widget: &Widget, index: usize) {
+        let on_select = find_handler(widget, |dropdown: &DropdownState| {
+            dropdown.on_select.clone()
+        });
+
+        let option = widget.options().get(index).cloned();
+        let option = option.expect("option should exist");
+
+        assert!(
+            !option.disabled,
+            "option should be enabled"
+        );
+
+        widget.state_mut::<DropdownState>().selected = Some(index);
+
+        on_select(index);
+        self.run();
+    }
+
+    /// Resize `window`, as if the user resized it.
+    #[track_caller]
+    pub fn resize(&mut self, window: &Widget, width: u32, height: u32) {
+        let on_resize = find_handler(window, |window: &WindowState| {
+            window.on_resize.clone()
+        });
+
+        window.set_size(width as f32, height as f32);
+
+        on_resize();
+        self.run();
+    }
+
+    /// Ask `window` to close, as if the user clicked the close button.
+    #[track_caller]
+    pub fn request_close(&mut self, window: &Widget) {
+        let on_close_requested = find_handler(window, |window: &WindowState| {
+            window.on_close_requested.clone()
+        });
+
+        on_close_requested();
+        self.run();
+    }
+
+    /// Maximize or unmaximize `window`, as if the user did it.
+    #[track_caller]
+    pub fn set_maximized(&mut self, window: &Widget, maximized: bool) {
+        window.state_mut::<WindowState>().maximized = maximized;
+        self.state_changed(window);
+    }
+
+    /// Make `window` fullscreen or leave fullscreen, as if the user did it.
+    #[track_caller]
+    pub fn set_fullscreen(&mut self, window: &Widget, fullscreen: bool) {
+        window.state_mut::<WindowState>().fullscreen = fullscreen;
+        self.state_changed(window);
+    }
+
+    /// Minimize or unminimize `window`, as if the user did it.
+    #[track_caller]
+    pub fn set_minimized(&mut self, window: &Widget, minimized: bool) {
+        window.state_mut::<WindowState>().minimized = minimized;
+        self.state_changed(window);
+    }
+
+    #[track_caller]
+    fn state_changed(&mut self, window: &Widget) {
+        let on_state_changed = find_handler(window, |window: &WindowState| {
+            window.on_state_changed.clone()
+        });
+
+        on_state_changed();
+        self.run();
+    }
+
+    /// Advance all animating windows by `delta`.
+    pub fn animate(&mut self, delta: Duration) {
+        let on_frames: Vec<_> = self
+            .windows()
+            .iter()
+            .filter(|window| window.is_animating())
+            .filter_map(|window| window.state::<WindowState>().on_animation_frame.clone())
+            .collect();
+
+        for on_frame in on_frames {
+            on_frame(delta);
+        }
+
+        self.run();
+    }
+
+    fn rebuild_view(&mut self) {
+        if let Some(ref mut state) = self.state {
+            let view = (self.build)(&self.data);
+            view.rebuild(
+                (),
+                state,
+                &mut self.context,
+                &mut self.data,
+            );
+        }
+    }
+
+    fn handle_event(&mut self, event: Event) {
+        match event {
+            Event::Quit => {
+                self.running = false;
+            }
+
+            Event::Rebuild => self.rebuild_view(),
+
+            Event::Message(mut message) => {
+                if let Some(ref mut state) = self.state {
+                    let mut action = V::message(
+                        (),
+                        state,
+                        &mut self.context,
+                        &mut self.data,
+                        &mut message,
+                    );
+
+                    if action.take_rebuild() {
+                        let view = (self.build)(&self.data);
+                        view.rebuild(
+                            (),
+                            state,
+                            &mut self.context,
+                            &mut self.data,
+                        );
+                    }
+
+                    action.rebuild = false;
+                    self.context.send_action(action);
+                }
+            }
+        }
+    }
+}
+
+impl<T, V, B> Drop for Harness<T, V, B>
+where
+    V: Effect<Context<Platform>, T>,
+{
+    fn drop(&mut self) {
+        if let Some(state) = self.state.take() {
+            V::teardown((), state, &mut self.context);
+        }
+    }
+}
+
+/// Find the id of the enabled item at `labels` in `menu`.
+fn find_menu_item(menu: &MenuModel, labels: &[&str]) -> Option<usize> {
+    let (first, rest) = labels.split_first()?;
+
+    menu.entries.iter().find_map(|entry| match entry {
+        MenuEntry::Item {
+            id, label, enabled, ..
+        } if label == first && rest.is_empty() && *enabled => Some(*id),
+
+        MenuEntry::Submenu {
+            label,
+            enabled,
+            menu,
+        } if label == first && *enabled => find_menu_item(menu, rest),
+
+        _ => None,
+    })
+}
+
+/// Find a handler in the state `S` of `widget`, or of the closest ancestor that has one.
+#[track_caller]
+fn find_handler<S, H>(widget: &Widget, get: impl Fn(&S) -> Option<Rc<H>>) -> Rc<H>
+where
+    S: Any,
+    H: ?Sized,
+{
+    let mut current = Some(widget.clone());
+
+    while let Some(widget) = current {
+        let handler = widget.try_state::<S>().and_then(|state| get(&state));
+
+        if let Some(handler) = handler {
+            return handler;
+        }
+
+        current = widget.parent();
+    }
+
+    panic!("neither the widget nor any of its ancestors has a matching handler");
+}
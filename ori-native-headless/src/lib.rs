@@ -0,0 +1,17 @@
+//! A headless [`Platform`] that keeps its widgets in memory.
+//!
+//! Nothing is shown, instead every native widget is recorded in a tree of [`Widget`]s,
+//! which can be inspected after building or rebuilding a view. Input can be simulated
+//! with a [`Harness`], and a simplified rendering of a window is available with
+//! [`Widget::screenshot`].
+
+mod harness;
+mod platform;
+mod screenshot;
+mod widget;
+
+pub mod widgets;
+
+pub use harness::Harness;
+pub use platform::{Event, HeadlessProxy, Platform};
+pub use widget::{Widget, WidgetKind};
@@ -0,0 +1,215 @@
+use std::{
+    pin::Pin,
+    rc::Rc,
+    sync::{Arc, Mutex},
+};
+
+use ori::{Message, Proxied, Proxy};
+use ori_native_core::{
+    Accelerator, ClipboardContent, ClipboardKind,
+    native::{HasAppActions, HasClipboard},
+};
+use tokio::{
+    sync::mpsc::{UnboundedReceiver, UnboundedSender},
+    task::JoinHandle,
+};
+
+use crate::Widget;
+
+#[derive(Debug)]
+pub enum Event {
+    Quit,
+    Rebuild,
+    Message(Message),
+}
+
+pub struct Platform {
+    pub(crate) proxy:    HeadlessProxy,
+    pub(crate) receiver: UnboundedReceiver<Event>,
+    pub(crate) windows:  Vec<Widget>,
+
+    pub(crate) clipboard:   Option<ClipboardContent>,
+    pub(crate) app_actions: Vec<AppAction>,
+}
+
+pub(crate) struct AppAction {
+    pub(crate) name:        String,
+    pub(crate) accelerator: Option<Accelerator>,
+    pub(crate) enabled:     bool,
+    pub(crate) on_activate: Rc<dyn Fn()>,
+}
+
+impl Default for Platform {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Platform {
+    pub fn new() -> Self {
+        let runtime = Arc::new(tokio::runtime::Runtime::new().unwrap());
+        let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+
+        Self {
+            proxy: HeadlessProxy {
+                sender,
+                runtime,
+                tasks: Default::default(),
+            },
+            receiver,
+            windows: Vec::new(),
+            clipboard: None,
+            app_actions: Vec::new(),
+        }
+    }
+
+    /// Get the windows that are currently open, in the order they were built.
+    pub fn windows(&self) -> &[Widget] {
+        &self.windows
+    }
+
+    /// Get the content of the clipboard.
+    pub fn clipboard(&self) -> Option<&ClipboardContent> {
+        self.clipboard.as_ref()
+    }
+
+    /// Replace the content of the clipboard, as if another application wrote to it.
+    pub fn set_clipboard(&mut self, content: Option<ClipboardContent>) {
+        self.clipboard = content;
+    }
+
+    /// Get the names of the actions added to the application.
+    pub fn app_actions(&self) -> Vec<&str> {
+        self.app_actions
+            .iter()
+            .map(|action| action.name.as_str())
+            .collect()
+    }
+
+    /// Whether the action named `name` can be activated, `None` if there's no such action.
+    pub fn is_app_action_enabled(&self, name: &str) -> Option<bool> {
+        self.app_action(name).map(|action| action.enabled)
+    }
+
+    pub(crate) fn app_action(&self, name: &str) -> Option<&AppAction> {
+        self.app_actions.iter().find(|action| action.name == name)
+    }
+
+    /// Take the next event sent through the [`Proxy`], if any.
+    pub fn next_event(&mut self) -> Option<Event> {
+        self.receiver.try_recv().ok()
+    }
+
+    /// Wait for every future spawned through the [`Proxy`] to complete, returning whether
+    /// there were any.
+    ///
+    /// Panics of the futures are resumed here.
+    pub fn wait_for_tasks(&mut self) -> bool {
+        let tasks = std::mem::take(&mut *self.proxy.tasks.lock().unwrap());
+
+        if tasks.is_empty() {
+            return false;
+        }
+
+        self.proxy.runtime.block_on(async {
+            for task in tasks {
+                if let Err(err) = task.await
+                    && err.is_panic()
+                {
+                    std::panic::resume_unwind(err.into_panic());
+                }
+            }
+        });
+
+        true
+    }
+}
+
+impl ori_native_core::Platform for Platform {
+    type Widget = Widget;
+
+    fn quit(&mut self) {
+        let _ = self.proxy.sender.send(Event::Quit);
+    }
+}
+
+impl HasClipboard for Platform {
+    fn write_clipboard(&mut self, content: ClipboardContent) {
+        self.clipboard = Some(content);
+    }
+
+    fn read_clipboard(
+        &mut self,
+        kind: ClipboardKind,
+        on_read: impl FnOnce(Option<ClipboardContent>) + 'static,
+    ) {
+        let content = self.clipboard.clone();
+        on_read(content.filter(|content| content.kind() == kind));
+    }
+}
+
+impl HasAppActions for Platform {
+    fn add_app_action(
+        &mut self,
+        name: &str,
+        accelerator: Option<&str>,
+        on_activate: impl Fn() + 'static,
+    ) {
+        self.remove_app_action(name);
+
+        self.app_actions.push(AppAction {
+            name:        name.to_owned(),
+            accelerator: accelerator.and_then(|accelerator| Accelerator::parse(accelerator).ok()),
+            enabled:     true,
+            on_activate: Rc::new(on_activate),
+        });
+    }
+
+    fn remove_app_action(&mut self, name: &str) {
+        self.app_actions.retain(|action| action.name != name);
+    }
+
+    fn set_app_action_enabled(&mut self, name: &str, enabled: bool) {
+        if let Some(action) = self
+            .app_actions
+            .iter_mut()
+            .find(|action| action.name == name)
+        {
+            action.enabled = enabled;
+        }
+    }
+}
+
+impl Proxied for Platform {
+    type Proxy = HeadlessProxy;
+
+    fn proxy(&mut self) -> Self::Proxy {
+        self.proxy.clone()
+    }
+}
+
+#[derive(Clone)]
+pub struct HeadlessProxy {
+    sender:  UnboundedSender<Event>,
+    runtime: Arc<tokio::runtime::Runtime>,
+    tasks:   Arc<Mutex<Vec<JoinHandle<()>>>>,
+}
+
+impl Proxy for HeadlessProxy {
+    fn cloned(&self) -> Arc<dyn Proxy> {
+        Arc::new(self.clone())
+    }
+
+    fn rebuild(&self) {
+        let _ = self.sender.send(Event::Rebuild);
+    }
+
+    fn message(&self, message: Message) {
+        let _ = self.sender.send(Event::Message(message));
+    }
+
+    fn spawn_boxed(&self, future: Pin<Box<dyn Future<Output = ()> + Send>>) {
+        let task = self.runtime.spawn(future);
+        self.tasks.lock().unwrap().push(task);
+    }
+}
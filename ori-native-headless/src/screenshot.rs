@@ -0,0 +1,198 @@
+use ori_native_core::{Color, Overflow, native::Screenshot};
+
+use crate::{
+    Widget, WidgetKind,
+    widgets::{GroupState, ScrollState},
+};
+
+impl Widget {
+    /// Render the contents of a window, the menubar isn't included.
+    ///
+    /// Only the backgrounds and borders of groups are drawn, as rectangles without rounded
+    /// corners, which is enough to compare the layout and colors of a view.
+    #[track_caller]
+    pub fn screenshot(&self) -> Screenshot {
+        if self.kind() != WidgetKind::Window {
+            panic!(
+                "{:?} widget can't be rendered",
+                self.kind()
+            );
+        }
+
+        let (width, height) = self.size();
+        let mut canvas = Canvas::new(width as u32, height as u32);
+
+        let bounds = Rect::new(0.0, 0.0, width, height);
+
+        for child in self.children() {
+            canvas.draw(&child, (0.0, 0.0), bounds);
+        }
+
+        canvas.into_screenshot()
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Rect {
+    min_x: f32,
+    min_y: f32,
+    max_x: f32,
+    max_y: f32,
+}
+
+impl Rect {
+    fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            min_x: x,
+            min_y: y,
+            max_x: x + width,
+            max_y: y + height,
+        }
+    }
+
+    fn intersect(self, other: Self) -> Self {
+        Self {
+            min_x: self.min_x.max(other.min_x),
+            min_y: self.min_y.max(other.min_y),
+            max_x: self.max_x.min(other.max_x),
+            max_y: self.max_y.min(other.max_y),
+        }
+    }
+}
+
+struct Canvas {
+    width:  u32,
+    height: u32,
+    pixels: Vec<Color>,
+}
+
+impl Canvas {
+    fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![Color::TRANSPARENT; width as usize * height as usize],
+        }
+    }
+
+    /// Draw `widget` and its children, with `origin` the position of its parent.
+    fn draw(&mut self, widget: &Widget, origin: (f32, f32), mut clip: Rect) {
+        if !widget.is_visible() {
+            return;
+        }
+
+        let (x, y) = widget.position();
+        let (x, y) = (origin.0 + x, origin.1 + y);
+        let (width, height) = widget.size();
+
+        let bounds = Rect::new(x, y, width, height);
+        let mut offset = (0.0, 0.0);
+
+        match widget.kind() {
+            WidgetKind::Group => {
+                let group = widget.state::<GroupState>();
+                self.fill(
+                    bounds.intersect(clip),
+                    group.background_color,
+                );
+
+                // the sides are drawn between the top and bottom borders, so no pixel is
+                // drawn twice
+                let [top, right, bottom, left] = group.border_width;
+                let borders = [
+                    Rect::new(x, y, width, top),
+                    Rect::new(
+                        x + width - right,
+                        y + top,
+                        right,
+                        height - top - bottom,
+                    ),
+                    Rect::new(x, y + height - bottom, width, bottom),
+                    Rect::new(x, y + top, left, height - top - bottom),
+                ];
+
+                for border in borders {
+                    self.fill(
+                        border.intersect(clip),
+                        group.border_color,
+                    );
+                }
+
+                if group.overflow == Overflow::Hidden {
+                    clip = clip.intersect(bounds);
+                }
+            }
+
+            WidgetKind::Scroll => {
+                offset = widget.state::<ScrollState>().offset;
+                clip = clip.intersect(bounds);
+            }
+
+            _ => {}
+        }
+
+        for child in widget.children() {
+            self.draw(
+                &child,
+                (x - offset.0, y - offset.1),
+                clip,
+            );
+        }
+    }
+
+    /// Blend `color` over the pixels whose centers are in `rect`.
+    fn fill(&mut self, rect: Rect, color: Color) {
+        if color.a <= 0.0 {
+            return;
+        }
+
+        let min_x = rect.min_x.round().max(0.0) as u32;
+        let min_y = rect.min_y.round().max(0.0) as u32;
+        let max_x = (rect.max_x.round().max(0.0) as u32).min(self.width);
+        let max_y = (rect.max_y.round().max(0.0) as u32).min(self.height);
+
+        for y in min_y..max_y {
+            for x in min_x..max_x {
+                let pixel = &mut self.pixels[(y * self.width + x) as usize];
+                *pixel = blend(*pixel, color);
+            }
+        }
+    }
+
+    fn into_screenshot(self) -> Screenshot {
+        let channel = |x: f32| (x.clamp(0.0, 1.0) * 255.0).round() as u8;
+
+        let pixels = self.pixels.iter().flat_map(|color| {
+            [
+                channel(color.r),
+                channel(color.g),
+                channel(color.b),
+                channel(color.a),
+            ]
+        });
+
+        Screenshot {
+            width:  self.width,
+            height: self.height,
+            pixels: pixels.collect(),
+        }
+    }
+}
+
+/// Blend `source` over `destination`, neither of which is premultiplied.
+fn blend(destination: Color, source: Color) -> Color {
+    let alpha = source.a + destination.a * (1.0 - source.a);
+
+    if alpha <= 0.0 {
+        return Color::TRANSPARENT;
+    }
+
+    let color = source * source.a + destination * (destination.a * (1.0 - source.a));
+
+    Color {
+        r: color.r / alpha,
+        g: color.g / alpha,
+        b: color.b / alpha,
+        a: alpha,
+    }
+}
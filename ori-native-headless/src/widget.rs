@@ -0,0 +1,350 @@
+use std::{
+    any::{Any, type_name},
+    cell::{Ref, RefCell, RefMut},
+    fmt,
+    rc::{Rc, Weak},
+};
+
+use ori_native_core::{Font, MenuModel};
+
+use crate::widgets::{ContextMenuState, TextInputState, TextState, ToggleState, WindowState};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WidgetKind {
+    Window,
+    Group,
+    Pressable,
+    Popover,
+    ContextMenu,
+    Shortcuts,
+    KeyHandler,
+    PointerHandler,
+    DragGesture,
+    DragSource,
+    DropTarget,
+    FileDrop,
+    Scroll,
+    Text,
+    TextInput,
+    Checkbox,
+    Switch,
+    Radio,
+    Slider,
+    Progress,
+    Spinner,
+    Dropdown,
+    Image,
+}
+
+/// A native widget of the headless platform.
+///
+/// Cloning a widget is cheap and yields a handle to the same widget. Getters for the
+/// properties of a kind of widget, e.g. [`Widget::value`] of a slider, panic when called on
+/// a widget of another kind.
+#[derive(Clone)]
+pub struct Widget {
+    data: Rc<RefCell<WidgetData>>,
+}
+
+pub(crate) struct WidgetData {
+    pub(crate) kind:     WidgetKind,
+    pub(crate) parent:   Weak<RefCell<WidgetData>>,
+    pub(crate) children: Vec<Widget>,
+
+    pub(crate) x:       f32,
+    pub(crate) y:       f32,
+    pub(crate) width:   f32,
+    pub(crate) height:  f32,
+    pub(crate) visible: bool,
+
+    /// The state of the kind of widget, e.g. a [`TextState`] for a [`WidgetKind::Text`].
+    pub(crate) state: Box<dyn Any>,
+}
+
+impl Widget {
+    pub(crate) fn new(kind: WidgetKind, state: impl Any) -> Self {
+        let data = WidgetData {
+            kind,
+            parent: Weak::new(),
+            children: Vec::new(),
+
+            x: 0.0,
+            y: 0.0,
+            width: 0.0,
+            height: 0.0,
+            visible: true,
+
+            state: Box::new(state),
+        };
+
+        Self {
+            data: Rc::new(RefCell::new(data)),
+        }
+    }
+
+    pub(crate) fn data(&self) -> Ref<'_, WidgetData> {
+        self.data.borrow()
+    }
+
+    pub(crate) fn data_mut(&self) -> RefMut<'_, WidgetData> {
+        self.data.borrow_mut()
+    }
+
+    /// Get the state of the widget, panicking if it's of another kind.
+    #[track_caller]
+    pub(crate) fn state<S: Any>(&self) -> Ref<'_, S> {
+        match self.try_state() {
+            Some(state) => state,
+            None => panic!(
+                "{:?} widget has no {}",
+                self.kind(),
+                type_name::<S>()
+            ),
+        }
+    }
+
+    /// Get the state of the widget mutably, panicking if it's of another kind.
+    #[track_caller]
+    pub(crate) fn state_mut<S: Any>(&self) -> RefMut<'_, S> {
+        let kind = self.kind();
+
+        match RefMut::filter_map(self.data_mut(), |data| {
+            data.state.downcast_mut()
+        }) {
+            Ok(state) => state,
+            Err(_) => panic!(
+                "{kind:?} widget has no {}",
+                type_name::<S>()
+            ),
+        }
+    }
+
+    /// Get the state of the widget, if it's an `S`.
+    pub(crate) fn try_state<S: Any>(&self) -> Option<Ref<'_, S>> {
+        Ref::filter_map(self.data(), |data| {
+            data.state.downcast_ref()
+        })
+        .ok()
+    }
+
+    pub(crate) fn downgrade(&self) -> Weak<RefCell<WidgetData>> {
+        Rc::downgrade(&self.data)
+    }
+
+    pub(crate) fn upgrade(data: &Weak<RefCell<WidgetData>>) -> Option<Self> {
+        let data = data.upgrade()?;
+        Some(Widget { data })
+    }
+
+    pub(crate) fn insert_child(&self, index: usize, child: &Widget) {
+        child.data_mut().parent = Rc::downgrade(&self.data);
+        self.data_mut().children.insert(index, child.clone());
+    }
+
+    pub(crate) fn remove_child(&self, index: usize) {
+        let child = self.data_mut().children.remove(index);
+        child.data_mut().parent = Weak::new();
+    }
+
+    pub(crate) fn replace_child(&self, index: usize, child: &Widget) {
+        child.data_mut().parent = Rc::downgrade(&self.data);
+
+        let previous = std::mem::replace(
+            &mut self.data_mut().children[index],
+            child.clone(),
+        );
+
+        previous.data_mut().parent = Weak::new();
+    }
+
+    pub(crate) fn swap_children(&self, index_a: usize, index_b: usize) {
+        self.data_mut().children.swap(index_a, index_b);
+    }
+
+    /// Replace all children of a container that only ever has a single child.
+    pub(crate) fn set_child(&self, child: &Widget) {
+        if self.data().children.is_empty() {
+            self.insert_child(0, child);
+        } else {
+            self.replace_child(0, child);
+        }
+    }
+
+    pub(crate) fn set_size(&self, width: f32, height: f32) {
+        let mut data = self.data_mut();
+        data.width = width;
+        data.height = height;
+    }
+
+    pub(crate) fn set_bounds(&self, x: f32, y: f32, width: f32, height: f32) {
+        let mut data = self.data_mut();
+        data.x = x;
+        data.y = y;
+        data.width = width;
+        data.height = height;
+    }
+
+    pub fn kind(&self) -> WidgetKind {
+        self.data().kind
+    }
+
+    pub fn parent(&self) -> Option<Widget> {
+        Widget::upgrade(&self.data().parent)
+    }
+
+    pub fn children(&self) -> Vec<Widget> {
+        self.data().children.clone()
+    }
+
+    pub fn child(&self, index: usize) -> Option<Widget> {
+        self.data().children.get(index).cloned()
+    }
+
+    /// Get the position of the widget relative to its parent.
+    pub fn position(&self) -> (f32, f32) {
+        let data = self.data();
+        (data.x, data.y)
+    }
+
+    pub fn size(&self) -> (f32, f32) {
+        let data = self.data();
+        (data.width, data.height)
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.data().visible
+    }
+
+    /// Whether the widget is shown, which is when it and all of its ancestors are visible, up
+    /// to a window.
+    pub fn is_mapped(&self) -> bool {
+        let mut current = Some(self.clone());
+
+        while let Some(widget) = current {
+            if !widget.is_visible() {
+                return false;
+            }
+
+            if widget.kind() == WidgetKind::Window {
+                return true;
+            }
+
+            current = widget.parent();
+        }
+
+        false
+    }
+
+    /// Get the text of a text or text input, or the label of a checkbox, switch or radio.
+    #[track_caller]
+    pub fn text(&self) -> String {
+        match self.kind() {
+            WidgetKind::Text => self.state::<TextState>().text.clone(),
+            WidgetKind::TextInput => self.state::<TextInputState>().text.clone(),
+            WidgetKind::Checkbox | WidgetKind::Switch | WidgetKind::Radio => {
+                self.state::<ToggleState>().label.clone()
+            }
+            kind => panic!("{kind:?} widget has no text"),
+        }
+    }
+
+    /// Get the font of a text or text input.
+    #[track_caller]
+    pub fn font(&self) -> Font {
+        match self.kind() {
+            WidgetKind::Text => self.state::<TextState>().font.clone(),
+            WidgetKind::TextInput => self.state::<TextInputState>().font.clone(),
+            kind => panic!("{kind:?} widget has no font"),
+        }
+    }
+
+    /// Get the menu of a context menu, or the menubar of a window.
+    #[track_caller]
+    pub fn menu(&self) -> MenuModel {
+        match self.kind() {
+            WidgetKind::Window => self.state::<WindowState>().menubar.clone(),
+            WidgetKind::ContextMenu => self.state::<ContextMenuState>().menu.clone(),
+            kind => panic!("{kind:?} widget has no menu"),
+        }
+    }
+
+    /// Find the first widget, in depth first order, that matches `predicate`.
+    ///
+    /// This includes the widget itself.
+    pub fn find(&self, predicate: impl Fn(&Widget) -> bool) -> Option<Widget> {
+        self.find_impl(&predicate)
+    }
+
+    /// Find all widgets, in depth first order, that match `predicate`.
+    ///
+    /// This includes the widget itself.
+    pub fn find_all(&self, predicate: impl Fn(&Widget) -> bool) -> Vec<Widget> {
+        let mut widgets = Vec::new();
+        self.find_all_impl(&predicate, &mut widgets);
+        widgets
+    }
+
+    fn find_all_impl(&self, predicate: &dyn Fn(&Widget) -> bool, widgets: &mut Vec<Widget>) {
+        if predicate(self) {
+            widgets.push(self.clone());
+        }
+
+        for child in self.children() {
+            child.find_all_impl(predicate, widgets);
+        }
+    }
+
+    fn find_impl(&self, predicate: &dyn Fn(&Widget) -> bool) -> Option<Widget> {
+        if predicate(self) {
+            return Some(self.clone());
+        }
+
+        self.children()
+            .iter()
+            .find_map(|child| child.find_impl(predicate))
+    }
+
+    /// Find the first [`WidgetKind::Text`] widget displaying `text`.
+    pub fn find_text(&self, text: &str) -> Option<Widget> {
+        self.find(|widget| widget.kind() == WidgetKind::Text && widget.text() == text)
+    }
+
+    /// Find the first widget of `kind`.
+    pub fn find_kind(&self, kind: WidgetKind) -> Option<Widget> {
+        self.find(|widget| widget.kind() == kind)
+    }
+}
+
+impl PartialEq for Widget {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.data, &other.data)
+    }
+}
+
+impl Eq for Widget {}
+
+impl fmt::Debug for Widget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let data = self.data();
+
+        let mut debug = f.debug_struct("Widget");
+        debug.field("kind", &data.kind);
+        debug.field("x", &data.x);
+        debug.field("y", &data.y);
+        debug.field("width", &data.width);
+        debug.field("height", &data.height);
+
+        if matches!(
+            data.kind,
+            WidgetKind::Text | WidgetKind::TextInput
+        ) {
+            debug.field("text", &self.text());
+        }
+
+        if !data.children.is_empty() {
+            debug.field("children", &data.children);
+        }
+
+        debug.finish()
+    }
+}
@@ -0,0 +1,58 @@
+use std::rc::Rc;
+
+use ori_native_core::{
+    MenuModel, NativeWidget,
+    native::{HasContextMenu, NativeContextMenu},
+};
+
+use crate::{Platform, Widget, WidgetKind};
+
+impl HasContextMenu for Platform {
+    type ContextMenu = ContextMenu;
+}
+
+pub struct ContextMenu {
+    widget: Widget,
+}
+
+#[derive(Default)]
+pub(crate) struct ContextMenuState {
+    pub(crate) menu:      MenuModel,
+    pub(crate) on_select: Option<Rc<dyn Fn(usize)>>,
+}
+
+impl NativeWidget<Platform> for ContextMenu {
+    fn widget(&self) -> &Widget {
+        &self.widget
+    }
+}
+
+impl NativeContextMenu<Platform> for ContextMenu {
+    fn build(_platform: &mut Platform, contents: &Widget) -> Self {
+        let widget = Widget::new(
+            WidgetKind::ContextMenu,
+            ContextMenuState::default(),
+        );
+        widget.set_child(contents);
+
+        Self { widget }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        self.widget.set_size(width, height);
+
+        if let Some(contents) = self.widget.child(0) {
+            contents.set_bounds(0.0, 0.0, width, height);
+        }
+    }
+
+    fn set_menu(&mut self, _platform: &mut Platform, menu: &MenuModel) {
+        self.widget.state_mut::<ContextMenuState>().menu = menu.clone();
+    }
+
+    fn set_on_select(&mut self, on_select: impl Fn(usize) + 'static) {
+        self.widget.state_mut::<ContextMenuState>().on_select = Some(Rc::new(on_select));
+    }
+}
@@ -0,0 +1,180 @@
+use std::{any::Any, rc::Rc};
+
+use ori_native_core::{
+    DragEvent, NativeWidget,
+    native::{
+        HasDragGesture, HasDragSource, HasDropTarget, NativeDragGesture, NativeDragSource,
+        NativeDropTarget,
+    },
+};
+
+use crate::{Platform, Widget, WidgetKind};
+
+impl HasDragGesture for Platform {
+    type DragGesture = DragGesture;
+}
+
+impl HasDragSource for Platform {
+    type DragSource = DragSource;
+}
+
+impl HasDropTarget for Platform {
+    type DropTarget = DropTarget;
+}
+
+pub struct DragGesture {
+    widget: Widget,
+}
+
+#[derive(Default)]
+pub(crate) struct DragGestureState {
+    pub(crate) threshold: f32,
+    pub(crate) on_drag:   Option<Rc<dyn Fn(DragEvent)>>,
+}
+
+impl Widget {
+    /// Get the distance the pointer must move before a drag starts.
+    pub fn drag_threshold(&self) -> f32 {
+        self.state::<DragGestureState>().threshold
+    }
+
+    /// Get the payload of a drag source.
+    pub fn payload(&self) -> Option<Rc<dyn Any>> {
+        self.state::<DragSourceState>().payload.clone()
+    }
+}
+
+impl NativeWidget<Platform> for DragGesture {
+    fn widget(&self) -> &Widget {
+        &self.widget
+    }
+}
+
+impl NativeDragGesture<Platform> for DragGesture {
+    fn build(_platform: &mut Platform, contents: &Widget) -> Self {
+        let widget = Widget::new(
+            WidgetKind::DragGesture,
+            DragGestureState::default(),
+        );
+        widget.set_child(contents);
+
+        Self { widget }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        set_size(&self.widget, width, height);
+    }
+
+    fn set_threshold(&mut self, threshold: f32) {
+        self.widget.state_mut::<DragGestureState>().threshold = threshold;
+    }
+
+    fn set_on_drag(&mut self, on_drag: impl Fn(DragEvent) + 'static) {
+        self.widget.state_mut::<DragGestureState>().on_drag = Some(Rc::new(on_drag));
+    }
+}
+
+pub struct DragSource {
+    widget: Widget,
+}
+
+#[derive(Default)]
+pub(crate) struct DragSourceState {
+    pub(crate) payload:       Option<Rc<dyn Any>>,
+    pub(crate) on_drag_start: Option<Rc<dyn Fn()>>,
+    pub(crate) on_drag_end:   Option<Rc<dyn Fn(bool)>>,
+}
+
+impl NativeWidget<Platform> for DragSource {
+    fn widget(&self) -> &Widget {
+        &self.widget
+    }
+}
+
+impl NativeDragSource<Platform> for DragSource {
+    fn build(_platform: &mut Platform, contents: &Widget) -> Self {
+        let widget = Widget::new(
+            WidgetKind::DragSource,
+            DragSourceState::default(),
+        );
+        widget.set_child(contents);
+
+        Self { widget }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        set_size(&self.widget, width, height);
+    }
+
+    fn set_payload(&mut self, payload: Rc<dyn Any>) {
+        self.widget.state_mut::<DragSourceState>().payload = Some(payload);
+    }
+
+    fn set_on_drag_start(&mut self, on_drag_start: impl Fn() + 'static) {
+        self.widget.state_mut::<DragSourceState>().on_drag_start = Some(Rc::new(on_drag_start));
+    }
+
+    fn set_on_drag_end(&mut self, on_drag_end: impl Fn(bool) + 'static) {
+        self.widget.state_mut::<DragSourceState>().on_drag_end = Some(Rc::new(on_drag_end));
+    }
+}
+
+pub struct DropTarget {
+    widget: Widget,
+}
+
+#[derive(Default)]
+#[allow(clippy::type_complexity)]
+pub(crate) struct DropTargetState {
+    pub(crate) accept:   Option<Rc<dyn Fn(&dyn Any) -> bool>>,
+    pub(crate) on_hover: Option<Rc<dyn Fn(bool)>>,
+    pub(crate) on_drop:  Option<Rc<dyn Fn(&dyn Any)>>,
+}
+
+impl NativeWidget<Platform> for DropTarget {
+    fn widget(&self) -> &Widget {
+        &self.widget
+    }
+}
+
+impl NativeDropTarget<Platform> for DropTarget {
+    fn build(_platform: &mut Platform, contents: &Widget) -> Self {
+        let widget = Widget::new(
+            WidgetKind::DropTarget,
+            DropTargetState::default(),
+        );
+        widget.set_child(contents);
+
+        Self { widget }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        set_size(&self.widget, width, height);
+    }
+
+    fn set_accept(&mut self, accept: impl Fn(&dyn Any) -> bool + 'static) {
+        self.widget.state_mut::<DropTargetState>().accept = Some(Rc::new(accept));
+    }
+
+    fn set_on_hover(&mut self, on_hover: impl Fn(bool) + 'static) {
+        self.widget.state_mut::<DropTargetState>().on_hover = Some(Rc::new(on_hover));
+    }
+
+    fn set_on_drop(&mut self, on_drop: impl Fn(&dyn Any) + 'static) {
+        self.widget.state_mut::<DropTargetState>().on_drop = Some(Rc::new(on_drop));
+    }
+}
+
+fn set_size(widget: &Widget, width: f32, height: f32) {
+    widget.set_size(width, height);
+
+    if let Some(contents) = widget.child(0) {
+        contents.set_bounds(0.0, 0.0, width, height);
+    }
+}
@@ -0,0 +1,119 @@
+use std::rc::Rc;
+
+use ori_native_core::{
+    Font, LayoutLeaf, NativeWidget, Wrap,
+    native::{HasDropdown, NativeDropdown},
+    views::DropdownOption,
+};
+
+use crate::{Platform, Widget, WidgetKind};
+
+/// The width of the arrow after the selected option.
+const ARROW_SIZE: f32 = 16.0;
+
+/// The space around the selected option.
+const PADDING: f32 = 8.0;
+
+impl HasDropdown for Platform {
+    type Dropdown = Dropdown;
+}
+
+pub struct Dropdown {
+    widget: Widget,
+}
+
+#[derive(Default)]
+pub(crate) struct DropdownState {
+    pub(crate) options:    Vec<DropdownOption>,
+    pub(crate) selected:   Option<usize>,
+    pub(crate) searchable: bool,
+    pub(crate) on_select:  Option<Rc<dyn Fn(usize)>>,
+}
+
+impl Widget {
+    /// Get the options of a dropdown.
+    pub fn options(&self) -> Vec<DropdownOption> {
+        self.state::<DropdownState>().options.clone()
+    }
+
+    /// Get the index of the selected option of a dropdown.
+    pub fn selected(&self) -> Option<usize> {
+        self.state::<DropdownState>().selected
+    }
+
+    /// Get whether the options of a dropdown can be searched.
+    pub fn is_searchable(&self) -> bool {
+        self.state::<DropdownState>().searchable
+    }
+}
+
+impl NativeWidget<Platform> for Dropdown {
+    fn widget(&self) -> &Widget {
+        &self.widget
+    }
+}
+
+impl NativeDropdown<Platform> for Dropdown {
+    fn build(_platform: &mut Platform) -> Self {
+        Self {
+            widget: Widget::new(
+                WidgetKind::Dropdown,
+                DropdownState::default(),
+            ),
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_options(&mut self, options: &[DropdownOption]) {
+        self.widget.state_mut::<DropdownState>().options = options.to_vec();
+    }
+
+    fn set_selected(&mut self, selected: Option<usize>) {
+        self.widget.state_mut::<DropdownState>().selected = selected;
+    }
+
+    fn set_searchable(&mut self, searchable: bool) {
+        self.widget.state_mut::<DropdownState>().searchable = searchable;
+    }
+
+    fn set_on_select(&mut self, on_select: impl Fn(usize) + 'static) {
+        self.widget.state_mut::<DropdownState>().on_select = Some(Rc::new(on_select));
+    }
+
+    fn get_layout(&mut self, _platform: &mut Platform) -> impl LayoutLeaf<Platform> {
+        let state = self.widget.state::<DropdownState>();
+
+        let label = state
+            .selected
+            .and_then(|index| state.options.get(index))
+            .map_or(String::new(), |option| {
+                option.label.clone()
+            });
+
+        Layout { label }
+    }
+}
+
+struct Layout {
+    label: String,
+}
+
+impl LayoutLeaf<Platform> for Layout {
+    fn measure(
+        &mut self,
+        _platform: &mut Platform,
+        _known_size: taffy::Size<Option<f32>>,
+        _available_space: taffy::Size<taffy::AvailableSpace>,
+    ) -> taffy::Size<f32> {
+        let font = Font::default();
+
+        let (width, height) = super::text::measure_text(&font, &self.label, Wrap::None, None);
+        let height = height.max(super::text::line_height(&font));
+
+        taffy::Size {
+            width:  PADDING + width + PADDING + ARROW_SIZE + PADDING,
+            height: PADDING + height + PADDING,
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use std::rc::Rc;
+
+use ori_native_core::{
+    ExternalDrop, NativeWidget,
+    native::{HasFileDrop, NativeFileDrop},
+};
+
+use crate::{Platform, Widget, WidgetKind};
+
+impl HasFileDrop for Platform {
+    type FileDrop = FileDrop;
+}
+
+pub struct FileDrop {
+    widget: Widget,
+}
+
+#[derive(Default)]
+pub(crate) struct FileDropState {
+    pub(crate) accepts_files: bool,
+    pub(crate) accepts_text:  bool,
+    pub(crate) on_hover:      Option<Rc<dyn Fn(bool)>>,
+    pub(crate) on_drop:       Option<Rc<dyn Fn(ExternalDrop)>>,
+}
+
+impl Widget {
+    /// Get whether a file drop accepts files.
+    pub fn accepts_files(&self) -> bool {
+        self.state::<FileDropState>().accepts_files
+    }
+
+    /// Get whether a file drop accepts text.
+    pub fn accepts_text(&self) -> bool {
+        self.state::<FileDropState>().accepts_text
+    }
+}
+
+impl NativeWidget<Platform> for FileDrop {
+    fn widget(&self) -> &Widget {
+        &self.widget
+    }
+}
+
+impl NativeFileDrop<Platform> for FileDrop {
+    fn build(_platform: &mut Platform, contents: &Widget) -> Self {
+        let widget = Widget::new(
+            WidgetKind::FileDrop,
+            FileDropState::default(),
+        );
+        widget.set_child(contents);
+
+        Self { widget }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        self.widget.set_size(width, height);
+
+        if let Some(contents) = self.widget.child(0) {
+            contents.set_bounds(0.0, 0.0, width, height);
+        }
+    }
+
+    fn set_accepts(&mut self, files: bool, text: bool) {
+        let mut state = self.widget.state_mut::<FileDropState>();
+        state.accepts_files = files;
+        state.accepts_text = text;
+    }
+
+    fn set_on_hover(&mut self, on_hover: impl Fn(bool) + 'static) {
+        self.widget.state_mut::<FileDropState>().on_hover = Some(Rc::new(on_hover));
+    }
+
+    fn set_on_drop(&mut self, on_drop: impl Fn(ExternalDrop) + 'static) {
+        self.widget.state_mut::<FileDropState>().on_drop = Some(Rc::new(on_drop));
+    }
+}
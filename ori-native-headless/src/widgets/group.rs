@@ -0,0 +1,124 @@
+use ori_native_core::{
+    Color, NativeParent, NativeWidget, Overflow,
+    native::{HasGroup, NativeGroup},
+};
+
+use crate::{Platform, Widget, WidgetKind};
+
+impl HasGroup for Platform {
+    type Group = Group;
+}
+
+pub struct Group {
+    widget: Widget,
+}
+
+pub(crate) struct GroupState {
+    pub(crate) background_color: Color,
+    pub(crate) border_color:     Color,
+    pub(crate) border_width:     [f32; 4],
+    pub(crate) corner_radii:     [f32; 4],
+    pub(crate) overflow:         Overflow,
+}
+
+impl Widget {
+    pub fn background_color(&self) -> Color {
+        self.state::<GroupState>().background_color
+    }
+
+    pub fn border_color(&self) -> Color {
+        self.state::<GroupState>().border_color
+    }
+
+    /// Get the border widths, in the order top, right, bottom, left.
+    pub fn border_width(&self) -> [f32; 4] {
+        self.state::<GroupState>().border_width
+    }
+
+    /// Get the corner radii, in the order top left, top right, bottom right, bottom left.
+    pub fn corner_radii(&self) -> [f32; 4] {
+        self.state::<GroupState>().corner_radii
+    }
+
+    pub fn overflow(&self) -> Overflow {
+        self.state::<GroupState>().overflow
+    }
+}
+
+impl NativeWidget<Platform> for Group {
+    fn widget(&self) -> &Widget {
+        &self.widget
+    }
+}
+
+impl NativeParent<Platform> for Group {
+    fn replace_child(&mut self, _platform: &mut Platform, index: usize, child: &Widget) {
+        self.widget.replace_child(index, child);
+    }
+}
+
+impl NativeGroup<Platform> for Group {
+    fn build(_platform: &mut Platform) -> Self {
+        let state = GroupState {
+            background_color: Color::TRANSPARENT,
+            border_color:     Color::TRANSPARENT,
+            border_width:     [0.0; 4],
+            corner_radii:     [0.0; 4],
+            overflow:         Overflow::Visible,
+        };
+
+        Self {
+            widget: Widget::new(WidgetKind::Group, state),
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn insert_child(&mut self, index: usize, child: &Widget) {
+        self.widget.insert_child(index, child);
+    }
+
+    fn remove_child(&mut self, index: usize) {
+        self.widget.remove_child(index);
+    }
+
+    fn swap_children(&mut self, index_a: usize, index_b: usize) {
+        self.widget.swap_children(index_a, index_b);
+    }
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        self.widget.set_size(width, height);
+    }
+
+    fn set_child_layout(&mut self, index: usize, x: f32, y: f32, width: f32, height: f32) {
+        if let Some(child) = self.widget.child(index) {
+            child.set_bounds(x, y, width, height);
+        }
+    }
+
+    fn set_child_visible(&mut self, index: usize, visible: bool) {
+        if let Some(child) = self.widget.child(index) {
+            child.data_mut().visible = visible;
+        }
+    }
+
+    fn set_background_color(&mut self, _platform: &mut Platform, color: Color) {
+        self.widget.state_mut::<GroupState>().background_color = color;
+    }
+
+    fn set_border_color(&mut self, _platform: &mut Platform, color: Color) {
+        self.widget.state_mut::<GroupState>().border_color = color;
+    }
+
+    fn set_border_width(&mut self, _platform: &mut Platform, width: [f32; 4]) {
+        self.widget.state_mut::<GroupState>().border_width = width;
+    }
+
+    fn set_corner_radii(&mut self, _platform: &mut Platform, radii: [f32; 4]) {
+        self.widget.state_mut::<GroupState>().corner_radii = radii;
+    }
+
+    fn set_overflow(&mut self, _platform: &mut Platform, overflow: Overflow) {
+        self.widget.state_mut::<GroupState>().overflow = overflow;
+    }
+}
@@ -0,0 +1,107 @@
+use std::{borrow::Cow, convert::Infallible};
+
+use ori_native_core::{
+    Color, LayoutLeaf, NativeWidget,
+    native::{HasImage, NativeImage},
+};
+
+use crate::{Platform, Widget, WidgetKind};
+
+impl HasImage for Platform {
+    type Image = Image;
+}
+
+pub struct Image {
+    widget: Widget,
+}
+
+pub(crate) struct ImageState {
+    pub(crate) data: Vec<u8>,
+    pub(crate) tint: Option<Color>,
+}
+
+impl Widget {
+    pub fn image_data(&self) -> Vec<u8> {
+        self.state::<ImageState>().data.clone()
+    }
+
+    pub fn tint(&self) -> Option<Color> {
+        self.state::<ImageState>().tint
+    }
+}
+
+impl NativeWidget<Platform> for Image {
+    fn widget(&self) -> &Widget {
+        &self.widget
+    }
+}
+
+impl NativeImage<Platform> for Image {
+    type Error = Infallible;
+
+    fn build(_plaform: &mut Platform) -> Self {
+        let state = ImageState {
+            data: Vec::new(),
+            tint: None,
+        };
+
+        Self {
+            widget: Widget::new(WidgetKind::Image, state),
+        }
+    }
+
+    fn teardown(self, _plaform: &mut Platform) {}
+
+    fn load_data(
+        &mut self,
+        _plaform: &mut Platform,
+        data: Cow<'static, [u8]>,
+    ) -> Result<impl LayoutLeaf<Platform>, Self::Error> {
+        let (width, height) = png_size(&data).unwrap_or((0, 0));
+        self.widget.state_mut::<ImageState>().data = data.into_owned();
+
+        Ok(Layout {
+            width:  width as f32,
+            height: height as f32,
+        })
+    }
+
+    fn set_tint(&mut self, tint: Option<Color>) {
+        self.widget.state_mut::<ImageState>().tint = tint;
+    }
+}
+
+struct Layout {
+    width:  f32,
+    height: f32,
+}
+
+impl LayoutLeaf<Platform> for Layout {
+    fn measure(
+        &mut self,
+        _platform: &mut Platform,
+        known_size: taffy::Size<Option<f32>>,
+        _available_space: taffy::Size<taffy::AvailableSpace>,
+    ) -> taffy::Size<f32> {
+        taffy::Size {
+            width:  known_size.width.unwrap_or(self.width),
+            height: known_size.height.unwrap_or(self.height),
+        }
+    }
+}
+
+/// Read the size of a PNG image from its header.
+///
+/// Images are never decoded, so every other format measures as empty.
+fn png_size(data: &[u8]) -> Option<(u32, u32)> {
+    const SIGNATURE: &[u8] = &[0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a];
+
+    if !data.starts_with(SIGNATURE) || data.get(12..16)? != b"IHDR" {
+        return None;
+    }
+
+    let width = u32::from_be_bytes(data.get(16..20)?.try_into().ok()?);
+    let height = u32::from_be_bytes(data.get(20..24)?.try_into().ok()?);
+
+    Some((width, height))
+}
@@ -0,0 +1,62 @@
+use std::rc::Rc;
+
+use ori_native_core::{
+    KeyEvent, NativeWidget, Propagation,
+    native::{HasKeyHandler, NativeKeyHandler},
+};
+
+use crate::{Platform, Widget, WidgetKind};
+
+impl HasKeyHandler for Platform {
+    type KeyHandler = KeyHandler;
+}
+
+pub struct KeyHandler {
+    widget: Widget,
+}
+
+#[derive(Default)]
+#[allow(clippy::type_complexity)]
+pub(crate) struct KeyHandlerState {
+    pub(crate) on_key: Option<(
+        Rc<dyn Fn(KeyEvent)>,
+        Rc<dyn Fn() -> Option<Propagation>>,
+    )>,
+}
+
+impl NativeWidget<Platform> for KeyHandler {
+    fn widget(&self) -> &Widget {
+        &self.widget
+    }
+}
+
+impl NativeKeyHandler<Platform> for KeyHandler {
+    fn build(_platform: &mut Platform, contents: &Widget) -> Self {
+        let widget = Widget::new(
+            WidgetKind::KeyHandler,
+            KeyHandlerState::default(),
+        );
+        widget.set_child(contents);
+
+        Self { widget }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        self.widget.set_size(width, height);
+
+        if let Some(contents) = self.widget.child(0) {
+            contents.set_bounds(0.0, 0.0, width, height);
+        }
+    }
+
+    fn set_on_key(
+        &mut self,
+        on_key: impl Fn(KeyEvent) + 'static,
+        propagation: impl Fn() -> Option<Propagation> + 'static,
+    ) {
+        self.widget.state_mut::<KeyHandlerState>().on_key =
+            Some((Rc::new(on_key), Rc::new(propagation)));
+    }
+}
@@ -0,0 +1,36 @@
+mod context_menu;
+mod drag;
+mod dropdown;
+mod file_drop;
+mod group;
+mod image;
+mod key_handler;
+mod pointer_handler;
+mod popover;
+mod pressable;
+mod progress;
+mod scroll;
+mod shortcuts;
+mod slider;
+mod text;
+mod textinput;
+mod toggle;
+mod window;
+
+pub(crate) use context_menu::ContextMenuState;
+pub(crate) use drag::{DragGestureState, DragSourceState, DropTargetState};
+pub(crate) use dropdown::DropdownState;
+pub(crate) use file_drop::FileDropState;
+pub(crate) use group::GroupState;
+pub(crate) use key_handler::KeyHandlerState;
+pub(crate) use pointer_handler::PointerHandlerState;
+pub(crate) use popover::PopoverState;
+pub(crate) use pressable::PressableState;
+pub(crate) use scroll::ScrollState;
+pub(crate) use shortcuts::ShortcutsState;
+pub(crate) use slider::SliderState;
+pub(crate) use text::TextState;
+pub(crate) use textinput::{TextInputState, truncate};
+pub(crate) use toggle::ToggleState;
+pub use window::Window;
+pub(crate) use window::WindowState;
@@ -0,0 +1,53 @@
+use std::rc::Rc;
+
+use ori_native_core::{
+    NativeWidget, PointerEvent,
+    native::{HasPointerHandler, NativePointerHandler},
+};
+
+use crate::{Platform, Widget, WidgetKind};
+
+impl HasPointerHandler for Platform {
+    type PointerHandler = PointerHandler;
+}
+
+pub struct PointerHandler {
+    widget: Widget,
+}
+
+#[derive(Default)]
+pub(crate) struct PointerHandlerState {
+    pub(crate) on_pointer: Option<Rc<dyn Fn(PointerEvent)>>,
+}
+
+impl NativeWidget<Platform> for PointerHandler {
+    fn widget(&self) -> &Widget {
+        &self.widget
+    }
+}
+
+impl NativePointerHandler<Platform> for PointerHandler {
+    fn build(_platform: &mut Platform, contents: &Widget) -> Self {
+        let widget = Widget::new(
+            WidgetKind::PointerHandler,
+            PointerHandlerState::default(),
+        );
+        widget.set_child(contents);
+
+        Self { widget }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        self.widget.set_size(width, height);
+
+        if let Some(contents) = self.widget.child(0) {
+            contents.set_bounds(0.0, 0.0, width, height);
+        }
+    }
+
+    fn set_on_pointer(&mut self, on_pointer: impl Fn(PointerEvent) + 'static) {
+        self.widget.state_mut::<PointerHandlerState>().on_pointer = Some(Rc::new(on_pointer));
+    }
+}
@@ -0,0 +1,103 @@
+use std::rc::Rc;
+
+use ori_native_core::{
+    NativeParent, NativeWidget,
+    native::{HasPopover, NativePopover, Placement},
+};
+
+use crate::{Platform, Widget, WidgetKind};
+
+impl HasPopover for Platform {
+    type Popover = Popover;
+}
+
+/// A popover, the anchor is child `0` and the contents are child `1`.
+pub struct Popover {
+    widget: Widget,
+}
+
+pub(crate) struct PopoverState {
+    pub(crate) open:       bool,
+    pub(crate) placement:  Placement,
+    pub(crate) flip:       bool,
+    pub(crate) on_dismiss: Option<Rc<dyn Fn()>>,
+}
+
+impl Widget {
+    /// Get whether a popover is shown, a popover that is opened while it isn't mapped is only
+    /// shown once it is, see [`Widget::is_mapped`].
+    pub fn is_open(&self) -> bool {
+        self.state::<PopoverState>().open && self.is_mapped()
+    }
+
+    pub fn placement(&self) -> Placement {
+        self.state::<PopoverState>().placement
+    }
+
+    pub fn flip(&self) -> bool {
+        self.state::<PopoverState>().flip
+    }
+}
+
+impl NativeWidget<Platform> for Popover {
+    fn widget(&self) -> &Widget {
+        &self.widget
+    }
+}
+
+impl NativeParent<Platform> for Popover {
+    fn replace_child(&mut self, _platform: &mut Platform, index: usize, child: &Widget) {
+        debug_assert!(index < 2);
+
+        self.widget.replace_child(index, child);
+    }
+}
+
+impl NativePopover<Platform> for Popover {
+    fn build(_platform: &mut Platform, anchor: &Widget, contents: &Widget) -> Self {
+        let state = PopoverState {
+            open:       false,
+            placement:  Placement::Below,
+            flip:       true,
+            on_dismiss: None,
+        };
+
+        let widget = Widget::new(WidgetKind::Popover, state);
+        widget.insert_child(0, anchor);
+        widget.insert_child(1, contents);
+
+        Self { widget }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        self.widget.set_size(width, height);
+    }
+
+    fn set_anchor_layout(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        if let Some(anchor) = self.widget.child(0) {
+            anchor.set_bounds(x, y, width, height);
+        }
+    }
+
+    fn set_contents_size(&mut self, width: f32, height: f32) {
+        if let Some(contents) = self.widget.child(1) {
+            contents.set_bounds(0.0, 0.0, width, height);
+        }
+    }
+
+    fn set_placement(&mut self, placement: Placement, flip: bool) {
+        let mut state = self.widget.state_mut::<PopoverState>();
+        state.placement = placement;
+        state.flip = flip;
+    }
+
+    fn set_open(&mut self, open: bool) {
+        self.widget.state_mut::<PopoverState>().open = open;
+    }
+
+    fn set_on_dismiss(&mut self, on_dismiss: impl Fn() + 'static) {
+        self.widget.state_mut::<PopoverState>().on_dismiss = Some(Rc::new(on_dismiss));
+    }
+}
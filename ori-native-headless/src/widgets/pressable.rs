@@ -0,0 +1,63 @@
+use std::rc::Rc;
+
+use ori_native_core::{
+    NativeWidget,
+    native::{HasPressable, NativePressable, Press},
+};
+
+use crate::{Platform, Widget, WidgetKind};
+
+impl HasPressable for Platform {
+    type Pressable = Pressable;
+}
+
+pub struct Pressable {
+    widget: Widget,
+}
+
+#[derive(Default)]
+pub(crate) struct PressableState {
+    pub(crate) on_press: Option<Rc<dyn Fn(Press)>>,
+    pub(crate) on_hover: Option<Rc<dyn Fn(bool)>>,
+    pub(crate) on_focus: Option<Rc<dyn Fn(bool)>>,
+}
+
+impl NativeWidget<Platform> for Pressable {
+    fn widget(&self) -> &Widget {
+        &self.widget
+    }
+}
+
+impl NativePressable<Platform> for Pressable {
+    fn build(_plaform: &mut Platform, contents: &Widget) -> Self {
+        let widget = Widget::new(
+            WidgetKind::Pressable,
+            PressableState::default(),
+        );
+        widget.insert_child(0, contents);
+
+        Self { widget }
+    }
+
+    fn teardown(self, _plaform: &mut Platform) {}
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        self.widget.set_size(width, height);
+
+        if let Some(contents) = self.widget.child(0) {
+            contents.set_bounds(0.0, 0.0, width, height);
+        }
+    }
+
+    fn set_on_press(&mut self, on_press: impl Fn(Press) + 'static) {
+        self.widget.state_mut::<PressableState>().on_press = Some(Rc::new(on_press));
+    }
+
+    fn set_on_hover(&mut self, on_hover: impl Fn(bool) + 'static) {
+        self.widget.state_mut::<PressableState>().on_hover = Some(Rc::new(on_hover));
+    }
+
+    fn set_on_focus(&mut self, on_focus: impl Fn(bool) + 'static) {
+        self.widget.state_mut::<PressableState>().on_focus = Some(Rc::new(on_focus));
+    }
+}
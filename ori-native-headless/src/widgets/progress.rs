@@ -0,0 +1,128 @@
+use ori_native_core::{
+    LayoutLeaf, NativeWidget,
+    native::{HasProgress, HasSpinner, NativeProgress, NativeSpinner},
+};
+
+use crate::{Platform, Widget, WidgetKind};
+
+/// The width of a progress bar, unless set by its layout.
+const PROGRESS_WIDTH: f32 = 128.0;
+
+/// The height of a progress bar, unless set by its layout.
+const PROGRESS_HEIGHT: f32 = 8.0;
+
+/// The size of a spinner, unless set by its layout.
+const SPINNER_SIZE: f32 = 16.0;
+
+impl HasProgress for Platform {
+    type Progress = Progress;
+}
+
+pub struct Progress {
+    widget: Widget,
+}
+
+pub(crate) struct ProgressState {
+    pub(crate) fraction: f32,
+}
+
+impl Widget {
+    /// Get the fraction shown by a progress bar.
+    pub fn fraction(&self) -> f32 {
+        self.state::<ProgressState>().fraction
+    }
+
+    /// Get whether a spinner is spinning.
+    pub fn is_spinning(&self) -> bool {
+        self.state::<SpinnerState>().spinning
+    }
+}
+
+impl NativeWidget<Platform> for Progress {
+    fn widget(&self) -> &Widget {
+        &self.widget
+    }
+}
+
+impl NativeProgress<Platform> for Progress {
+    fn build(_platform: &mut Platform) -> Self {
+        let state = ProgressState { fraction: 0.0 };
+
+        Self {
+            widget: Widget::new(WidgetKind::Progress, state),
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_fraction(&mut self, fraction: f32) {
+        self.widget.state_mut::<ProgressState>().fraction = fraction;
+    }
+
+    fn get_layout(&mut self, _platform: &mut Platform) -> impl LayoutLeaf<Platform> {
+        Layout {
+            width:  PROGRESS_WIDTH,
+            height: PROGRESS_HEIGHT,
+        }
+    }
+}
+
+impl HasSpinner for Platform {
+    type Spinner = Spinner;
+}
+
+pub struct Spinner {
+    widget: Widget,
+}
+
+pub(crate) struct SpinnerState {
+    pub(crate) spinning: bool,
+}
+
+impl NativeWidget<Platform> for Spinner {
+    fn widget(&self) -> &Widget {
+        &self.widget
+    }
+}
+
+impl NativeSpinner<Platform> for Spinner {
+    fn build(_platform: &mut Platform) -> Self {
+        let state = SpinnerState { spinning: false };
+
+        Self {
+            widget: Widget::new(WidgetKind::Spinner, state),
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_spinning(&mut self, spinning: bool) {
+        self.widget.state_mut::<SpinnerState>().spinning = spinning;
+    }
+
+    fn get_layout(&mut self, _platform: &mut Platform) -> impl LayoutLeaf<Platform> {
+        Layout {
+            width:  SPINNER_SIZE,
+            height: SPINNER_SIZE,
+        }
+    }
+}
+
+struct Layout {
+    width:  f32,
+    height: f32,
+}
+
+impl LayoutLeaf<Platform> for Layout {
+    fn measure(
+        &mut self,
+        _platform: &mut Platform,
+        known_size: taffy::Size<Option<f32>>,
+        _available_space: taffy::Size<taffy::AvailableSpace>,
+    ) -> taffy::Size<f32> {
+        taffy::Size {
+            width:  known_size.width.unwrap_or(self.width),
+            height: known_size.height.unwrap_or(self.height),
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use std::rc::Rc;
+
+use ori_native_core::{
+    NativeParent, NativeWidget, ScrollEvent,
+    native::{HasScroll, NativeScroll, ScrollbarPolicy},
+};
+
+use crate::{Platform, Widget, WidgetKind};
+
+impl HasScroll for Platform {
+    type Scroll = Scroll;
+}
+
+pub struct Scroll {
+    widget: Widget,
+}
+
+pub(crate) struct ScrollState {
+    pub(crate) offset:     (f32, f32),
+    pub(crate) scrollbars: (ScrollbarPolicy, ScrollbarPolicy),
+    pub(crate) kinetic:    bool,
+    pub(crate) on_scroll:  Option<Rc<dyn Fn(ScrollEvent)>>,
+}
+
+impl Widget {
+    /// Get the scroll offset of a scroll.
+    pub fn scroll_offset(&self) -> (f32, f32) {
+        self.state::<ScrollState>().offset
+    }
+
+    /// Get the horizontal and vertical scrollbar policies of a scroll.
+    pub fn scrollbars(&self) -> (ScrollbarPolicy, ScrollbarPolicy) {
+        self.state::<ScrollState>().scrollbars
+    }
+
+    pub fn kinetic(&self) -> bool {
+        self.state::<ScrollState>().kinetic
+    }
+
+    /// Get the scroll position of a scroll, with the size of its first child as the size of
+    /// the contents.
+    pub fn scroll_event(&self) -> ScrollEvent {
+        let (content_width, content_height) = match self.child(0) {
+            Some(contents) => contents.size(),
+            None => (0.0, 0.0),
+        };
+
+        let (offset_x, offset_y) = self.scroll_offset();
+        let (viewport_width, viewport_height) = self.size();
+
+        ScrollEvent {
+            offset_x,
+            offset_y,
+            viewport_width,
+            viewport_height,
+            content_width,
+            content_height,
+        }
+    }
+}
+
+impl NativeWidget<Platform> for Scroll {
+    fn widget(&self) -> &Widget {
+        &self.widget
+    }
+}
+
+impl NativeParent<Platform> for Scroll {
+    fn replace_child(&mut self, _platform: &mut Platform, index: usize, child: &Widget) {
+        debug_assert_eq!(index, 0);
+
+        self.widget.set_child(child);
+    }
+}
+
+impl NativeScroll<Platform> for Scroll {
+    fn build(_platform: &mut Platform, contents: &Widget) -> Self {
+        let state = ScrollState {
+            offset:     (0.0, 0.0),
+            scrollbars: (
+                ScrollbarPolicy::Auto,
+                ScrollbarPolicy::Auto,
+            ),
+            kinetic:    true,
+            on_scroll:  None,
+        };
+
+        let widget = Widget::new(WidgetKind::Scroll, state);
+        widget.set_child(contents);
+
+        Self { widget }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        self.widget.set_size(width, height);
+    }
+
+    fn set_scrollbars(&mut self, horizontal: ScrollbarPolicy, vertical: ScrollbarPolicy) {
+        self.widget.state_mut::<ScrollState>().scrollbars = (horizontal, vertical);
+    }
+
+    fn set_kinetic(&mut self, kinetic: bool) {
+        self.widget.state_mut::<ScrollState>().kinetic = kinetic;
+    }
+
+    fn set_offset(&mut self, x: f32, y: f32) {
+        self.widget.state_mut::<ScrollState>().offset = (x, y);
+    }
+
+    fn scroll_to_end(&mut self) {
+        let event = self.widget.scroll_event();
+        self.widget.state_mut::<ScrollState>().offset = (
+            event.max_offset_x(),
+            event.max_offset_y(),
+        );
+    }
+
+    fn scroll_into_view(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        let event = self.widget.scroll_event();
+
+        let x = into_view(
+            event.offset_x,
+            event.viewport_width,
+            x,
+            width,
+        );
+        let y = into_view(
+            event.offset_y,
+            event.viewport_height,
+            y,
+            height,
+        );
+
+        self.widget.state_mut::<ScrollState>().offset = (x, y);
+    }
+
+    fn set_on_scroll(&mut self, on_scroll: impl Fn(ScrollEvent) + 'static) {
+        self.widget.state_mut::<ScrollState>().on_scroll = Some(Rc::new(on_scroll));
+    }
+}
+
+fn into_view(offset: f32, viewport: f32, start: f32, length: f32) -> f32 {
+    if start < offset || length > viewport {
+        start
+    } else if start + length > offset + viewport {
+        start + length - viewport
+    } else {
+        offset
+    }
+}
@@ -0,0 +1,73 @@
+use std::rc::Rc;
+
+use ori_native_core::{
+    Accelerator, NativeWidget,
+    native::{HasShortcuts, NativeShortcuts, ShortcutScope},
+};
+
+use crate::{Platform, Widget, WidgetKind};
+
+impl HasShortcuts for Platform {
+    type Shortcuts = Shortcuts;
+}
+
+pub struct Shortcuts {
+    widget: Widget,
+}
+
+pub(crate) struct ShortcutsState {
+    pub(crate) accelerators: Vec<Accelerator>,
+    pub(crate) scope:        ShortcutScope,
+    pub(crate) on_shortcut:  Option<Rc<dyn Fn(usize)>>,
+}
+
+impl Widget {
+    pub fn accelerators(&self) -> Vec<Accelerator> {
+        self.state::<ShortcutsState>().accelerators.clone()
+    }
+
+    pub fn shortcut_scope(&self) -> ShortcutScope {
+        self.state::<ShortcutsState>().scope
+    }
+}
+
+impl NativeWidget<Platform> for Shortcuts {
+    fn widget(&self) -> &Widget {
+        &self.widget
+    }
+}
+
+impl NativeShortcuts<Platform> for Shortcuts {
+    fn build(_platform: &mut Platform, contents: &Widget) -> Self {
+        let state = ShortcutsState {
+            accelerators: Vec::new(),
+            scope:        ShortcutScope::Focus,
+            on_shortcut:  None,
+        };
+
+        let widget = Widget::new(WidgetKind::Shortcuts, state);
+        widget.set_child(contents);
+
+        Self { widget }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        self.widget.set_size(width, height);
+
+        if let Some(contents) = self.widget.child(0) {
+            contents.set_bounds(0.0, 0.0, width, height);
+        }
+    }
+
+    fn set_shortcuts(&mut self, accelerators: &[Accelerator], scope: ShortcutScope) {
+        let mut state = self.widget.state_mut::<ShortcutsState>();
+        state.accelerators = accelerators.to_vec();
+        state.scope = scope;
+    }
+
+    fn set_on_shortcut(&mut self, on_shortcut: impl Fn(usize) + 'static) {
+        self.widget.state_mut::<ShortcutsState>().on_shortcut = Some(Rc::new(on_shortcut));
+    }
+}
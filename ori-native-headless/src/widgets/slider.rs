@@ -0,0 +1,145 @@
+use std::rc::Rc;
+
+use ori_native_core::{
+    Direction, LayoutLeaf, NativeWidget,
+    native::{HasSlider, NativeSlider},
+    views::SliderMark,
+};
+
+use crate::{Platform, Widget, WidgetKind};
+
+/// The size of a slider across its direction.
+const THICKNESS: f32 = 16.0;
+
+/// The size of a slider along its direction.
+const LENGTH: f32 = 128.0;
+
+impl HasSlider for Platform {
+    type Slider = Slider;
+}
+
+pub struct Slider {
+    widget: Widget,
+}
+
+pub(crate) struct SliderState {
+    pub(crate) value:     f32,
+    pub(crate) range:     (f32, f32),
+    pub(crate) step:      Option<f32>,
+    pub(crate) direction: Direction,
+    pub(crate) marks:     Vec<SliderMark>,
+    pub(crate) on_change: Option<Rc<dyn Fn(f32)>>,
+    pub(crate) on_commit: Option<Rc<dyn Fn(f32)>>,
+}
+
+impl Widget {
+    /// Get the value of a slider.
+    pub fn value(&self) -> f32 {
+        self.state::<SliderState>().value
+    }
+
+    /// Get the range of a slider, as `(min, max)`.
+    pub fn range(&self) -> (f32, f32) {
+        self.state::<SliderState>().range
+    }
+
+    /// Get the step of a slider.
+    pub fn step(&self) -> Option<f32> {
+        self.state::<SliderState>().step
+    }
+
+    /// Get the direction of a slider.
+    pub fn direction(&self) -> Direction {
+        self.state::<SliderState>().direction
+    }
+
+    /// Get the marks of a slider.
+    pub fn marks(&self) -> Vec<SliderMark> {
+        self.state::<SliderState>().marks.clone()
+    }
+}
+
+impl NativeWidget<Platform> for Slider {
+    fn widget(&self) -> &Widget {
+        &self.widget
+    }
+}
+
+impl NativeSlider<Platform> for Slider {
+    fn build(_platform: &mut Platform, direction: Direction) -> Self {
+        let state = SliderState {
+            value: 0.0,
+            range: (0.0, 1.0),
+            step: None,
+            direction,
+            marks: Vec::new(),
+            on_change: None,
+            on_commit: None,
+        };
+
+        Self {
+            widget: Widget::new(WidgetKind::Slider, state),
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_range(&mut self, min: f32, max: f32) {
+        let mut state = self.widget.state_mut::<SliderState>();
+
+        state.range = (min, max.max(min));
+        state.value = state.value.clamp(state.range.0, state.range.1);
+    }
+
+    fn set_step(&mut self, step: Option<f32>) {
+        self.widget.state_mut::<SliderState>().step = step;
+    }
+
+    fn set_value(&mut self, value: f32) {
+        let mut state = self.widget.state_mut::<SliderState>();
+        state.value = value.clamp(state.range.0, state.range.1);
+    }
+
+    fn set_marks(&mut self, marks: &[SliderMark]) {
+        self.widget.state_mut::<SliderState>().marks = marks.to_vec();
+    }
+
+    fn set_on_change(&mut self, on_change: impl Fn(f32) + 'static) {
+        self.widget.state_mut::<SliderState>().on_change = Some(Rc::new(on_change));
+    }
+
+    fn set_on_commit(&mut self, on_commit: impl Fn(f32) + 'static) {
+        self.widget.state_mut::<SliderState>().on_commit = Some(Rc::new(on_commit));
+    }
+
+    fn get_layout(&mut self, _platform: &mut Platform) -> impl LayoutLeaf<Platform> {
+        Layout {
+            direction: self.widget.direction(),
+        }
+    }
+}
+
+struct Layout {
+    direction: Direction,
+}
+
+impl LayoutLeaf<Platform> for Layout {
+    fn measure(
+        &mut self,
+        _platform: &mut Platform,
+        _known_size: taffy::Size<Option<f32>>,
+        _available_space: taffy::Size<taffy::AvailableSpace>,
+    ) -> taffy::Size<f32> {
+        match self.direction {
+            Direction::Horizontal => taffy::Size {
+                width:  LENGTH,
+                height: THICKNESS,
+            },
+
+            Direction::Vertical => taffy::Size {
+                width:  THICKNESS,
+                height: LENGTH,
+            },
+        }
+    }
+}
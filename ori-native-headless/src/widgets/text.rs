@@ -0,0 +1,190 @@
+use ori_native_core::{
+    Font, LayoutLeaf, NativeWidget, TextSpan, Wrap,
+    native::{HasText, NativeText},
+};
+
+use crate::{Platform, Widget, WidgetKind};
+
+/// The width of a character, relative to the font size.
+const CHAR_WIDTH: f32 = 0.5;
+
+/// The height of a line, relative to the font size.
+const LINE_HEIGHT: f32 = 1.25;
+
+impl HasText for Platform {
+    type Text = Text;
+}
+
+pub struct Text {
+    widget: Widget,
+}
+
+pub(crate) struct TextState {
+    pub(crate) text: String,
+    pub(crate) font: Font,
+    pub(crate) wrap: Wrap,
+}
+
+impl Widget {
+    pub fn wrap(&self) -> Wrap {
+        self.state::<TextState>().wrap
+    }
+}
+
+impl NativeWidget<Platform> for Text {
+    fn widget(&self) -> &Widget {
+        &self.widget
+    }
+}
+
+impl NativeText<Platform> for Text {
+    type Layout = TextLayout;
+
+    fn build(
+        _platform: &mut Platform,
+        spans: Box<[TextSpan]>,
+        text: String,
+        wrap: Wrap,
+    ) -> (Self, Self::Layout) {
+        let state = TextState {
+            text: String::new(),
+            font: Font::default(),
+            wrap: Wrap::None,
+        };
+
+        let mut this = Self {
+            widget: Widget::new(WidgetKind::Text, state),
+        };
+
+        let leaf = this.set_text(spans, text, wrap);
+
+        (this, leaf)
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_text(&mut self, spans: Box<[TextSpan]>, text: String, wrap: Wrap) -> Self::Layout {
+        let font = spans
+            .first()
+            .map_or_else(Font::default, |span| span.font.clone());
+
+        let mut state = self.widget.state_mut::<TextState>();
+        state.text = text.clone();
+        state.font = font.clone();
+        state.wrap = wrap;
+
+        TextLayout { font, text, wrap }
+    }
+}
+
+/// Text layout with fixed metrics, every character is [`CHAR_WIDTH`] font sizes wide and
+/// every line is [`LINE_HEIGHT`] font sizes tall.
+///
+/// This keeps measurements identical across machines, regardless of installed fonts.
+pub struct TextLayout {
+    font: Font,
+    text: String,
+    wrap: Wrap,
+}
+
+impl LayoutLeaf<Platform> for TextLayout {
+    fn measure(
+        &mut self,
+        _platform: &mut Platform,
+        known_size: taffy::Size<Option<f32>>,
+        available_space: taffy::Size<taffy::AvailableSpace>,
+    ) -> taffy::Size<f32> {
+        let max_width = match (self.wrap, available_space.width) {
+            (Wrap::None, _) => None,
+            (_, taffy::AvailableSpace::MinContent) => Some(0.0),
+            (_, taffy::AvailableSpace::MaxContent) => None,
+            (_, taffy::AvailableSpace::Definite(width)) => Some(width),
+        };
+
+        let (width, height) = measure_text(
+            &self.font,
+            &self.text,
+            self.wrap,
+            known_size.width.or(max_width),
+        );
+
+        taffy::Size {
+            width:  known_size.width.unwrap_or(width),
+            height: known_size.height.unwrap_or(height),
+        }
+    }
+}
+
+pub(super) fn line_height(font: &Font) -> f32 {
+    font.size * LINE_HEIGHT
+}
+
+pub(super) fn measure_text(
+    font: &Font,
+    text: &str,
+    wrap: Wrap,
+    max_width: Option<f32>,
+) -> (f32, f32) {
+    let char_width = font.size * CHAR_WIDTH;
+
+    let max_chars = match max_width {
+        Some(width) if !matches!(wrap, Wrap::None) => {
+            Some(((width / char_width).floor() as usize).max(1))
+        }
+        _ => None,
+    };
+
+    let mut columns = 0;
+    let mut lines = 0;
+
+    for line in text.split('\n') {
+        for (line_columns, line_count) in wrap_line(line, wrap, max_chars) {
+            columns = usize::max(columns, line_columns);
+            lines += line_count;
+        }
+    }
+
+    (
+        columns as f32 * char_width,
+        lines.max(1) as f32 * line_height(font),
+    )
+}
+
+/// Wrap a single line, yielding the widths, in characters, of the resulting lines.
+fn wrap_line(line: &str, wrap: Wrap, max_chars: Option<usize>) -> Vec<(usize, usize)> {
+    let length = line.chars().count();
+
+    let Some(max_chars) = max_chars else {
+        return vec![(length, 1)];
+    };
+
+    match wrap {
+        Wrap::None => vec![(length, 1)],
+
+        Wrap::Char => {
+            let lines = length.div_ceil(max_chars).max(1);
+            vec![(usize::min(length, max_chars), lines)]
+        }
+
+        Wrap::Word => {
+            let mut lines = Vec::new();
+            let mut current = 0;
+
+            for word in line.split(' ') {
+                let word = word.chars().count();
+
+                if current > 0 && current + 1 + word > max_chars {
+                    lines.push((current, 1));
+                    current = word;
+                } else if current > 0 {
+                    current += 1 + word;
+                } else {
+                    current = word;
+                }
+            }
+
+            lines.push((current, 1));
+            lines
+        }
+    }
+}
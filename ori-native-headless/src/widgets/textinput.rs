@@ -0,0 +1,182 @@
+use std::rc::Rc;
+
+use ori_native_core::{
+    Font, LayoutLeaf, NativeWidget,
+    native::{HasTextInput, NativeTextInput},
+    views::{InputMode, InputPurpose, Newline},
+};
+
+use crate::{Platform, Widget, WidgetKind};
+
+impl HasTextInput for Platform {
+    type TextInput = TextInput;
+}
+
+pub struct TextInput {
+    widget: Widget,
+}
+
+pub(crate) struct TextInputState {
+    pub(crate) text:             String,
+    pub(crate) font:             Font,
+    pub(crate) placeholder:      String,
+    pub(crate) placeholder_font: Font,
+    pub(crate) newline:          Newline,
+    pub(crate) accept_tab:       bool,
+    pub(crate) input_mode:       InputMode,
+    pub(crate) max_length:       Option<usize>,
+    pub(crate) purpose:          InputPurpose,
+    pub(crate) editable:         bool,
+    pub(crate) on_change:        Option<Rc<dyn Fn(String)>>,
+    pub(crate) on_submit:        Option<Rc<dyn Fn(String)>>,
+}
+
+impl Widget {
+    pub fn placeholder(&self) -> String {
+        self.state::<TextInputState>().placeholder.clone()
+    }
+
+    pub fn placeholder_font(&self) -> Font {
+        self.state::<TextInputState>().placeholder_font.clone()
+    }
+
+    pub fn newline(&self) -> Newline {
+        self.state::<TextInputState>().newline
+    }
+
+    pub fn accept_tab(&self) -> bool {
+        self.state::<TextInputState>().accept_tab
+    }
+
+    pub fn input_mode(&self) -> InputMode {
+        self.state::<TextInputState>().input_mode
+    }
+
+    pub fn max_length(&self) -> Option<usize> {
+        self.state::<TextInputState>().max_length
+    }
+
+    pub fn purpose(&self) -> InputPurpose {
+        self.state::<TextInputState>().purpose
+    }
+
+    pub fn is_editable(&self) -> bool {
+        self.state::<TextInputState>().editable
+    }
+}
+
+impl NativeWidget<Platform> for TextInput {
+    fn widget(&self) -> &Widget {
+        &self.widget
+    }
+}
+
+impl NativeTextInput<Platform> for TextInput {
+    fn build(_platform: &mut Platform, mode: InputMode) -> Self {
+        let state = TextInputState {
+            text:             String::new(),
+            font:             Font::default(),
+            placeholder:      String::new(),
+            placeholder_font: Font::default(),
+            newline:          Newline::Enter,
+            accept_tab:       true,
+            input_mode:       mode,
+            max_length:       None,
+            purpose:          InputPurpose::Text,
+            editable:         true,
+            on_change:        None,
+            on_submit:        None,
+        };
+
+        Self {
+            widget: Widget::new(WidgetKind::TextInput, state),
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_on_change(&mut self, _platform: &mut Platform, on_change: impl Fn(String) + 'static) {
+        self.widget.state_mut::<TextInputState>().on_change = Some(Rc::new(on_change));
+    }
+
+    fn set_on_submit(&mut self, _platform: &mut Platform, on_submit: impl Fn(String) + 'static) {
+        self.widget.state_mut::<TextInputState>().on_submit = Some(Rc::new(on_submit));
+    }
+
+    fn set_newline(&mut self, _platform: &mut Platform, newline: Newline) {
+        self.widget.state_mut::<TextInputState>().newline = newline;
+    }
+
+    fn set_accept_tab(&mut self, _platform: &mut Platform, accept_tab: bool) {
+        self.widget.state_mut::<TextInputState>().accept_tab = accept_tab;
+    }
+
+    fn set_max_length(&mut self, _platform: &mut Platform, max_length: Option<usize>) {
+        self.widget.state_mut::<TextInputState>().max_length = max_length;
+    }
+
+    fn set_purpose(&mut self, _platform: &mut Platform, purpose: InputPurpose) {
+        self.widget.state_mut::<TextInputState>().purpose = purpose;
+    }
+
+    fn set_editable(&mut self, _platform: &mut Platform, editable: bool) {
+        self.widget.state_mut::<TextInputState>().editable = editable;
+    }
+
+    fn set_font(&mut self, _platform: &mut Platform, font: Font) {
+        self.widget.state_mut::<TextInputState>().font = font;
+    }
+
+    fn set_text(&mut self, _platform: &mut Platform, text: String) {
+        let mut state = self.widget.state_mut::<TextInputState>();
+        state.text = truncate(text, state.max_length);
+    }
+
+    fn set_placeholder_font(&mut self, _platform: &mut Platform, font: Font) {
+        self.widget.state_mut::<TextInputState>().placeholder_font = font;
+    }
+
+    fn set_placeholder_text(&mut self, _platform: &mut Platform, text: String) {
+        self.widget.state_mut::<TextInputState>().placeholder = text;
+    }
+
+    fn get_layout(&mut self, _platform: &mut Platform) -> impl LayoutLeaf<Platform> {
+        let state = self.widget.state::<TextInputState>();
+
+        Layout {
+            font:             state.font.clone(),
+            placeholder_font: state.placeholder_font.clone(),
+        }
+    }
+}
+
+struct Layout {
+    font:             Font,
+    placeholder_font: Font,
+}
+
+impl LayoutLeaf<Platform> for Layout {
+    fn measure(
+        &mut self,
+        _platform: &mut Platform,
+        _known_size: taffy::Size<Option<f32>>,
+        _available_space: taffy::Size<taffy::AvailableSpace>,
+    ) -> taffy::Size<f32> {
+        let theight = super::text::line_height(&self.font);
+        let pheight = super::text::line_height(&self.placeholder_font);
+
+        taffy::Size {
+            width:  0.0,
+            height: theight.max(pheight).ceil(),
+        }
+    }
+}
+
+/// Cut `text` short at `max_length` characters, like native inputs do.
+pub(crate) fn truncate(mut text: String, max_length: Option<usize>) -> String {
+    if let Some((index, _)) = max_length.and_then(|max| text.char_indices().nth(max)) {
+        text.truncate(index);
+    }
+
+    text
+}
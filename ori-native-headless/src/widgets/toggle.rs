@@ -0,0 +1,154 @@
+use std::{
+    cell::RefCell,
+    rc::{Rc, Weak},
+};
+
+use ori_native_core::{
+    Font, LayoutLeaf, NativeWidget, Wrap,
+    native::{HasToggle, NativeToggle, ToggleKind},
+    views::CheckState,
+};
+
+use crate::{Platform, Widget, WidgetKind, widget::WidgetData};
+
+/// The size of the indicator of a checkbox or radio.
+const INDICATOR_SIZE: f32 = 16.0;
+
+/// The width of a switch, its height is the size of an indicator.
+const SWITCH_WIDTH: f32 = 40.0;
+
+/// The space between the indicator and the label.
+const SPACING: f32 = 8.0;
+
+impl HasToggle for Platform {
+    type Toggle = Toggle;
+}
+
+pub struct Toggle {
+    widget: Widget,
+}
+
+pub(crate) struct ToggleState {
+    pub(crate) checked:   CheckState,
+    pub(crate) group:     Weak<RefCell<WidgetData>>,
+    pub(crate) label:     String,
+    pub(crate) on_toggle: Option<Rc<dyn Fn(bool)>>,
+}
+
+impl Widget {
+    /// Get the checked state of a checkbox, switch or radio.
+    pub fn checked(&self) -> CheckState {
+        self.state::<ToggleState>().checked
+    }
+
+    /// Get the radios in the group of a radio, including itself.
+    pub fn radio_group(&self) -> Vec<Widget> {
+        let leader = self.group_leader();
+
+        let Some(parent) = self.parent() else {
+            return vec![self.clone()];
+        };
+
+        parent
+            .children()
+            .into_iter()
+            .filter(|radio| radio.kind() == WidgetKind::Radio && radio.group_leader() == leader)
+            .collect()
+    }
+
+    /// Get the first radio of the group of a radio, radios without a group lead their own.
+    fn group_leader(&self) -> Widget {
+        let group = Widget::upgrade(&self.state::<ToggleState>().group);
+        group.unwrap_or_else(|| self.clone())
+    }
+}
+
+impl NativeWidget<Platform> for Toggle {
+    fn widget(&self) -> &Widget {
+        &self.widget
+    }
+}
+
+impl NativeToggle<Platform> for Toggle {
+    fn build(_platform: &mut Platform, kind: ToggleKind) -> Self {
+        let kind = match kind {
+            ToggleKind::Checkbox => WidgetKind::Checkbox,
+            ToggleKind::Switch => WidgetKind::Switch,
+            ToggleKind::Radio => WidgetKind::Radio,
+        };
+
+        let state = ToggleState {
+            checked:   CheckState::Unchecked,
+            group:     Weak::new(),
+            label:     String::new(),
+            on_toggle: None,
+        };
+
+        Self {
+            widget: Widget::new(kind, state),
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_group(&mut self, leader: &Self) {
+        self.widget.state_mut::<ToggleState>().group = leader.widget.downgrade();
+    }
+
+    fn set_checked(&mut self, checked: CheckState) {
+        self.widget.state_mut::<ToggleState>().checked = checked;
+    }
+
+    fn set_label(&mut self, label: String) {
+        self.widget.state_mut::<ToggleState>().label = label;
+    }
+
+    fn set_on_toggle(&mut self, on_toggle: impl Fn(bool) + 'static) {
+        self.widget.state_mut::<ToggleState>().on_toggle = Some(Rc::new(on_toggle));
+    }
+
+    fn get_layout(&mut self, _platform: &mut Platform) -> impl LayoutLeaf<Platform> {
+        let indicator = match self.widget.kind() {
+            WidgetKind::Switch => SWITCH_WIDTH,
+            _ => INDICATOR_SIZE,
+        };
+
+        Layout {
+            indicator,
+            label: self.widget.text(),
+        }
+    }
+}
+
+struct Layout {
+    indicator: f32,
+    label:     String,
+}
+
+impl LayoutLeaf<Platform> for Layout {
+    fn measure(
+        &mut self,
+        _platform: &mut Platform,
+        _known_size: taffy::Size<Option<f32>>,
+        _available_space: taffy::Size<taffy::AvailableSpace>,
+    ) -> taffy::Size<f32> {
+        if self.label.is_empty() {
+            return taffy::Size {
+                width:  self.indicator,
+                height: INDICATOR_SIZE,
+            };
+        }
+
+        let (width, height) = super::text::measure_text(
+            &Font::default(),
+            &self.label,
+            Wrap::None,
+            None,
+        );
+
+        taffy::Size {
+            width:  self.indicator + SPACING + width,
+            height: height.max(INDICATOR_SIZE),
+        }
+    }
+}
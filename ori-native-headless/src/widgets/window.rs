@@ -0,0 +1,333 @@
+use std::{
+    cell::RefCell,
+    rc::{Rc, Weak},
+    time::Duration,
+};
+
+use ori_native_core::{
+    MenuModel, NativeParent,
+    native::{HasDialog, HasWindow, NativeDialog, NativeWindow, Screenshot},
+};
+
+use crate::{Platform, Widget, WidgetKind, widget::WidgetData};
+
+/// The size of a window, until it is resized.
+const DEFAULT_SIZE: (u32, u32) = (800, 600);
+
+impl HasWindow for Platform {
+    type Window = Window;
+}
+
+impl HasDialog for Platform {
+    type Dialog = Window;
+}
+
+pub struct Window {
+    widget: Widget,
+}
+
+pub(crate) struct WindowState {
+    pub(crate) resizable:  bool,
+    pub(crate) min_width:  u32,
+    pub(crate) min_height: u32,
+    pub(crate) animating:  bool,
+
+    pub(crate) modal:         bool,
+    pub(crate) transient_for: Weak<RefCell<WidgetData>>,
+
+    pub(crate) name:       Option<String>,
+    pub(crate) title:      String,
+    pub(crate) icon_name:  Option<String>,
+    pub(crate) decorated:  bool,
+    pub(crate) menubar:    MenuModel,
+    pub(crate) maximized:  bool,
+    pub(crate) fullscreen: bool,
+    pub(crate) minimized:  bool,
+
+    pub(crate) on_resize:          Option<Rc<dyn Fn()>>,
+    pub(crate) on_close_requested: Option<Rc<dyn Fn()>>,
+    pub(crate) on_state_changed:   Option<Rc<dyn Fn()>>,
+    pub(crate) on_menu_select:     Option<Rc<dyn Fn(usize)>>,
+    pub(crate) on_animation_frame: Option<Rc<dyn Fn(Duration)>>,
+}
+
+impl Widget {
+    pub fn is_resizable(&self) -> bool {
+        self.state::<WindowState>().resizable
+    }
+
+    pub fn min_size(&self) -> (u32, u32) {
+        let state = self.state::<WindowState>();
+        (state.min_width, state.min_height)
+    }
+
+    pub fn is_animating(&self) -> bool {
+        self.state::<WindowState>().animating
+    }
+
+    pub fn is_modal(&self) -> bool {
+        self.state::<WindowState>().modal
+    }
+
+    /// Get the window this window is transient for, if it's a dialog.
+    pub fn transient_for(&self) -> Option<Widget> {
+        Widget::upgrade(&self.state::<WindowState>().transient_for)
+    }
+
+    /// Get the name of the window, that dialogs refer to it by.
+    pub fn name(&self) -> Option<String> {
+        self.state::<WindowState>().name.clone()
+    }
+
+    pub fn title(&self) -> String {
+        self.state::<WindowState>().title.clone()
+    }
+
+    pub fn icon_name(&self) -> Option<String> {
+        self.state::<WindowState>().icon_name.clone()
+    }
+
+    pub fn is_decorated(&self) -> bool {
+        self.state::<WindowState>().decorated
+    }
+
+    pub fn is_maximized(&self) -> bool {
+        self.state::<WindowState>().maximized
+    }
+
+    pub fn is_fullscreen(&self) -> bool {
+        self.state::<WindowState>().fullscreen
+    }
+
+    pub fn is_minimized(&self) -> bool {
+        self.state::<WindowState>().minimized
+    }
+}
+
+impl Window {
+    pub fn widget(&self) -> &Widget {
+        &self.widget
+    }
+
+    /// Notify the state changed handler, like a real window does when the state is changed
+    /// programmatically.
+    fn state_changed(&self) {
+        let on_state_changed = self.widget.state::<WindowState>().on_state_changed.clone();
+
+        if let Some(on_state_changed) = on_state_changed {
+            on_state_changed();
+        }
+    }
+}
+
+impl NativeParent<Platform> for Window {
+    fn replace_child(&mut self, _platform: &mut Platform, index: usize, child: &Widget) {
+        debug_assert_eq!(index, 0);
+
+        self.widget.set_child(child);
+    }
+}
+
+impl NativeWindow<Platform> for Window {
+    fn build(platform: &mut Platform, contents: &Widget) -> Self {
+        let state = WindowState {
+            resizable:  true,
+            min_width:  0,
+            min_height: 0,
+            animating:  false,
+
+            modal:         false,
+            transient_for: Weak::new(),
+
+            name:       None,
+            title:      String::new(),
+            icon_name:  None,
+            decorated:  true,
+            menubar:    MenuModel::default(),
+            maximized:  false,
+            fullscreen: false,
+            minimized:  false,
+
+            on_resize:          None,
+            on_close_requested: None,
+            on_state_changed:   None,
+            on_menu_select:     None,
+            on_animation_frame: None,
+        };
+
+        let widget = Widget::new(WidgetKind::Window, state);
+        widget.set_size(
+            DEFAULT_SIZE.0 as f32,
+            DEFAULT_SIZE.1 as f32,
+        );
+        widget.set_child(contents);
+
+        platform.windows.push(widget.clone());
+
+        Self { widget }
+    }
+
+    fn teardown(self, platform: &mut Platform) {
+        platform.windows.retain(|window| *window != self.widget);
+    }
+
+    fn close(&mut self, platform: &mut Platform) {
+        self.widget.data_mut().visible = false;
+        platform.windows.retain(|window| *window != self.widget);
+    }
+
+    fn get_size(&self) -> (u32, u32) {
+        let (width, height) = self.widget.size();
+        (width as u32, height as u32)
+    }
+
+    fn get_min_size(&self) -> (Option<u32>, Option<u32>) {
+        let (min_width, min_height) = self.widget.min_size();
+        (Some(min_width), Some(min_height))
+    }
+
+    fn set_on_animation_frame(&mut self, on_frame: impl Fn(Duration) + 'static) {
+        self.widget.state_mut::<WindowState>().on_animation_frame = Some(Rc::new(on_frame));
+    }
+
+    fn set_on_resize(&mut self, on_resize: impl Fn() + 'static) {
+        self.widget.state_mut::<WindowState>().on_resize = Some(Rc::new(on_resize));
+    }
+
+    fn set_on_close_requested(&mut self, on_close_requested: impl Fn() + 'static) {
+        self.widget.state_mut::<WindowState>().on_close_requested =
+            Some(Rc::new(on_close_requested));
+    }
+
+    fn set_on_state_changed(&mut self, on_state_changed: impl Fn() + 'static) {
+        self.widget.state_mut::<WindowState>().on_state_changed = Some(Rc::new(on_state_changed));
+    }
+
+    fn set_on_menu_select(&mut self, on_select: impl Fn(usize) + 'static) {
+        self.widget.state_mut::<WindowState>().on_menu_select = Some(Rc::new(on_select));
+    }
+
+    fn start_animating(&mut self) {
+        self.widget.state_mut::<WindowState>().animating = true;
+    }
+
+    fn stop_animating(&mut self) {
+        self.widget.state_mut::<WindowState>().animating = false;
+    }
+
+    fn set_min_size(&mut self, width: u32, height: u32) {
+        let mut state = self.widget.state_mut::<WindowState>();
+        state.min_width = width;
+        state.min_height = height;
+    }
+
+    fn set_size(&mut self, width: u32, height: u32) {
+        self.widget.set_size(
+            width.max(1) as f32,
+            height.max(1) as f32,
+        );
+    }
+
+    fn set_resizable(&mut self, resizable: bool) {
+        self.widget.state_mut::<WindowState>().resizable = resizable;
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.widget.data_mut().visible = visible;
+    }
+
+    fn set_name(&mut self, name: Option<&str>) {
+        self.widget.state_mut::<WindowState>().name = name.map(ToOwned::to_owned);
+    }
+
+    fn set_title(&mut self, title: &str) {
+        self.widget.state_mut::<WindowState>().title = title.to_owned();
+    }
+
+    fn set_icon_name(&mut self, icon_name: Option<&str>) {
+        self.widget.state_mut::<WindowState>().icon_name = icon_name.map(ToOwned::to_owned);
+    }
+
+    fn set_decorated(&mut self, decorated: bool) {
+        self.widget.state_mut::<WindowState>().decorated = decorated;
+    }
+
+    fn set_menubar(&mut self, menu: Option<&MenuModel>) {
+        self.widget.state_mut::<WindowState>().menubar = menu.cloned().unwrap_or_default();
+    }
+
+    fn is_maximized(&self) -> bool {
+        self.widget.is_maximized()
+    }
+
+    fn is_fullscreen(&self) -> bool {
+        self.widget.is_fullscreen()
+    }
+
+    fn is_minimized(&self) -> bool {
+        self.widget.is_minimized()
+    }
+
+    fn set_maximized(&mut self, maximized: bool) {
+        self.widget.state_mut::<WindowState>().maximized = maximized;
+        self.state_changed();
+    }
+
+    fn set_fullscreen(&mut self, fullscreen: bool) {
+        self.widget.state_mut::<WindowState>().fullscreen = fullscreen;
+        self.state_changed();
+    }
+
+    fn set_minimized(&mut self, minimized: bool) {
+        self.widget.state_mut::<WindowState>().minimized = minimized;
+        self.state_changed();
+    }
+
+    fn screenshot(&self) -> Option<Screenshot> {
+        Some(self.widget.screenshot())
+    }
+}
+
+impl NativeDialog<Platform> for Window {
+    fn build(platform: &mut Platform, contents: &Widget, owner: Option<&str>, modal: bool) -> Self {
+        let parent = find_owner(platform, owner, None);
+        let window = <Self as NativeWindow<Platform>>::build(platform, contents);
+
+        {
+            let mut state = window.widget.state_mut::<WindowState>();
+            state.modal = modal;
+            state.transient_for = parent.as_ref().map(Widget::downgrade).unwrap_or_default();
+        }
+
+        window
+    }
+
+    fn set_owner(&mut self, platform: &mut Platform, owner: Option<&str>) {
+        let parent = find_owner(platform, owner, Some(&self.widget));
+        self.widget.state_mut::<WindowState>().transient_for =
+            parent.as_ref().map(Widget::downgrade).unwrap_or_default();
+    }
+
+    fn set_modal(&mut self, modal: bool) {
+        self.widget.state_mut::<WindowState>().modal = modal;
+    }
+}
+
+/// Find the window named `owner`, or the active window, other than `dialog`.
+fn find_owner(platform: &Platform, owner: Option<&str>, dialog: Option<&Widget>) -> Option<Widget> {
+    let windows = platform
+        .windows
+        .iter()
+        .filter(|window| Some(*window) != dialog);
+
+    if let Some(owner) = owner
+        && let Some(window) = windows
+            .clone()
+            .find(|window| window.name().as_deref() == Some(owner))
+    {
+        return Some(window.clone());
+    }
+
+    // the most recently opened visible window stands in for the active window
+    windows.rev().find(|window| window.is_visible()).cloned()
+}
@@ -0,0 +1,36 @@
+use ori::Effect;
+use ori_native_core::{ClipboardContent, ClipboardKind, Context, views::*};
+use ori_native_headless::{Harness, Platform};
+
+#[derive(Default)]
+struct Pastes {
+    texts: Vec<String>,
+}
+
+fn ui(_: &Pastes) -> impl Effect<Context<Platform>, Pastes> + use<> {
+    ori::views::effects((
+        window(column((
+            pressable(|_, _| text("Copy")).on_press(|_: &mut Pastes| copy("copied")),
+            pressable(|_, _| text("Paste")).on_press(|_: &mut Pastes| paste(ClipboardKind::Text)),
+        ))),
+        clipboard().on_text(|data: &mut Pastes, text| data.texts.push(text)),
+        clipboard().on_text(|data: &mut Pastes, text| data.texts.push(text)),
+    ))
+}
+
+#[test]
+fn paste_is_handled_once() {
+    let mut harness = Harness::new(Pastes::default(), ui);
+    let window = harness.window(0);
+
+    harness.press(&window.find_text("Copy").unwrap());
+    assert_eq!(
+        harness.context().platform.clipboard(),
+        Some(&ClipboardContent::Text(String::from(
+            "copied"
+        )))
+    );
+
+    harness.press(&window.find_text("Paste").unwrap());
+    assert_eq!(harness.data().texts, ["copied"]);
+}
@@ -0,0 +1,105 @@
+use ori::Effect;
+use ori_native_core::{Context, Menu, MenuEntry, MenuItem, MenuModel, views::*};
+use ori_native_headless::{Harness, Platform, WidgetKind};
+
+struct Notes {
+    notes:  Vec<&'static str>,
+    pinned: Option<usize>,
+}
+
+fn ui(data: &Notes) -> impl Effect<Context<Platform>, Notes> + use<> {
+    let notes = data.notes.iter().enumerate().map(|(index, note)| {
+        let menu = Menu::new([
+            MenuItem::new("Pin", move |data: &mut Notes| {
+                data.pinned = Some(index);
+            })
+            .checked(data.pinned == Some(index)),
+            MenuItem::submenu(
+                "Move",
+                Menu::new([
+                    MenuItem::new("Up", move |data: &mut Notes| {
+                        data.notes.swap(index - 1, index);
+                    })
+                    .enabled(index > 0),
+                    MenuItem::new("Down", move |data: &mut Notes| {
+                        data.notes.swap(index, index + 1);
+                    })
+                    .enabled(index + 1 < data.notes.len()),
+                ]),
+            ),
+            MenuItem::separator(),
+            MenuItem::new("Delete", move |data: &mut Notes| {
+                data.notes.remove(index);
+            }),
+        ]);
+
+        context_menu(text(*note), menu)
+    });
+
+    window(column(notes.collect::<Vec<_>>()))
+}
+
+fn notes() -> Notes {
+    Notes {
+        notes:  vec!["Milk", "Eggs", "Bread"],
+        pinned: None,
+    }
+}
+
+#[test]
+fn select_item_updates_data() {
+    let mut harness = Harness::new(notes(), ui);
+    let window = harness.window(0);
+
+    let eggs = window.find_text("Eggs").unwrap();
+    harness.select_menu_item(&eggs, &["Delete"]);
+
+    assert_eq!(harness.data().notes, ["Milk", "Bread"]);
+    assert!(window.find_text("Eggs").is_none());
+}
+
+#[test]
+fn select_item_in_submenu() {
+    let mut harness = Harness::new(notes(), ui);
+    let window = harness.window(0);
+
+    let milk = window.find_text("Milk").unwrap();
+    harness.select_menu_item(&milk, &["Move", "Down"]);
+
+    assert_eq!(
+        harness.data().notes,
+        ["Eggs", "Milk", "Bread"]
+    );
+}
+
+#[test]
+fn menu_follows_data() {
+    let mut harness = Harness::new(notes(), ui);
+    let window = harness.window(0);
+
+    let bread = window.find_text("Bread").unwrap();
+    harness.select_menu_item(&bread, &["Pin"]);
+    assert_eq!(harness.data().pinned, Some(2));
+
+    let milk = window.find_kind(WidgetKind::ContextMenu).unwrap();
+    assert_eq!(is_checked(&milk.menu()), Some(false));
+
+    let bread = bread.parent().unwrap();
+    assert_eq!(is_checked(&bread.menu()), Some(true));
+}
+
+fn is_checked(menu: &MenuModel) -> Option<bool> {
+    match &menu.entries[0] {
+        MenuEntry::Item { checked, .. } => *checked,
+        _ => None,
+    }
+}
+
+#[test]
+#[should_panic(expected = "menu item should exist and be enabled")]
+fn disabled_item_cant_be_selected() {
+    let mut harness = Harness::new(notes(), ui);
+    let milk = harness.window(0).find_text("Milk").unwrap();
+
+    harness.select_menu_item(&milk, &["Move", "Up"]);
+}
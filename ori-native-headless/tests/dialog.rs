@@ -0,0 +1,61 @@
+use ori::Effect;
+use ori_native_core::{Context, views::*};
+use ori_native_headless::{Harness, Platform};
+
+#[derive(Default)]
+struct Dialogs {
+    first:  Option<bool>,
+    second: Option<bool>,
+}
+
+fn confirm(
+    label: &'static str,
+    on_response: fn(&mut Dialogs, bool),
+) -> impl Effect<Context<Platform>, Dialogs> + use<> {
+    dialog(move |id| {
+        pressable(move |_, _| text(label)).on_press(move |_: &mut Dialogs| respond(id, true))
+    })
+    .owner("main")
+    .on_response(on_response)
+}
+
+fn ui(_: &Dialogs) -> impl Effect<Context<Platform>, Dialogs> + use<> {
+    ori::views::effects((
+        window(text("Main")).name("main"),
+        window(text("Other")),
+        confirm("First", |data, response| {
+            data.first = Some(response)
+        }),
+        confirm("Second", |data, response| {
+            data.second = Some(response)
+        }),
+    ))
+}
+
+#[test]
+fn respond_only_reaches_its_dialog() {
+    let mut harness = Harness::new(Dialogs::default(), ui);
+
+    let second = harness.window(3);
+    harness.press(&second.find_text("Second").unwrap());
+
+    assert_eq!(harness.data().first, None);
+    assert_eq!(harness.data().second, Some(true));
+}
+
+#[test]
+fn dialog_is_transient_for_its_owner() {
+    let harness = Harness::new(Dialogs::default(), ui);
+
+    let main = harness.window(0);
+    assert_eq!(main.name().as_deref(), Some("main"));
+
+    assert_eq!(
+        harness.window(2).transient_for(),
+        Some(main.clone())
+    );
+    assert_eq!(
+        harness.window(3).transient_for(),
+        Some(main)
+    );
+}
@@ -0,0 +1,91 @@
+use ori::Effect;
+use ori_native_core::{Context, WidgetView, views::*};
+use ori_native_headless::{Harness, Platform};
+
+#[derive(Clone)]
+struct Dragged(usize);
+
+struct List {
+    items: Vec<&'static str>,
+    ended: Vec<bool>,
+}
+
+fn ui(data: &List) -> impl Effect<Context<Platform>, List> + use<> {
+    let items = (0..data.items.len()).map(item).collect::<Vec<_>>();
+    window(column(items))
+}
+
+fn item(index: usize) -> impl WidgetView<Platform, List> + use<> {
+    drop_target(move |data: &List, _hovered| {
+        draggable(text(data.items[index]), Dragged(index))
+            .on_drag_end(|data: &mut List, dropped| data.ended.push(dropped))
+    })
+    .on_drop(move |data: &mut List, Dragged(from)| {
+        let item = data.items.remove(from);
+        data.items.insert(index, item);
+    })
+}
+
+fn list() -> List {
+    List {
+        items: vec!["A", "B", "C", "D"],
+        ended: Vec::new(),
+    }
+}
+
+#[test]
+fn drop_moves_item_down_by_one() {
+    let mut harness = Harness::new(list(), ui);
+    let window = harness.window(0);
+    let a = window.find_text("A").unwrap();
+    let b = window.find_text("B").unwrap();
+
+    assert!(harness.drag_and_drop(&a, &b));
+    assert_eq!(
+        harness.data().items,
+        ["B", "A", "C", "D"]
+    );
+    assert_eq!(harness.data().ended, [true]);
+}
+
+#[test]
+fn drop_moves_item_to_end() {
+    let mut harness = Harness::new(list(), ui);
+    let window = harness.window(0);
+    let b = window.find_text("B").unwrap();
+    let d = window.find_text("D").unwrap();
+
+    assert!(harness.drag_and_drop(&b, &d));
+    assert_eq!(
+        harness.data().items,
+        ["A", "C", "D", "B"]
+    );
+}
+
+#[test]
+fn drop_moves_item_up() {
+    let mut harness = Harness::new(list(), ui);
+    let window = harness.window(0);
+    let a = window.find_text("A").unwrap();
+    let d = window.find_text("D").unwrap();
+
+    assert!(harness.drag_and_drop(&d, &a));
+    assert_eq!(
+        harness.data().items,
+        ["D", "A", "B", "C"]
+    );
+}
+
+#[test]
+fn drop_outside_target_is_not_dropped() {
+    let mut harness = Harness::new(list(), ui);
+    let window = harness.window(0);
+    let source = window.find_text("A").unwrap();
+
+    assert!(!harness.drag_and_drop(&source, &window));
+    assert_eq!(
+        harness.data().items,
+        ["A", "B", "C", "D"]
+    );
+    assert_eq!(harness.data().ended, [false]);
+}
@@ -0,0 +1,72 @@
+use ori::Effect;
+use ori_native_core::{Context, views::*};
+use ori_native_headless::{Harness, Platform, WidgetKind};
+
+struct Colors {
+    colors:   Vec<&'static str>,
+    selected: Option<usize>,
+}
+
+fn ui(data: &Colors) -> impl Effect<Context<Platform>, Colors> + use<> {
+    let options = data.colors.iter().map(|color| match *color {
+        "Green" => DropdownOption::disabled(*color),
+        color => DropdownOption::new(color),
+    });
+
+    window(
+        dropdown(options, data.selected)
+            .searchable(true)
+            .on_select(|data: &mut Colors, index| data.selected = Some(index)),
+    )
+}
+
+fn colors() -> Colors {
+    Colors {
+        colors:   vec!["Red", "Green", "Blue"],
+        selected: None,
+    }
+}
+
+#[test]
+fn select_option_updates_data() {
+    let mut harness = Harness::new(colors(), ui);
+    let dropdown = harness.window(0).find_kind(WidgetKind::Dropdown).unwrap();
+
+    assert!(dropdown.is_searchable());
+    assert_eq!(dropdown.selected(), None);
+    assert_eq!(
+        dropdown.options(),
+        [
+            DropdownOption::new("Red"),
+            DropdownOption::disabled("Green"),
+            DropdownOption::new("Blue"),
+        ]
+    );
+
+    harness.select_option(&dropdown, 2);
+
+    assert_eq!(harness.data().selected, Some(2));
+    assert_eq!(dropdown.selected(), Some(2));
+}
+
+#[test]
+#[should_panic(expected = "option should be enabled")]
+fn disabled_option_cant_be_selected() {
+    let mut harness = Harness::new(colors(), ui);
+    let dropdown = harness.window(0).find_kind(WidgetKind::Dropdown).unwrap();
+
+    harness.select_option(&dropdown, 1);
+}
+
+#[test]
+fn dropdown_follows_data() {
+    let mut harness = Harness::new(colors(), ui);
+    let dropdown = harness.window(0).find_kind(WidgetKind::Dropdown).unwrap();
+
+    harness.data_mut().colors.insert(0, "Yellow");
+    harness.data_mut().selected = Some(3);
+    harness.rebuild();
+
+    assert_eq!(dropdown.options().len(), 4);
+    assert_eq!(dropdown.selected(), Some(3));
+}
@@ -0,0 +1,80 @@
+use std::path::PathBuf;
+
+use ori::Effect;
+use ori_native_core::{Context, ExternalDrop, views::*};
+use ori_native_headless::{Harness, Platform};
+
+#[derive(Default)]
+struct Mail {
+    attachments: Vec<PathBuf>,
+    body:        String,
+    hovers:      Vec<bool>,
+}
+
+fn ui(_: &Mail) -> impl Effect<Context<Platform>, Mail> + use<> {
+    let attachments = |_: &Mail, _| {
+        file_drop(|data: &Mail, hovered| {
+            text(match hovered {
+                true => String::from("Drop to attach"),
+                false => format!("{} attachments", data.attachments.len()),
+            })
+        })
+        .on_files(|data: &mut Mail, paths| data.attachments.extend(paths))
+        .on_hover(|data: &mut Mail, hovered| data.hovers.push(hovered))
+    };
+
+    window(column((
+        file_drop(attachments).on_text(|data: &mut Mail, text| data.body.push_str(&text)),
+        text("Signature"),
+    )))
+}
+
+#[test]
+fn dropped_files_are_handled() {
+    let mut harness = Harness::new(Mail::default(), ui);
+    let window = harness.window(0);
+    let attachments = window.find_text("0 attachments").unwrap();
+
+    let dropped = harness.drop_external(
+        &attachments,
+        ExternalDrop::Files(vec![PathBuf::from("/tmp/report.pdf")]),
+    );
+
+    assert!(dropped);
+    assert_eq!(
+        harness.data().attachments,
+        [PathBuf::from("/tmp/report.pdf")]
+    );
+    assert_eq!(harness.data().hovers, [true, false]);
+    assert!(window.find_text("1 attachments").is_some());
+}
+
+#[test]
+fn drop_goes_to_closest_accepting_target() {
+    let mut harness = Harness::new(Mail::default(), ui);
+    let attachments = harness.window(0).find_text("0 attachments").unwrap();
+
+    let dropped = harness.drop_external(
+        &attachments,
+        ExternalDrop::Text(String::from("Hello")),
+    );
+
+    assert!(dropped);
+    assert_eq!(harness.data().body, "Hello");
+    assert!(harness.data().attachments.is_empty());
+    assert!(harness.data().hovers.is_empty());
+}
+
+#[test]
+fn drop_outside_targets_is_refused() {
+    let mut harness = Harness::new(Mail::default(), ui);
+    let signature = harness.window(0).find_text("Signature").unwrap();
+
+    let dropped = harness.drop_external(
+        &signature,
+        ExternalDrop::Text(String::from("Hello")),
+    );
+
+    assert!(!dropped);
+    assert!(harness.data().body.is_empty());
+}
@@ -0,0 +1,84 @@
+use ori::Effect;
+use ori_native_core::{
+    Context, GridLayout, GridPlacement, Layout, Track, fr, repeat, span, views::*,
+};
+use ori_native_headless::{Harness, Platform};
+
+fn areas(_: &()) -> impl Effect<Context<Platform>, ()> + use<> {
+    window(
+        grid((
+            text("Header").grid_area("header"),
+            text("Sidebar").grid_area("sidebar"),
+            text("Main").grid_area("main"),
+        ))
+        .columns([Track::from(100.0), fr(1.0)])
+        .rows([Track::from(20.0), fr(1.0)])
+        .areas(["header header", "sidebar main"])
+        .size(300.0, 200.0),
+    )
+}
+
+#[test]
+fn items_fill_their_areas() {
+    let harness = Harness::new((), areas);
+    let window = harness.window(0);
+
+    let header = window.find_text("Header").unwrap();
+    assert_eq!(header.position(), (0.0, 0.0));
+    assert_eq!(header.size(), (300.0, 20.0));
+
+    let sidebar = window.find_text("Sidebar").unwrap();
+    assert_eq!(sidebar.position(), (0.0, 20.0));
+    assert_eq!(sidebar.size(), (100.0, 180.0));
+
+    let main = window.find_text("Main").unwrap();
+    assert_eq!(main.position(), (100.0, 20.0));
+    assert_eq!(main.size(), (200.0, 180.0));
+}
+
+fn placed(_: &()) -> impl Effect<Context<Platform>, ()> + use<> {
+    window(
+        grid((
+            text("Wide").grid_column(GridPlacement::Line(1), span(2)),
+            text("Last").grid_column(
+                GridPlacement::Line(-2),
+                GridPlacement::Line(-1),
+            ),
+            text("Below").grid_row(GridPlacement::Line(3), span(1)),
+            text("Next"),
+        ))
+        .columns([repeat(3, [Track::from(50.0)])])
+        .rows([Track::from(20.0)])
+        .auto_rows([Track::from(30.0)])
+        .size(150.0, 100.0),
+    )
+}
+
+#[test]
+fn items_are_placed_on_lines_and_spans() {
+    let harness = Harness::new((), placed);
+    let window = harness.window(0);
+
+    let wide = window.find_text("Wide").unwrap();
+    assert_eq!(wide.position(), (0.0, 0.0));
+    assert_eq!(wide.size(), (100.0, 20.0));
+
+    let last = window.find_text("Last").unwrap();
+    assert_eq!(last.position(), (100.0, 0.0));
+    assert_eq!(last.size(), (50.0, 20.0));
+
+    // the rows after the first are auto rows
+    let below = window.find_text("Below").unwrap();
+    assert_eq!(below.position(), (0.0, 50.0));
+    assert_eq!(below.size(), (50.0, 30.0));
+
+    // the first free cell is in the second row
+    let next = window.find_text("Next").unwrap();
+    assert_eq!(next.position(), (0.0, 20.0));
+}
+
+#[test]
+#[should_panic(expected = "grid area `main` should be a rectangle")]
+fn areas_must_be_rectangles() {
+    let _ = grid(()).areas(["main main", "main ."]);
+}
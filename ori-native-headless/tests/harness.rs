@@ -0,0 +1,136 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+
+use ori::{Effect, Proxied, Proxy};
+use ori_native_core::{Context, views::*};
+use ori_native_headless::{Harness, Platform};
+
+struct Counter {
+    count: u32,
+}
+
+fn counter(data: &Counter) -> impl Effect<Context<Platform>, Counter> + use<> {
+    window(column((
+        pressable(|_, _| text("Press me!")).on_press(|data: &mut Counter| data.count += 1),
+        text(format!("Pressed {} times.", data.count)),
+    )))
+}
+
+#[test]
+fn build_opens_window() {
+    let harness = Harness::new(Counter { count: 0 }, counter);
+
+    assert_eq!(harness.windows().len(), 1);
+    assert!(harness.window(0).is_visible());
+    assert!(harness.window(0).find_text("Pressed 0 times.").is_some());
+}
+
+#[test]
+fn press_updates_data_and_view() {
+    let mut harness = Harness::new(Counter { count: 0 }, counter);
+
+    let window = harness.window(0);
+    let button = window.find_text("Press me!").unwrap();
+
+    harness.press(&button);
+    harness.press(&button);
+
+    assert_eq!(harness.data().count, 2);
+    assert!(window.find_text("Pressed 2 times.").is_some());
+}
+
+#[test]
+fn rebuild_applies_data() {
+    let mut harness = Harness::new(Counter { count: 0 }, counter);
+
+    harness.data_mut().count = 7;
+    harness.rebuild();
+
+    assert!(harness.window(0).find_text("Pressed 7 times.").is_some());
+}
+
+#[test]
+fn run_waits_for_spawned_futures() {
+    let mut harness = Harness::new(Counter { count: 0 }, counter);
+
+    let done = Arc::new(AtomicBool::new(false));
+    let proxy = harness.context_mut().platform.proxy();
+
+    proxy.spawn_boxed(Box::pin({
+        let done = done.clone();
+
+        async move {
+            std::thread::sleep(std::time::Duration::from_millis(50));
+            done.store(true, Ordering::SeqCst);
+        }
+    }));
+
+    harness.run();
+
+    assert!(done.load(Ordering::SeqCst));
+}
+
+#[test]
+fn run_handles_messages_of_spawned_futures() {
+    let mut harness = Harness::new(Counter { count: 0 }, counter);
+
+    let proxy = harness.context_mut().platform.proxy();
+
+    proxy.spawn_boxed(Box::pin({
+        let proxy = proxy.clone();
+
+        async move {
+            proxy.rebuild();
+        }
+    }));
+
+    harness.data_mut().count = 3;
+    harness.run();
+
+    assert!(harness.window(0).find_text("Pressed 3 times.").is_some());
+}
+
+#[test]
+fn request_close_closes_window_and_quits() {
+    let mut harness = Harness::new(Counter { count: 0 }, counter);
+
+    let window = harness.window(0);
+    harness.request_close(&window);
+
+    assert!(!window.is_visible());
+    assert!(harness.windows().is_empty());
+    assert!(!harness.is_running());
+}
+
+fn with_reset(data: &Counter) -> impl Effect<Context<Platform>, Counter> + use<> {
+    ori::views::effects((
+        counter(data),
+        app_action("reset", |data: &mut Counter| {
+            data.count = 0
+        })
+        .accelerator("Ctrl+R")
+        .enabled(data.count > 0),
+    ))
+}
+
+#[test]
+fn app_action_is_activated_by_name_and_accelerator() {
+    let mut harness = Harness::new(Counter { count: 0 }, with_reset);
+    assert_eq!(
+        harness.context().platform.is_app_action_enabled("reset"),
+        Some(false)
+    );
+
+    let window = harness.window(0);
+    harness.press(&window.find_text("Press me!").unwrap());
+    harness.press(&window.find_text("Press me!").unwrap());
+
+    assert!(harness.press_shortcut(&window, "Ctrl+R"));
+    assert_eq!(harness.data().count, 0);
+
+    harness.press(&window.find_text("Press me!").unwrap());
+    harness.activate_app_action("reset");
+    assert_eq!(harness.data().count, 0);
+}
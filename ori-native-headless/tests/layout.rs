@@ -0,0 +1,156 @@
+use ori::Effect;
+use ori_native_core::{
+    BoxSizing, ContainerLayout, Context, Fraction, GridLayout, Layout, Position, Track, views::*,
+};
+use ori_native_headless::{Harness, Platform};
+
+fn offsets(_: &()) -> impl Effect<Context<Platform>, ()> + use<> {
+    window(
+        column((
+            text("Abs")
+                .size(16.0)
+                .position(Position::Absolute)
+                .offset_right(10.0)
+                .offset_bottom(10.0),
+            text("Half")
+                .size(16.0)
+                .position(Position::Absolute)
+                .offset(Fraction(0.5), 0.0),
+        ))
+        .size(200.0, 100.0),
+    )
+}
+
+#[test]
+fn absolute_items_are_placed_by_offsets() {
+    let harness = Harness::new((), offsets);
+    let window = harness.window(0);
+
+    let abs = window.find_text("Abs").unwrap();
+    assert_eq!(abs.position(), (166.0, 70.0));
+
+    let half = window.find_text("Half").unwrap();
+    assert_eq!(half.position(), (100.0, 0.0));
+}
+
+fn sizes(_: &()) -> impl Effect<Context<Platform>, ()> + use<> {
+    window(column((
+        row((
+            text("Basis").size(16.0).flex_basis(100.0),
+            text("Next").size(16.0),
+        ))
+        .width(300.0),
+        column(text("Ratio").size(16.0))
+            .width(40.0)
+            .aspect_ratio(0.5),
+        column(text("Border").size(16.0))
+            .size(100.0, 50.0)
+            .padding(10.0),
+        column(text("Content").size(16.0))
+            .size(100.0, 50.0)
+            .padding(10.0)
+            .box_sizing(BoxSizing::ContentBox),
+    )))
+}
+
+#[test]
+fn flex_basis_sets_size_before_growing() {
+    let harness = Harness::new((), sizes);
+    let window = harness.window(0);
+
+    let basis = window.find_text("Basis").unwrap();
+    assert_eq!(basis.size().0, 100.0);
+
+    let next = window.find_text("Next").unwrap();
+    assert_eq!(next.position(), (100.0, 0.0));
+}
+
+#[test]
+fn aspect_ratio_sets_missing_size() {
+    let harness = Harness::new((), sizes);
+    let window = harness.window(0);
+
+    let ratio = window.find_text("Ratio").unwrap().parent().unwrap();
+    assert_eq!(ratio.size(), (40.0, 80.0));
+}
+
+#[test]
+fn box_sizing_includes_padding_or_not() {
+    let harness = Harness::new((), sizes);
+    let window = harness.window(0);
+
+    let border = window.find_text("Border").unwrap().parent().unwrap();
+    assert_eq!(border.size(), (100.0, 50.0));
+
+    let content = window.find_text("Content").unwrap().parent().unwrap();
+    assert_eq!(content.size(), (120.0, 70.0));
+}
+
+fn gaps(_: &()) -> impl Effect<Context<Platform>, ()> + use<> {
+    window(
+        grid((
+            text("A").size(16.0),
+            text("B").size(16.0),
+            text("C").size(16.0),
+        ))
+        .columns([Track::from(50.0), Track::from(50.0)])
+        .rows([Track::from(20.0), Track::from(20.0)])
+        .row_gap(5.0)
+        .column_gap(10.0),
+    )
+}
+
+#[test]
+fn row_and_column_gaps_differ() {
+    let harness = Harness::new((), gaps);
+    let window = harness.window(0);
+
+    assert_eq!(
+        window.find_text("B").unwrap().position(),
+        (60.0, 0.0)
+    );
+    assert_eq!(
+        window.find_text("C").unwrap().position(),
+        (0.0, 25.0)
+    );
+}
+
+struct Shown {
+    hidden: bool,
+}
+
+fn shown(data: &Shown) -> impl Effect<Context<Platform>, Shown> + use<> {
+    window(column((
+        text("Text").size(16.0).hidden(data.hidden),
+        grid((
+            text("A").size(16.0),
+            text("B").size(16.0),
+        ))
+        .columns([Track::from(50.0), Track::from(50.0)])
+        .hidden(true)
+        .hidden(data.hidden),
+    )))
+}
+
+#[test]
+fn hidden_items_are_shown_again() {
+    let mut harness = Harness::new(Shown { hidden: true }, shown);
+    let window = harness.window(0);
+
+    let text = window.find_text("Text").unwrap();
+    let grid = window.find_text("A").unwrap().parent().unwrap();
+    assert!(!text.is_visible());
+    assert!(!grid.is_visible());
+
+    harness.data_mut().hidden = false;
+    harness.rebuild();
+
+    assert!(text.is_visible());
+    assert!(grid.is_visible());
+
+    // the grid is laid out as a grid again
+    assert_eq!(
+        window.find_text("B").unwrap().position(),
+        (50.0, 0.0)
+    );
+}
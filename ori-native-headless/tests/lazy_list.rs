@@ -0,0 +1,112 @@
+use ori::Effect;
+use ori_native_core::{Context, Layout, views::*};
+use ori_native_headless::{Harness, Platform, Widget, WidgetKind};
+
+struct Items {
+    count: usize,
+    label: &'static str,
+}
+
+fn ui(data: &Items) -> impl Effect<Context<Platform>, Items> + use<> {
+    window(
+        lazy_list(
+            data.count,
+            20.0,
+            |index, data: &Items| text(format!("{} {index}", data.label)),
+        )
+        .height(100.0),
+    )
+}
+
+fn items() -> Items {
+    Items {
+        count: 100,
+        label: "Item",
+    }
+}
+
+fn rows(window: &Widget) -> Vec<Widget> {
+    window.find_all(|widget| widget.kind() == WidgetKind::Text)
+}
+
+/// Get the sorted indices of the built rows.
+fn indices(window: &Widget) -> Vec<usize> {
+    let mut indices = rows(window)
+        .iter()
+        .map(|row| {
+            let text = row.text();
+            let (_, index) = text.split_once(' ').unwrap();
+            index.parse().unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    indices.sort();
+    indices
+}
+
+#[test]
+fn only_visible_rows_are_built() {
+    let harness = Harness::new(items(), ui);
+    let window = harness.window(0);
+
+    assert_eq!(
+        indices(&window),
+        (0..10).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn scrolling_reuses_rows() {
+    let mut harness = Harness::new(items(), ui);
+    let window = harness.window(0);
+    let list = window.find_kind(WidgetKind::Scroll).unwrap();
+
+    let before = rows(&window);
+    harness.scroll(&list, (0.0, 400.0));
+
+    assert_eq!(
+        indices(&window),
+        (16..30).collect::<Vec<_>>()
+    );
+
+    let after = rows(&window);
+    assert!(before.iter().all(|row| after.contains(row)));
+
+    let first = window.find_text("Item 16").unwrap();
+    assert_eq!(first.position().1, 320.0);
+}
+
+#[test]
+fn rebuild_updates_visible_rows() {
+    let mut harness = Harness::new(items(), ui);
+    let window = harness.window(0);
+    let before = rows(&window);
+
+    harness.data_mut().label = "Row";
+    harness.rebuild();
+
+    assert_eq!(rows(&window), before);
+    assert!(before.iter().all(|row| row.text().starts_with("Row ")));
+}
+
+#[test]
+fn shrinking_while_scrolled_to_end_keeps_last_rows() {
+    let mut harness = Harness::new(items(), ui);
+    let window = harness.window(0);
+    let list = window.find_kind(WidgetKind::Scroll).unwrap();
+
+    harness.scroll(&list, (0.0, 2000.0));
+    assert_eq!(list.scroll_offset(), (0.0, 1900.0));
+    assert_eq!(
+        indices(&window),
+        (91..100).collect::<Vec<_>>()
+    );
+
+    harness.data_mut().count = 20;
+    harness.rebuild();
+
+    assert_eq!(
+        indices(&window),
+        (15..20).collect::<Vec<_>>()
+    );
+}
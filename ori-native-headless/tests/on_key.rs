@@ -0,0 +1,51 @@
+use ori::Effect;
+use ori_native_core::{Context, Key, KeyEvent, Propagation, views::*};
+use ori_native_headless::{Harness, Platform};
+
+#[derive(Default)]
+struct Keys {
+    inner: Vec<Key>,
+    outer: Vec<Key>,
+}
+
+fn ui(_: &Keys) -> impl Effect<Context<Platform>, Keys> + use<> {
+    window(on_key(
+        on_key(
+            text("Focus"),
+            |data: &mut Keys, event: KeyEvent| {
+                if !event.is_pressed() {
+                    return ((), Propagation::Proceed);
+                }
+
+                data.inner.push(event.key);
+
+                match event.key {
+                    Key::Up | Key::Down => ((), Propagation::Stop),
+                    _ => ((), Propagation::Proceed),
+                }
+            },
+        ),
+        |data: &mut Keys, event: KeyEvent| {
+            if event.is_pressed() {
+                data.outer.push(event.key);
+            }
+
+            ((), Propagation::Proceed)
+        },
+    ))
+}
+
+#[test]
+fn handler_decides_propagation() {
+    let mut harness = Harness::new(Keys::default(), ui);
+    let focus = harness.window(0).find_text("Focus").unwrap();
+
+    assert!(harness.key(&focus, KeyEvent::pressed(Key::Down)));
+    assert!(!harness.key(&focus, KeyEvent::pressed(Key::Enter)));
+
+    assert_eq!(
+        harness.data().inner,
+        [Key::Down, Key::Enter]
+    );
+    assert_eq!(harness.data().outer, [Key::Enter]);
+}
@@ -0,0 +1,124 @@
+use ori::Effect;
+use ori_native_core::{Context, PointerButton, PointerEvent, PointerEventKind, views::*};
+use ori_native_headless::{Harness, Platform};
+
+type Stroke = Vec<(f32, f32)>;
+
+#[derive(Default)]
+struct Sketch {
+    pen:     &'static str,
+    drawing: Option<Stroke>,
+    strokes: Vec<(&'static str, Stroke)>,
+}
+
+fn ui(data: &Sketch) -> impl Effect<Context<Platform>, Sketch> + use<> {
+    let pen = data.pen;
+
+    window(on_pointer(
+        text("Canvas"),
+        move |data: &mut Sketch, event: PointerEvent| match event.kind {
+            PointerEventKind::Down(PointerButton::Primary) => {
+                data.drawing = Some(vec![(event.x, event.y)]);
+            }
+
+            PointerEventKind::Move => {
+                if let Some(stroke) = &mut data.drawing {
+                    stroke.push((event.x, event.y));
+                }
+            }
+
+            PointerEventKind::Up(PointerButton::Primary) => {
+                if let Some(stroke) = data.drawing.take() {
+                    data.strokes.push((pen, stroke));
+                }
+            }
+
+            PointerEventKind::Cancel(_) => data.drawing = None,
+
+            _ => {}
+        },
+    ))
+}
+
+fn sketch() -> Sketch {
+    Sketch {
+        pen: "Pencil",
+        ..Default::default()
+    }
+}
+
+fn down(x: f32, y: f32) -> PointerEvent {
+    PointerEvent::new(
+        PointerEventKind::Down(PointerButton::Primary),
+        x,
+        y,
+    )
+}
+
+fn moved(x: f32, y: f32) -> PointerEvent {
+    PointerEvent::new(PointerEventKind::Move, x, y)
+}
+
+fn up(x: f32, y: f32) -> PointerEvent {
+    PointerEvent::new(
+        PointerEventKind::Up(PointerButton::Primary),
+        x,
+        y,
+    )
+}
+
+#[test]
+fn press_move_and_release_draws_stroke() {
+    let mut harness = Harness::new(sketch(), ui);
+    let canvas = harness.window(0).find_text("Canvas").unwrap();
+
+    harness.pointer(&canvas, moved(1.0, 1.0));
+    assert_eq!(harness.data().drawing, None);
+
+    harness.pointer(&canvas, down(2.0, 2.0));
+    harness.pointer(&canvas, moved(4.0, 3.0));
+    harness.pointer(&canvas, up(4.0, 3.0));
+
+    assert_eq!(harness.data().drawing, None);
+    assert_eq!(
+        harness.data().strokes,
+        [("Pencil", vec![(2.0, 2.0), (4.0, 3.0)])]
+    );
+}
+
+#[test]
+fn cancelled_press_is_not_released() {
+    let mut harness = Harness::new(sketch(), ui);
+    let canvas = harness.window(0).find_text("Canvas").unwrap();
+
+    harness.pointer(&canvas, down(2.0, 2.0));
+    harness.pointer(&canvas, moved(4.0, 3.0));
+    harness.pointer(
+        &canvas,
+        PointerEvent::new(
+            PointerEventKind::Cancel(PointerButton::Primary),
+            4.0,
+            3.0,
+        ),
+    );
+
+    assert_eq!(harness.data().drawing, None);
+    assert!(harness.data().strokes.is_empty());
+}
+
+#[test]
+fn rebuild_replaces_handler() {
+    let mut harness = Harness::new(sketch(), ui);
+    let canvas = harness.window(0).find_text("Canvas").unwrap();
+
+    harness.data_mut().pen = "Brush";
+    harness.rebuild();
+
+    harness.pointer(&canvas, down(0.0, 0.0));
+    harness.pointer(&canvas, up(0.0, 0.0));
+
+    assert_eq!(
+        harness.data().strokes,
+        [("Brush", vec![(0.0, 0.0)])]
+    );
+}
@@ -0,0 +1,93 @@
+use ori::Effect;
+use ori_native_core::{Context, Layout, native::Placement, views::*};
+use ori_native_headless::{Harness, Platform, WidgetKind};
+
+#[derive(Default)]
+struct Menu {
+    open:      bool,
+    hidden:    bool,
+    dismissed: usize,
+}
+
+fn ui(data: &Menu) -> impl Effect<Context<Platform>, Menu> + use<> {
+    window(column(
+        popover(text("Anchor"), text("Contents"))
+            .open(data.open)
+            .placement(Placement::End)
+            .flip(false)
+            .on_dismiss(|data: &mut Menu| {
+                data.open = false;
+                data.dismissed += 1;
+            })
+            .hidden(data.hidden),
+    ))
+}
+
+#[test]
+fn dismiss_closes_popover() {
+    let mut harness = Harness::new(
+        Menu {
+            open: true,
+            ..Default::default()
+        },
+        ui,
+    );
+
+    let popover = harness.window(0).find_kind(WidgetKind::Popover).unwrap();
+    assert!(popover.is_open());
+    assert_eq!(popover.placement(), Placement::End);
+    assert!(!popover.flip());
+
+    harness.dismiss(&popover);
+
+    assert!(!popover.is_open());
+    assert!(!harness.data().open);
+    assert_eq!(harness.data().dismissed, 1);
+}
+
+#[test]
+fn popover_opened_while_hidden_opens_when_shown() {
+    let mut harness = Harness::new(
+        Menu {
+            open: true,
+            hidden: true,
+            ..Default::default()
+        },
+        ui,
+    );
+
+    let popover = harness.window(0).find_kind(WidgetKind::Popover).unwrap();
+    assert!(!popover.is_mapped());
+    assert!(!popover.is_open());
+
+    harness.data_mut().hidden = false;
+    harness.rebuild();
+
+    assert!(popover.is_mapped());
+    assert!(popover.is_open());
+}
+
+#[test]
+fn hiding_popover_doesnt_dismiss_it() {
+    let mut harness = Harness::new(
+        Menu {
+            open: true,
+            ..Default::default()
+        },
+        ui,
+    );
+
+    let popover = harness.window(0).find_kind(WidgetKind::Popover).unwrap();
+
+    harness.data_mut().hidden = true;
+    harness.rebuild();
+
+    assert!(!popover.is_open());
+    assert!(harness.data().open);
+    assert_eq!(harness.data().dismissed, 0);
+
+    harness.data_mut().hidden = false;
+    harness.rebuild();
+
+    assert!(popover.is_open());
+}
@@ -0,0 +1,78 @@
+use std::time::Duration;
+
+use ori::Effect;
+use ori_native_core::{Context, views::*};
+use ori_native_headless::{Harness, Platform, WidgetKind};
+
+struct Upload {
+    fraction: f32,
+    duration: f32,
+}
+
+fn ui(data: &Upload) -> impl Effect<Context<Platform>, Upload> + use<> {
+    window(progress(data.fraction).transition(Linear(data.duration)))
+}
+
+fn upload(duration: f32) -> Upload {
+    Upload {
+        fraction: 0.0,
+        duration,
+    }
+}
+
+#[test]
+fn progress_animates_to_new_fraction() {
+    let mut harness = Harness::new(upload(1.0), ui);
+    let window = harness.window(0);
+    let progress = window.find_kind(WidgetKind::Progress).unwrap();
+
+    harness.data_mut().fraction = 1.0;
+    harness.rebuild();
+
+    assert_eq!(progress.fraction(), 0.0);
+    assert!(window.is_animating());
+
+    harness.animate(Duration::from_millis(250));
+    assert_eq!(progress.fraction(), 0.25);
+
+    harness.animate(Duration::from_secs(1));
+    assert_eq!(progress.fraction(), 1.0);
+    assert!(!window.is_animating());
+}
+
+#[test]
+fn rebuild_mid_animation_starts_from_shown_fraction() {
+    let mut harness = Harness::new(upload(1.0), ui);
+    let window = harness.window(0);
+    let progress = window.find_kind(WidgetKind::Progress).unwrap();
+
+    harness.data_mut().fraction = 1.0;
+    harness.rebuild();
+    harness.animate(Duration::from_millis(500));
+    assert_eq!(progress.fraction(), 0.5);
+
+    harness.data_mut().fraction = 0.0;
+    harness.rebuild();
+    assert_eq!(progress.fraction(), 0.5);
+    assert!(window.is_animating());
+
+    harness.animate(Duration::from_millis(500));
+    assert_eq!(progress.fraction(), 0.25);
+
+    harness.animate(Duration::from_millis(500));
+    assert_eq!(progress.fraction(), 0.0);
+    assert!(!window.is_animating());
+}
+
+#[test]
+fn transition_without_duration_jumps_to_fraction() {
+    let mut harness = Harness::new(upload(0.0), ui);
+    let window = harness.window(0);
+    let progress = window.find_kind(WidgetKind::Progress).unwrap();
+
+    harness.data_mut().fraction = 0.75;
+    harness.rebuild();
+
+    assert_eq!(progress.fraction(), 0.75);
+    assert!(!window.is_animating());
+}
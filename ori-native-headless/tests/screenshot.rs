@@ -0,0 +1,126 @@
+use ori::Effect;
+use ori_native_core::{
+    BorderLayout, Color, Context, Layout, Menu, MenuItem, Sizing, WidgetView, native::Screenshot,
+    views::*,
+};
+use ori_native_headless::{Harness, Platform};
+
+struct Swatches {
+    color: Color,
+}
+
+fn ui(data: &Swatches) -> impl Effect<Context<Platform>, Swatches> + use<> {
+    window(contents(data)).sizing(Sizing::Content)
+}
+
+fn with_menubar(data: &Swatches) -> impl Effect<Context<Platform>, Swatches> + use<> {
+    let menu = Menu::new([MenuItem::submenu(
+        "Swatches",
+        Menu::new(
+            [MenuItem::new("Black", |data: &mut Swatches| {
+                data.color = Color::BLACK
+            })],
+        ),
+    )]);
+
+    window(contents(data)).sizing(Sizing::Content).menubar(menu)
+}
+
+fn contents(data: &Swatches) -> impl WidgetView<Platform, Swatches> + use<> {
+    column((
+        row(text("Red"))
+            .size(20.0, 10.0)
+            .background_color(Color::RED)
+            .border(2.0)
+            .border_color(Color::BLUE),
+        row(text("Pink"))
+            .size(20.0, 10.0)
+            .background_color(data.color),
+    ))
+    .padding(5.0)
+    .background_color(Color::WHITE)
+}
+
+fn swatches() -> Swatches {
+    Swatches {
+        color: Color::rgba(1.0, 0.0, 0.0, 0.5),
+    }
+}
+
+fn pixel(screenshot: &Screenshot, x: u32, y: u32) -> [u8; 4] {
+    let index = (y * screenshot.width + x) as usize * 4;
+    screenshot.pixels[index..index + 4].try_into().unwrap()
+}
+
+#[test]
+fn screenshot_draws_backgrounds_and_borders() {
+    let harness = Harness::new(swatches(), ui);
+    let screenshot = harness.window(0).screenshot();
+
+    assert_eq!(
+        (screenshot.width, screenshot.height),
+        (30, 30)
+    );
+
+    assert_eq!(
+        pixel(&screenshot, 0, 0),
+        [255, 255, 255, 255]
+    );
+    assert_eq!(
+        pixel(&screenshot, 5, 5),
+        [0, 0, 255, 255]
+    );
+    assert_eq!(
+        pixel(&screenshot, 10, 8),
+        [255, 0, 0, 255]
+    );
+    assert_eq!(
+        pixel(&screenshot, 24, 14),
+        [0, 0, 255, 255]
+    );
+
+    // translucent colors are blended over what's below
+    assert_eq!(
+        pixel(&screenshot, 10, 20),
+        [255, 128, 128, 255]
+    );
+}
+
+#[test]
+fn screenshot_follows_rebuild() {
+    let mut harness = Harness::new(swatches(), ui);
+    let window = harness.window(0);
+
+    harness.data_mut().color = Color::BLACK;
+    harness.rebuild();
+
+    let screenshot = window.screenshot();
+    assert_eq!(
+        pixel(&screenshot, 10, 20),
+        [0, 0, 0, 255]
+    );
+}
+
+#[test]
+fn screenshot_leaves_out_menubar() {
+    let mut harness = Harness::new(swatches(), with_menubar);
+    let window = harness.window(0);
+
+    let screenshot = window.screenshot();
+    assert_eq!(
+        (screenshot.width, screenshot.height),
+        (30, 30)
+    );
+    assert_eq!(
+        pixel(&screenshot, 5, 5),
+        [0, 0, 255, 255]
+    );
+
+    harness.select_menu_item(&window, &["Swatches", "Black"]);
+
+    let screenshot = window.screenshot();
+    assert_eq!(
+        pixel(&screenshot, 10, 20),
+        [0, 0, 0, 255]
+    );
+}
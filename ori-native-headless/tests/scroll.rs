@@ -0,0 +1,199 @@
+use ori::Effect;
+use ori_native_core::{Context, Layout, native::ScrollbarPolicy, views::*};
+use ori_native_headless::{Harness, Platform, WidgetKind};
+
+struct Log {
+    lines:  usize,
+    follow: bool,
+    offset: f32,
+}
+
+fn ui(data: &Log) -> impl Effect<Context<Platform>, Log> + use<> {
+    let lines = (0..data.lines)
+        .map(|i| text(format!("Line {i}")).height(20.0))
+        .collect::<Vec<_>>();
+
+    window(
+        vscroll(column(lines))
+            .scroll_to_end(data.follow)
+            .on_scroll(|data: &mut Log, event| data.offset = event.offset_y)
+            .height(100.0),
+    )
+}
+
+#[test]
+fn scroll_to_end_follows_growing_contents() {
+    let mut harness = Harness::new(
+        Log {
+            lines:  10,
+            follow: true,
+            offset: 0.0,
+        },
+        ui,
+    );
+
+    let scroll = harness.window(0).find_kind(WidgetKind::Scroll).unwrap();
+    assert_eq!(scroll.scroll_offset(), (0.0, 100.0));
+
+    harness.data_mut().lines = 15;
+    harness.rebuild();
+
+    assert_eq!(scroll.scroll_offset(), (0.0, 200.0));
+}
+
+#[test]
+fn scroll_to_end_lets_the_user_scroll_back() {
+    let mut harness = Harness::new(
+        Log {
+            lines:  10,
+            follow: true,
+            offset: 0.0,
+        },
+        ui,
+    );
+
+    let scroll = harness.window(0).find_kind(WidgetKind::Scroll).unwrap();
+    harness.scroll(&scroll, (0.0, 40.0));
+
+    harness.data_mut().lines = 15;
+    harness.rebuild();
+
+    assert_eq!(scroll.scroll_offset(), (0.0, 40.0));
+}
+
+#[test]
+fn scroll_to_end_scrolls_when_turned_on() {
+    let mut harness = Harness::new(
+        Log {
+            lines:  10,
+            follow: false,
+            offset: 0.0,
+        },
+        ui,
+    );
+
+    let scroll = harness.window(0).find_kind(WidgetKind::Scroll).unwrap();
+    assert_eq!(scroll.scroll_offset(), (0.0, 0.0));
+
+    harness.data_mut().follow = true;
+    harness.rebuild();
+
+    assert_eq!(scroll.scroll_offset(), (0.0, 100.0));
+}
+
+#[test]
+fn scroll_reports_offset() {
+    let mut harness = Harness::new(
+        Log {
+            lines:  10,
+            follow: false,
+            offset: 0.0,
+        },
+        ui,
+    );
+
+    let scroll = harness.window(0).find_kind(WidgetKind::Scroll).unwrap();
+    harness.scroll(&scroll, (0.0, 500.0));
+
+    // the offset is clamped to the end of the contents
+    assert_eq!(harness.data().offset, 100.0);
+}
+
+struct Viewer {
+    horizontal: ScrollbarPolicy,
+    kinetic:    bool,
+}
+
+fn viewer(data: &Viewer) -> impl Effect<Context<Platform>, Viewer> + use<> {
+    window(
+        scroll(
+            text("Image")
+                .size(16.0)
+                .width(300.0)
+                .height(200.0)
+                .flex_shrink(0.0),
+        )
+        .hscrollbar(data.horizontal)
+        .vscrollbar(ScrollbarPolicy::Always)
+        .kinetic(data.kinetic)
+        .size(100.0, 100.0),
+    )
+}
+
+#[test]
+fn scroll_pans_both_axes() {
+    let mut harness = Harness::new(
+        Viewer {
+            horizontal: ScrollbarPolicy::Auto,
+            kinetic:    true,
+        },
+        viewer,
+    );
+
+    let scroll = harness.window(0).find_kind(WidgetKind::Scroll).unwrap();
+
+    harness.scroll(&scroll, (150.0, 50.0));
+    assert_eq!(scroll.scroll_offset(), (150.0, 50.0));
+
+    harness.scroll(&scroll, (500.0, 500.0));
+    assert_eq!(scroll.scroll_offset(), (200.0, 100.0));
+}
+
+#[test]
+fn scrollbar_policies_follow_data() {
+    let mut harness = Harness::new(
+        Viewer {
+            horizontal: ScrollbarPolicy::Overlay,
+            kinetic:    true,
+        },
+        viewer,
+    );
+
+    let scroll = harness.window(0).find_kind(WidgetKind::Scroll).unwrap();
+    assert_eq!(
+        scroll.scrollbars(),
+        (
+            ScrollbarPolicy::Overlay,
+            ScrollbarPolicy::Always
+        )
+    );
+    assert!(scroll.kinetic());
+
+    harness.data_mut().horizontal = ScrollbarPolicy::Never;
+    harness.data_mut().kinetic = false;
+    harness.rebuild();
+
+    assert_eq!(
+        scroll.scrollbars(),
+        (
+            ScrollbarPolicy::Never,
+            ScrollbarPolicy::Always
+        )
+    );
+    assert!(!scroll.kinetic());
+
+    // an axis without scrollbar doesn't scroll
+    harness.scroll(&scroll, (150.0, 50.0));
+    assert_eq!(scroll.scroll_offset(), (0.0, 50.0));
+}
+
+#[test]
+fn single_axis_scrolls_have_one_scrollbar() {
+    let harness = Harness::new(
+        Log {
+            lines:  10,
+            follow: false,
+            offset: 0.0,
+        },
+        ui,
+    );
+
+    let scroll = harness.window(0).find_kind(WidgetKind::Scroll).unwrap();
+    assert_eq!(
+        scroll.scrollbars(),
+        (
+            ScrollbarPolicy::Never,
+            ScrollbarPolicy::Auto
+        )
+    );
+}
@@ -0,0 +1,68 @@
+use ori::Effect;
+use ori_native_core::{Context, views::*};
+use ori_native_headless::{Harness, Platform, WidgetKind};
+
+#[derive(Default)]
+struct Volume {
+    value:   f32,
+    changes: usize,
+    commits: Vec<f32>,
+}
+
+fn ui(data: &Volume) -> impl Effect<Context<Platform>, Volume> + use<> {
+    window(
+        slider(data.value, 0.0..=100.0)
+            .step(10.0)
+            .labeled_mark(50.0, "Half")
+            .on_change(|data: &mut Volume, value| {
+                data.value = value;
+                data.changes += 1;
+            })
+            .on_commit(|data: &mut Volume, value| data.commits.push(value)),
+    )
+}
+
+#[test]
+fn slide_changes_and_commits_once() {
+    let mut harness = Harness::new(Volume::default(), ui);
+    let slider = harness.window(0).find_kind(WidgetKind::Slider).unwrap();
+
+    harness.slide(&slider, [12.0, 38.0, 61.0]);
+
+    assert_eq!(harness.data().value, 60.0);
+    assert_eq!(harness.data().changes, 3);
+    assert_eq!(harness.data().commits, [60.0]);
+}
+
+#[test]
+fn slide_clamps_to_range() {
+    let mut harness = Harness::new(Volume::default(), ui);
+    let slider = harness.window(0).find_kind(WidgetKind::Slider).unwrap();
+
+    harness.slide(&slider, [250.0]);
+
+    assert_eq!(harness.data().value, 100.0);
+    assert_eq!(slider.value(), 100.0);
+}
+
+#[test]
+fn slider_follows_data() {
+    let mut harness = Harness::new(Volume::default(), ui);
+    let slider = harness.window(0).find_kind(WidgetKind::Slider).unwrap();
+
+    assert_eq!(slider.range(), (0.0, 100.0));
+    assert_eq!(slider.step(), Some(10.0));
+    assert_eq!(
+        slider.marks(),
+        [SliderMark {
+            value: 50.0,
+            label: Some(String::from("Half")),
+        }]
+    );
+
+    harness.data_mut().value = 30.0;
+    harness.rebuild();
+
+    assert_eq!(slider.value(), 30.0);
+    assert!(harness.data().commits.is_empty());
+}
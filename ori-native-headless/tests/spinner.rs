@@ -0,0 +1,28 @@
+use ori::Effect;
+use ori_native_core::{Context, Layout, views::*};
+use ori_native_headless::{Harness, Platform, WidgetKind};
+
+#[derive(Default)]
+struct Loading {
+    loading: bool,
+    hidden:  bool,
+}
+
+fn ui(data: &Loading) -> impl Effect<Context<Platform>, Loading> + use<> {
+    window(spinner().spinning(data.loading).hidden(data.hidden))
+}
+
+#[test]
+fn spinner_spins_while_spinning_and_shown() {
+    let mut harness = Harness::new(Loading::default(), ui);
+    let spinner = harness.window(0).find_kind(WidgetKind::Spinner).unwrap();
+    assert!(!spinner.is_spinning());
+
+    harness.data_mut().loading = true;
+    harness.rebuild();
+    assert!(spinner.is_spinning());
+
+    harness.data_mut().hidden = true;
+    harness.rebuild();
+    assert!(!spinner.is_spinning());
+}
@@ -0,0 +1,103 @@
+use ori::Effect;
+use ori_native_core::{Context, views::*};
+use ori_native_headless::{Harness, Platform, WidgetKind};
+
+#[derive(Default)]
+struct Login {
+    email:     String,
+    password:  String,
+    submitted: Option<String>,
+    editable:  bool,
+}
+
+fn ui(data: &Login) -> impl Effect<Context<Platform>, Login> + use<> {
+    window(column((
+        textinput()
+            .text(data.email.clone())
+            .mode(InputMode::SingleLine)
+            .purpose(InputPurpose::Email)
+            .editable(data.editable)
+            .on_change(|data: &mut Login, text| data.email = text)
+            .on_submit(|data: &mut Login, text| data.submitted = Some(text)),
+        textinput()
+            .text(data.password.clone())
+            .mode(InputMode::Password)
+            .max_length(8)
+            .on_change(|data: &mut Login, text| data.password = text),
+    )))
+}
+
+fn login() -> Login {
+    Login {
+        editable: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn single_line_input_drops_newlines_and_submits() {
+    let mut harness = Harness::new(login(), ui);
+    let email = harness.window(0).find_kind(WidgetKind::TextInput).unwrap();
+
+    assert_eq!(
+        email.input_mode(),
+        InputMode::SingleLine
+    );
+    assert_eq!(email.purpose(), InputPurpose::Email);
+
+    harness.change_text(&email, "me@\nexample.com");
+    assert_eq!(harness.data().email, "me@example.com");
+
+    harness.submit(&email);
+    assert_eq!(
+        harness.data().submitted.as_deref(),
+        Some("me@example.com")
+    );
+}
+
+#[test]
+fn password_input_is_cut_at_max_length() {
+    let mut harness = Harness::new(login(), ui);
+
+    let inputs = harness
+        .window(0)
+        .find_all(|widget| widget.kind() == WidgetKind::TextInput);
+    let password = &inputs[1];
+
+    assert_eq!(
+        password.input_mode(),
+        InputMode::Password
+    );
+    assert_eq!(password.max_length(), Some(8));
+
+    harness.change_text(password, "correct horse");
+    assert_eq!(harness.data().password, "correct ");
+}
+
+#[test]
+fn input_follows_data() {
+    let mut harness = Harness::new(login(), ui);
+    let email = harness.window(0).find_kind(WidgetKind::TextInput).unwrap();
+
+    harness.data_mut().email = String::from("you@example.com");
+    harness.data_mut().editable = false;
+    harness.rebuild();
+
+    assert_eq!(email.text(), "you@example.com");
+    assert!(!email.is_editable());
+}
+
+#[test]
+#[should_panic(expected = "text input should be editable")]
+fn read_only_input_cant_be_changed() {
+    let mut harness = Harness::new(
+        Login {
+            editable: false,
+            ..Default::default()
+        },
+        ui,
+    );
+
+    let email = harness.window(0).find_kind(WidgetKind::TextInput).unwrap();
+    harness.change_text(&email, "me@example.com");
+}
@@ -0,0 +1,77 @@
+use ori::Effect;
+use ori_native_core::{Context, views::*};
+use ori_native_headless::{Harness, Platform, WidgetKind};
+
+#[derive(Default)]
+struct Settings {
+    enabled: bool,
+    dark:    bool,
+    size:    Option<usize>,
+}
+
+fn ui(data: &Settings) -> impl Effect<Context<Platform>, Settings> + use<> {
+    window(column((
+        checkbox(data.enabled)
+            .label("Enabled")
+            .on_toggle(|data: &mut Settings, checked| data.enabled = checked),
+        switch(data.dark).on_toggle(|data: &mut Settings, on| data.dark = on),
+        radio_group(data.size, ["Small", "Medium", "Large"])
+            .on_select(|data: &mut Settings, index| data.size = Some(index)),
+    )))
+}
+
+#[test]
+fn toggle_updates_data() {
+    let mut harness = Harness::new(Settings::default(), ui);
+    let window = harness.window(0);
+
+    let checkbox = window.find_kind(WidgetKind::Checkbox).unwrap();
+    let switch = window.find_kind(WidgetKind::Switch).unwrap();
+    assert_eq!(checkbox.text(), "Enabled");
+
+    harness.toggle(&checkbox);
+    harness.toggle(&switch);
+    assert!(harness.data().enabled);
+    assert!(harness.data().dark);
+
+    harness.toggle(&checkbox);
+    assert!(!harness.data().enabled);
+    assert_eq!(
+        checkbox.checked(),
+        CheckState::Unchecked
+    );
+}
+
+#[test]
+fn toggle_follows_data() {
+    let mut harness = Harness::new(Settings::default(), ui);
+    let checkbox = harness.window(0).find_kind(WidgetKind::Checkbox).unwrap();
+
+    harness.data_mut().enabled = true;
+    harness.rebuild();
+
+    assert_eq!(checkbox.checked(), CheckState::Checked);
+}
+
+#[test]
+fn radio_group_selects_one() {
+    let mut harness = Harness::new(Settings::default(), ui);
+    let window = harness.window(0);
+
+    let radios = window.find_all(|widget| widget.kind() == WidgetKind::Radio);
+    assert_eq!(radios.len(), 3);
+    assert!(radios.iter().all(|radio| !radio.checked().is_checked()));
+
+    harness.toggle(&radios[1]);
+    assert_eq!(harness.data().size, Some(1));
+
+    harness.toggle(&radios[2]);
+    assert_eq!(harness.data().size, Some(2));
+    assert!(!radios[1].checked().is_checked());
+    assert!(radios[2].checked().is_checked());
+
+    harness.data_mut().size = Some(0);
+    harness.rebuild();
+    assert!(radios[0].checked().is_checked());
+    assert!(!radios[2].checked().is_checked());
+}
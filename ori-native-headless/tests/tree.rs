@@ -0,0 +1,173 @@
+use ori::{Effect, View};
+use ori_native_core::{
+    Context, Layout, NativeWidget, Sizing,
+    native::{HasGroup, NativeGroup, NativeWindow},
+    views::*,
+};
+use ori_native_headless::{Harness, Platform, WidgetKind, widgets::Window};
+
+struct Buttons {
+    labels: Vec<&'static str>,
+}
+
+fn ui(data: &Buttons) -> impl Effect<Context<Platform>, Buttons> + use<> {
+    let buttons = data
+        .labels
+        .iter()
+        .map(|label| text(*label).size(16.0))
+        .collect::<Vec<_>>();
+
+    window(
+        column((
+            text("Name").size(16.0),
+            row(buttons).gap(8.0),
+        ))
+        .padding(4.0)
+        .gap(4.0),
+    )
+    .sizing(Sizing::Content)
+}
+
+fn buttons() -> Buttons {
+    Buttons {
+        labels: vec!["Ok", "Cancel"],
+    }
+}
+
+#[test]
+fn build_creates_widget_tree() {
+    let harness = Harness::new(buttons(), ui);
+    let window = harness.window(0);
+
+    assert_eq!(window.kind(), WidgetKind::Window);
+    assert_eq!(window.children().len(), 1);
+
+    let column = window.child(0).unwrap();
+    assert_eq!(column.kind(), WidgetKind::Group);
+    assert_eq!(column.parent(), Some(window.clone()));
+
+    let [name, row] = column.children().try_into().unwrap();
+    assert_eq!(name.text(), "Name");
+    assert_eq!(row.kind(), WidgetKind::Group);
+
+    let labels = row
+        .children()
+        .iter()
+        .map(|label| label.text())
+        .collect::<Vec<_>>();
+    assert_eq!(labels, ["Ok", "Cancel"]);
+}
+
+#[test]
+fn widgets_are_placed_by_layout() {
+    let harness = Harness::new(buttons(), ui);
+    let window = harness.window(0);
+
+    // every character is 8 wide and every line 20 tall, at a font size of 16
+    let name = window.find_text("Name").unwrap();
+    assert_eq!(name.position(), (4.0, 4.0));
+    assert_eq!(name.size().1, 20.0);
+
+    let ok = window.find_text("Ok").unwrap();
+    let cancel = window.find_text("Cancel").unwrap();
+    assert_eq!(
+        ok.parent().unwrap().position(),
+        (4.0, 28.0)
+    );
+    assert_eq!(ok.position(), (0.0, 0.0));
+    assert_eq!(ok.size(), (16.0, 20.0));
+    assert_eq!(cancel.position(), (24.0, 0.0));
+    assert_eq!(cancel.size(), (48.0, 20.0));
+
+    assert_eq!(window.size(), (80.0, 52.0));
+}
+
+#[test]
+fn rebuild_updates_tree_and_layout() {
+    let mut harness = Harness::new(buttons(), ui);
+    let window = harness.window(0);
+    let row = window.find_text("Ok").unwrap().parent().unwrap();
+
+    harness.data_mut().labels.push("Help");
+    harness.rebuild();
+
+    assert_eq!(row.children().len(), 3);
+    assert_eq!(
+        row.child(2).unwrap().position(),
+        (80.0, 0.0)
+    );
+    assert_eq!(window.size(), (120.0, 52.0));
+
+    harness.data_mut().labels.truncate(1);
+    harness.rebuild();
+
+    assert_eq!(row.children().len(), 1);
+    assert!(window.find_text("Cancel").is_none());
+    assert_eq!(window.size(), (40.0, 52.0));
+}
+
+fn sized(_: &()) -> impl Effect<Context<Platform>, ()> + use<> {
+    window(column(text("Contents")).size(200.0, 100.0).padding(10.0))
+}
+
+#[test]
+fn window_min_size_fits_contents() {
+    let mut harness = Harness::new((), sized);
+    let window = harness.window(0);
+
+    assert!(window.is_resizable());
+    assert_eq!(window.min_size(), (200, 100));
+
+    harness.resize(&window, 400, 300);
+
+    assert_eq!(window.size(), (400.0, 300.0));
+    assert_eq!(window.min_size(), (200, 100));
+}
+
+#[test]
+fn view_builds_with_context() {
+    let mut cx = Context::new(Platform::new());
+
+    let view = row((
+        text("Ok").size(16.0),
+        text("Cancel").size(16.0),
+    ))
+    .gap(8.0);
+    let (element, _) = View::<Context<Platform>, ()>::build(view, &mut cx, &mut ());
+
+    let group = element.widget.widget();
+    assert_eq!(group.kind(), WidgetKind::Group);
+    assert_eq!(group.children().len(), 2);
+
+    cx.compute_layout(element.node, taffy::Size::max_content())
+        .unwrap();
+
+    let layout = cx.get_computed_layout(element.node).unwrap();
+    assert_eq!(layout.size.width, 72.0);
+    assert_eq!(layout.size.height, 20.0);
+}
+
+#[test]
+fn window_stores_min_size() {
+    let mut platform = Platform::new();
+    let group = <Platform as HasGroup>::Group::build(&mut platform);
+    let contents = group.widget().clone();
+
+    let mut window = Window::build(&mut platform, &contents);
+    assert_eq!(
+        window.get_min_size(),
+        (Some(0), Some(0))
+    );
+
+    window.set_min_size(200, 100);
+
+    assert_eq!(
+        window.get_min_size(),
+        (Some(200), Some(100))
+    );
+    assert_eq!(window.widget().min_size(), (200, 100));
+    assert_eq!(
+        platform.windows(),
+        [window.widget().clone()]
+    );
+}
@@ -0,0 +1,90 @@
+use ori::Effect;
+use ori_native_core::{Context, views::*};
+use ori_native_headless::{Harness, Platform};
+
+#[derive(Default)]
+struct Editor {
+    file:       &'static str,
+    maximized:  bool,
+    fullscreen: bool,
+    minimized:  bool,
+}
+
+fn ui(data: &Editor) -> impl Effect<Context<Platform>, Editor> + use<> {
+    window(text("Contents"))
+        .title(format!("{} - Editor", data.file))
+        .icon_name("text-editor")
+        .decorated(!data.fullscreen)
+        .maximized(data.maximized)
+        .fullscreen(data.fullscreen)
+        .minimized(data.minimized)
+        .on_maximized(|data: &mut Editor, maximized| data.maximized = maximized)
+        .on_fullscreen(|data: &mut Editor, fullscreen| data.fullscreen = fullscreen)
+        .on_minimized(|data: &mut Editor, minimized| data.minimized = minimized)
+}
+
+fn editor() -> Editor {
+    Editor {
+        file: "notes.txt",
+        ..Default::default()
+    }
+}
+
+#[test]
+fn properties_follow_data() {
+    let mut harness = Harness::new(editor(), ui);
+    let window = harness.window(0);
+
+    assert_eq!(window.title(), "notes.txt - Editor");
+    assert_eq!(
+        window.icon_name().as_deref(),
+        Some("text-editor")
+    );
+    assert!(window.is_decorated());
+
+    harness.data_mut().file = "todo.txt";
+    harness.data_mut().maximized = true;
+    harness.rebuild();
+
+    assert_eq!(window.title(), "todo.txt - Editor");
+    assert!(window.is_maximized());
+    assert!(harness.data().maximized);
+}
+
+#[test]
+fn state_changed_by_user_is_reported() {
+    let mut harness = Harness::new(editor(), ui);
+    let window = harness.window(0);
+
+    harness.set_maximized(&window, true);
+    assert!(harness.data().maximized);
+
+    harness.set_fullscreen(&window, true);
+    assert!(harness.data().fullscreen);
+    assert!(!window.is_decorated());
+
+    harness.set_minimized(&window, true);
+    assert!(harness.data().minimized);
+
+    harness.set_fullscreen(&window, false);
+    harness.set_maximized(&window, false);
+    assert!(!harness.data().fullscreen);
+    assert!(!harness.data().maximized);
+    assert!(window.is_decorated());
+}
+
+fn unbound(_: &()) -> impl Effect<Context<Platform>, ()> + use<> {
+    window(text("Contents")).maximized(true)
+}
+
+#[test]
+fn state_is_only_applied_when_it_changes() {
+    let mut harness = Harness::new((), unbound);
+    let window = harness.window(0);
+    assert!(window.is_maximized());
+
+    harness.set_maximized(&window, false);
+    harness.rebuild();
+
+    assert!(!window.is_maximized());
+}
@@ -1,4 +1,4 @@
-use crate::{Effect, platform};
+use crate::{Effect, QuitPolicy, platform};
 
 pub struct App {
     native: platform::Application,
@@ -17,6 +17,13 @@ impl App {
         }
     }
 
+    /// Set when the application quits on its own, defaults to
+    /// [`QuitPolicy::LastWindowClosed`].
+    pub fn quit_policy(mut self, quit_policy: QuitPolicy) -> Self {
+        self.native = self.native.quit_policy(quit_policy);
+        self
+    }
+
     #[track_caller]
     pub fn run<T, V>(self, data: &mut T, ui: impl FnMut(&T) -> V)
     where
@@ -26,9 +26,15 @@ pub mod views {
 
 pub mod prelude {
     pub use crate::{
-        Action, Align, App, AutoLength, BorderLayout, BuildMarker, BuildView, Color,
-        ContainerLayout, Context, Effect, Element, FlexLayout, Fraction, Justify, Keyed, Layout,
-        Length, Message, Overflow, Position, Proxy, Sizing, View, Wrap, keyed, views::*,
+        Action, Align, App, AutoLength, BorderLayout, BoxSizing, BuildMarker, BuildView,
+        ClipboardContent, ClipboardKind, Color, ContainerLayout, Context, DragEvent, DragPhase,
+        Effect, Element, ExternalDrop, FlexLayout, FlexWrap, Fraction, GridFlow, GridLayout,
+        GridPlacement, Justify, Key, KeyEvent, Keyed, Layout, Length, Menu, MenuItem, Message,
+        Overflow, PointerButton, PointerEvent, PointerEventKind, Position, Proxy, QuitPolicy,
+        Repeat, ScrollEvent, Sizing, TemplateTrack, Track, View, Wrap, fr, keyed, minmax,
+        native::{Placement, ScrollbarPolicy, ShortcutScope},
+        repeat, span,
+        views::*,
     };
 
     #[allow(unused_imports)]